@@ -19,10 +19,8 @@ use winres::WindowsResource;
 fn main() {
 	println!("cargo:rerun-if-changed=build.rs");
 	println!("cargo:rerun-if-changed=Cargo.toml");
-	println!("cargo:rerun-if-changed=fedra.iss.in");
 	println!("cargo:rerun-if-changed=sounds");
 	build_docs();
-	configure_installer();
 	let target = env::var("TARGET").unwrap_or_default();
 	if target.contains("windows") {
 		let manifest = new_manifest("Fedra")
@@ -115,24 +113,3 @@ fn build_docs() {
 		_ => println!("cargo:warning=Failed to generate documentation."),
 	}
 }
-
-fn configure_installer() {
-	let Some(target_dir) = target_profile_dir() else { return };
-	let input_path = PathBuf::from("fedra.iss.in");
-	if !input_path.exists() {
-		return;
-	}
-	let content = match fs::read_to_string(&input_path) {
-		Ok(c) => c,
-		Err(e) => {
-			println!("cargo:warning=Failed to read installer script: {e}");
-			return;
-		}
-	};
-	let version = env::var("CARGO_PKG_VERSION").unwrap_or_else(|_| "0.0.0".to_string());
-	let new_content = content.replace("@PROJECT_VERSION@", &version);
-	let output_path = target_dir.join("fedra.iss");
-	if let Err(e) = fs::write(&output_path, new_content) {
-		println!("cargo:warning=Failed to write installer script: {e}");
-	}
-}
@@ -5,11 +5,11 @@ use wxdragon::prelude::*;
 
 use crate::{
 	AppState, UiCommand, auth,
-	config::{Account, ConfigStore},
-	mastodon::MastodonClient,
+	config::{Account, Config, ConfigStore},
+	mastodon::{InstanceOverview, MastodonClient},
 	network::{self, NetworkCommand},
 	streaming,
-	timeline::TimelineType,
+	timeline::{TimelineManager, TimelineType},
 	ui::{
 		dialogs,
 		menu::update_menu_labels,
@@ -24,7 +24,7 @@ pub fn update_window_title(state: &AppState, frame: &Frame) {
 	let timeline = state
 		.timeline_manager
 		.active()
-		.map(|t| t.timeline_type.display_name())
+		.map(|t| t.timeline_type.display_name_with_lists(&state.lists))
 		.unwrap_or_else(|| "Unknown".to_string());
 
 	let title = crate::template::render_window_title(
@@ -34,6 +34,42 @@ pub fn update_window_title(state: &AppState, frame: &Frame) {
 	frame.set_label(&title);
 }
 
+/// Leaves the window idle once no account is left, or adding the first one was cancelled, so help and the menus stay
+/// usable. The Add Account button and menu item bring the sign in flow back.
+pub fn show_signed_out_state(
+	state: &mut AppState,
+	frame: &Frame,
+	timelines_selector: ListBox,
+	timeline_list: &crate::ui::timeline_list::TimelineList,
+) {
+	state.network_handle = None;
+	state.client = None;
+	state.access_token = None;
+	state.streaming_url = None;
+	state.instance_info = None;
+	state.current_user_id = None;
+	state.lists.clear();
+	state.timeline_manager = TimelineManager::new();
+	state.cw_expanded.clear();
+	state.config.active_account_id = None;
+	let _ = ConfigStore::new().save(&state.config);
+	timelines_selector.clear();
+	timeline_list.update_entries(&[], None, Some("No account signed in. Use Add Account to sign in."));
+	frame.set_label("Fedra");
+	if let Some(mb) = frame.get_menu_bar() {
+		update_menu_labels(&mb, state);
+	}
+	if let Some(button) = state.add_account_button {
+		crate::ui::window::set_shown(&button, true);
+		// Sign-in is still waiting on the browser, so its dialog keeps focus.
+		if state.pending_auth_dialog.is_none() {
+			button.set_focus();
+		}
+	}
+}
+
+/// Asks for an instance and signs in to it, first fetching its description and rules in the background unless they
+/// are cached or turned off. Returns false if the user cancelled before anything started.
 pub fn start_add_account_flow(frame: &Frame, ui_tx: &UiCommandSender, state: &mut AppState) -> bool {
 	let Some(instance_url) = dialogs::prompt_for_instance(frame) else { return false };
 	let client = match MastodonClient::new(instance_url.clone()) {
@@ -43,11 +79,90 @@ pub fn start_add_account_flow(frame: &Frame, ui_tx: &UiCommandSender, state: &mu
 			return true;
 		}
 	};
-	let instance_url_clone = instance_url;
+	if !state.config.show_instance_overview {
+		start_oauth_flow(frame, ui_tx, state, client, instance_url, None);
+		return true;
+	}
+	if let Some(cached) = state.instance_overviews.get(instance_url.as_str()).cloned() {
+		continue_add_account_flow(frame, ui_tx, state, instance_url, Some(cached));
+		return true;
+	}
 	let ui_tx_thread = ui_tx.clone();
 	thread::spawn(move || {
-		let result = auth::oauth_with_local_listener(&client, "Fedra").map_err(|e| e.to_string());
-		let _ = ui_tx_thread.send(UiCommand::OAuthResult { result, instance_url: instance_url_clone });
+		let overview = client.get_instance_overview().ok();
+		let _ = ui_tx_thread.send(UiCommand::InstanceOverviewLoaded { instance_url, overview });
+	});
+	true
+}
+
+/// Shows the instance's description and rules, when there are any, then goes on to authorize.
+pub fn continue_add_account_flow(
+	frame: &Frame,
+	ui_tx: &UiCommandSender,
+	state: &mut AppState,
+	instance_url: Url,
+	overview: Option<InstanceOverview>,
+) {
+	if let Some(overview) = overview {
+		state.instance_overviews.insert(instance_url.to_string(), overview.clone());
+		let Some(show_again) = dialogs::show_instance_info_dialog(frame, &instance_url, &overview) else { return };
+		if !show_again {
+			state.config.show_instance_overview = false;
+			let _ = ConfigStore::new().save(&state.config);
+		}
+	}
+	let client = match MastodonClient::new(instance_url.clone()) {
+		Ok(client) => client,
+		Err(err) => {
+			dialogs::show_error(frame, &err);
+			return;
+		}
+	};
+	start_oauth_flow(frame, ui_tx, state, client, instance_url, None);
+}
+
+/// Signs in again to an account whose token the instance no longer accepts, keeping its timelines. Clears
+/// `pending_reauth_account_id` if sign in can't start, so a later expiry can ask again.
+pub fn start_reauth_flow(frame: &Frame, ui_tx: &UiCommandSender, state: &mut AppState, account_id: &str) {
+	let Some(instance_url) = state
+		.config
+		.accounts
+		.iter()
+		.find(|a| a.id == account_id)
+		.and_then(|account| Url::parse(&account.instance).ok())
+	else {
+		state.pending_reauth_account_id = None;
+		return;
+	};
+	let client = match MastodonClient::new(instance_url.clone()) {
+		Ok(client) => client,
+		Err(err) => {
+			state.pending_reauth_account_id = None;
+			dialogs::show_error(frame, &err);
+			return;
+		}
+	};
+	start_oauth_flow(frame, ui_tx, state, client, instance_url, Some(account_id.to_string()));
+}
+
+fn start_oauth_flow(
+	frame: &Frame,
+	ui_tx: &UiCommandSender,
+	state: &mut AppState,
+	client: MastodonClient,
+	instance_url: Url,
+	reauth_account_id: Option<String>,
+) {
+	let ui_tx_thread = ui_tx.clone();
+	let scopes = state.config.oauth_scopes.clone();
+	let app_name = state.config.client_name().to_string();
+	let website = state.config.client_website().to_string();
+	thread::spawn(move || {
+		// Checked here rather than in the out-of-band fallback, which runs on the UI thread.
+		let pkce = client.supports_pkce();
+		let result =
+			auth::oauth_with_local_listener(&client, &app_name, &website, &scopes, pkce).map_err(|e| e.to_string());
+		let _ = ui_tx_thread.send(UiCommand::OAuthResult { result, instance_url, reauth_id: reauth_account_id, pkce });
 	});
 	let dialog = Dialog::builder(frame, "Authentication").with_size(300, 150).build();
 	let panel = Panel::builder(&dialog).build();
@@ -65,7 +180,6 @@ pub fn start_add_account_flow(frame: &Frame, ui_tx: &UiCommandSender, state: &mu
 	panel.set_sizer(sizer, true);
 	dialog.show(true);
 	state.pending_auth_dialog = Some(dialog);
-	true
 }
 
 pub fn try_oob_oauth(
@@ -73,15 +187,20 @@ pub fn try_oob_oauth(
 	client: &MastodonClient,
 	instance_url: &Url,
 	account: &mut Account,
+	config: &Config,
+	supports_pkce: bool,
 ) -> Option<Account> {
-	let credentials = match client.register_app("Fedra", auth::OOB_REDIRECT_URI) {
-		Ok(creds) => creds,
-		Err(err) => {
-			dialogs::show_error(frame, &err);
-			return None;
-		}
-	};
-	let authorize_url = match client.build_authorize_url(&credentials, auth::OOB_REDIRECT_URI) {
+	let scopes = config.oauth_scopes.as_str();
+	let credentials =
+		match client.register_app(config.client_name(), config.client_website(), auth::OOB_REDIRECT_URI, scopes) {
+			Ok(creds) => creds,
+			Err(err) => {
+				dialogs::show_error(frame, &err);
+				return None;
+			}
+		};
+	let pkce = auth::Pkce::when_supported(supports_pkce);
+	let authorize_url = match client.build_authorize_url(&credentials, auth::OOB_REDIRECT_URI, scopes, pkce.as_ref()) {
 		Ok(url) => url,
 		Err(err) => {
 			dialogs::show_error(frame, &err);
@@ -90,7 +209,7 @@ pub fn try_oob_oauth(
 	};
 	let _ = launch_default_browser(authorize_url.as_str(), BrowserLaunchFlags::Default);
 	let code = dialogs::prompt_for_oauth_code(frame, instance_url)?;
-	let access_token = match client.exchange_token(&credentials, &code, auth::OOB_REDIRECT_URI) {
+	let access_token = match client.exchange_token(&credentials, &code, auth::OOB_REDIRECT_URI, scopes, pkce.as_ref()) {
 		Ok(token) => token,
 		Err(err) => {
 			dialogs::show_error(frame, &err);
@@ -113,6 +232,7 @@ pub fn switch_to_account(
 	new_account_id: Option<String>,
 ) {
 	if let Some(new_id) = new_account_id {
+		state.declined_reauth_account_id = None;
 		if let Some(old_id) = state.config.active_account_id.clone() {
 			for timeline in state.timeline_manager.iter_mut() {
 				timeline.stream_handle = None;
@@ -125,6 +245,9 @@ pub fn switch_to_account(
 	}
 
 	state.network_handle = None;
+	state.relationships.clear();
+	state.pending_relationships.clear();
+	state.lists.clear();
 	let active_id =
 		state.config.active_account_id.clone().or_else(|| state.config.accounts.first().map(|a| a.id.clone()));
 
@@ -144,14 +267,27 @@ pub fn switch_to_account(
 	}) else {
 		return;
 	};
+	if let Some(button) = state.add_account_button {
+		let had_focus = button.has_focus();
+		crate::ui::window::set_shown(&button, false);
+		if had_focus {
+			timeline_list.set_focus();
+		}
+	}
 	state.streaming_url = Some(url.clone());
 	state.access_token = Some(token.clone());
 	state.network_handle = network::start_network(url.clone(), token.clone(), state.ui_waker.clone()).ok();
+	state.next_filter_expiry = None;
+	if let Some(handle) = &state.network_handle {
+		handle.send(NetworkCommand::FetchFilters);
+	}
 	if let Ok(client) = MastodonClient::new(url) {
 		state.client = Some(client.clone());
-		if let Ok(info) = client.get_instance_info() {
+		state.instance_info = client.get_instance_info().ok();
+		if let Some(info) = &state.instance_info {
 			state.max_post_chars = Some(info.max_post_chars);
-			state.poll_limits = info.poll_limits;
+			state.poll_limits = info.poll_limits.clone();
+			state.media_limits = info.media_limits.clone();
 			if let Some(ref streaming_url) = info.streaming_url {
 				if let Ok(parsed) = Url::parse(streaming_url) {
 					state.streaming_url = Some(parsed);
@@ -178,12 +314,17 @@ pub fn switch_to_account(
 		}
 	}
 
+	let mut restored_from_session = false;
 	if state.timeline_manager.len() == 0 {
 		let mut loaded_saved = false;
 		let default_timelines = state.config.default_timelines.clone();
 
-		if !state.config.saved_timelines.is_empty() {
-			let saved = std::mem::take(&mut state.config.saved_timelines);
+		let mut saved =
+			state.active_account_mut().map(|account| std::mem::take(&mut account.saved_timelines)).unwrap_or_default();
+		if saved.is_empty() {
+			saved = std::mem::take(&mut state.config.saved_timelines);
+		}
+		if !saved.is_empty() {
 			for t in saved {
 				if !state.config.restore_open_timelines {
 					let is_default = if t == TimelineType::Home || t == TimelineType::Notifications {
@@ -212,18 +353,26 @@ pub fn switch_to_account(
 				}
 
 				state.timeline_manager.open(t.clone());
+				if let Some(timeline) = state.timeline_manager.get_mut(&t) {
+					timeline.restored = true;
+				}
 				if let Some(handle) = &state.network_handle {
 					match t.clone() {
 						TimelineType::Thread { id, .. } => {
 							handle.send(NetworkCommand::FetchThreadById { timeline_type: t, status_id: id });
 						}
 						TimelineType::Search { query, search_type } => {
-							handle.send(NetworkCommand::Search { query, search_type, limit: Some(40), offset: None });
+							handle.send(NetworkCommand::Search {
+								query,
+								search_type,
+								limit: state.config.page_limit(),
+								offset: None,
+							});
 						}
 						_ => {
 							handle.send(NetworkCommand::FetchTimeline {
 								timeline_type: t,
-								limit: Some(40),
+								limit: state.config.page_limit(),
 								max_id: None,
 							});
 						}
@@ -231,6 +380,7 @@ pub fn switch_to_account(
 				}
 			}
 			loaded_saved = state.config.restore_open_timelines;
+			restored_from_session = loaded_saved;
 		}
 
 		if !loaded_saved {
@@ -251,12 +401,17 @@ pub fn switch_to_account(
 				if state.timeline_manager.open(t.clone())
 					&& let Some(handle) = &state.network_handle
 				{
-					handle.send(NetworkCommand::FetchTimeline { timeline_type: t, limit: Some(40), max_id: None });
+					handle.send(NetworkCommand::FetchTimeline {
+						timeline_type: t,
+						limit: state.config.page_limit(),
+						max_id: None,
+					});
 				}
 			}
 		}
 	}
 
+	state.timeline_manager.apply_pins(state.config.pinned_timelines());
 	let timeline_types: Vec<TimelineType> =
 		state.timeline_manager.iter_mut().map(|t| t.timeline_type.clone()).collect();
 	for tt in timeline_types {
@@ -278,7 +433,7 @@ pub fn switch_to_account(
 	}
 
 	timelines_selector.clear();
-	for name in state.timeline_manager.display_names() {
+	for name in state.timeline_manager.display_names(&state.lists) {
 		timelines_selector.append(&name);
 	}
 	let active_index = state.timeline_manager.active_index();
@@ -302,7 +457,10 @@ pub fn switch_to_account(
 	});
 	if should_announce {
 		let handle = state.active_account().map_or_else(|| "Unknown".to_string(), |account| account.full_handle());
-		timeline_list.announce(&format!("Switched to {handle}"));
+		let count = state.timeline_manager.len();
+		let noun = if count == 1 { "timeline" } else { "timelines" };
+		let how = if restored_from_session { "restored" } else { "open" };
+		timeline_list.announce(&format!("Switched to {handle}, {count} {noun} {how}"));
 	}
 	update_window_title(state, frame);
 	if let Some(mb) = frame.get_menu_bar() {
@@ -6,6 +6,8 @@ use std::{
 };
 
 use anyhow::{Context, Result};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use sha2::{Digest, Sha256};
 use url::Url;
 use wxdragon::utils::{BrowserLaunchFlags, launch_default_browser};
 
@@ -21,17 +23,45 @@ pub struct OAuthResult {
 	pub client_secret: String,
 }
 
-pub fn oauth_with_local_listener(client: &MastodonClient, app_name: &str) -> Result<OAuthResult> {
+/// A PKCE code verifier and its S256 challenge.
+pub struct Pkce {
+	pub verifier: String,
+	pub challenge: String,
+}
+
+impl Pkce {
+	pub fn generate() -> Result<Self> {
+		let mut bytes = [0u8; 32];
+		getrandom::fill(&mut bytes).map_err(|err| anyhow::anyhow!("Failed to generate PKCE verifier: {err}"))?;
+		let verifier = URL_SAFE_NO_PAD.encode(bytes);
+		let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+		Ok(Self { verifier, challenge })
+	}
+
+	/// Generates a challenge if the instance advertises PKCE support, otherwise falls back to the plain flow.
+	pub fn when_supported(supported: bool) -> Option<Self> {
+		if supported { Self::generate().ok() } else { None }
+	}
+}
+
+pub fn oauth_with_local_listener(
+	client: &MastodonClient,
+	app_name: &str,
+	website: &str,
+	scopes: &str,
+	supports_pkce: bool,
+) -> Result<OAuthResult> {
 	let listener = TcpListener::bind("127.0.0.1:0").context("Failed to bind OAuth listener")?;
 	let addr = listener.local_addr().context("Failed to get listener address")?;
 	let redirect_uri = format!("http://127.0.0.1:{}{}", addr.port(), CALLBACK_PATH);
-	let credentials = client.register_app(app_name, &redirect_uri)?;
-	let authorize_url = client.build_authorize_url(&credentials, &redirect_uri)?;
+	let credentials = client.register_app(app_name, website, &redirect_uri, scopes)?;
+	let pkce = Pkce::when_supported(supports_pkce);
+	let authorize_url = client.build_authorize_url(&credentials, &redirect_uri, scopes, pkce.as_ref())?;
 	if !launch_default_browser(authorize_url.as_str(), BrowserLaunchFlags::Default) {
 		return Err(anyhow::anyhow!("Failed to open browser"));
 	}
 	let code = wait_for_code(&listener, addr.port()).context("OAuth callback timeout")?;
-	let access_token = client.exchange_token(&credentials, &code, &redirect_uri)?;
+	let access_token = client.exchange_token(&credentials, &code, &redirect_uri, scopes, pkce.as_ref())?;
 	Ok(OAuthResult { access_token, client_id: credentials.client_id, client_secret: credentials.client_secret })
 }
 
@@ -9,14 +9,15 @@ use wxdragon::prelude::*;
 use crate::{
 	AppState,
 	accounts::{
-		start_add_account_flow, start_streaming_for_timeline, switch_to_account, try_oob_oauth, update_window_title,
+		continue_add_account_flow, show_signed_out_state, start_add_account_flow, start_reauth_flow,
+		start_streaming_for_timeline, switch_to_account, try_oob_oauth, update_window_title,
 	},
 	auth,
 	config::{self, Account, AutoloadMode, ContentWarningDisplay, SortOrder},
 	html,
-	mastodon::{MastodonClient, Status},
+	mastodon::{Filter, FilterContext, InstanceOverview, MastodonClient, SearchType, Status},
 	network::{self, ForeignInteraction, NetworkCommand},
-	timeline::{TimelineEntry, TimelineTextOptions, TimelineType},
+	timeline::{PublicScope, TimelineEntry, TimelineTextOptions, TimelineType},
 	ui::{
 		app_shell, dialogs,
 		menu::update_menu_labels,
@@ -28,6 +29,11 @@ use crate::{
 	ui_wake::UiCommandSender,
 };
 
+/// Minimum gap between resume-triggered reconnects, since Windows can report a resume more than once.
+const RESUME_REFRESH_COOLDOWN: Duration = Duration::from_secs(30);
+/// Most accounts sent in one relationships request, keeping the query string a reasonable length.
+const RELATIONSHIP_BATCH_SIZE: usize = 40;
+
 fn post_result_to_data(post: dialogs::PostResult, quoted_status_id: Option<String>) -> network::PostData {
 	network::PostData {
 		content: post.content,
@@ -76,19 +82,35 @@ pub enum UiCommand {
 	EditPost,
 	CopyPost,
 	CopyPostLink,
+	AnnouncePostStats,
+	CopyRawJson,
 	Favorite,
 	Bookmark,
 	Boost,
 	Pin,
+	MuteConversation,
+	TagBookmark,
+	FilterBookmarksByTag,
+	OpenMediaTimeline,
 	Refresh,
+	ReconnectStreams,
+	SystemResumed,
 	OpenTimeline(TimelineType),
 	OpenUserTimeline,
 	OpenUserTimelineByInput,
+	GoToPost,
 	OpenInstanceTimelineByInput,
 	CloseTimeline,
+	CloseSecondaryTimelines,
+	TogglePinTimeline,
+	ToggleFollowLive,
+	RefreshPost,
+	TimelineListFocused,
 	TimelineSelectionChanged(usize),
 	TimelineEntrySelectionChanged(usize),
 	ShowOptions,
+	AddAccount,
+	SignInAgain,
 	ManageAccounts,
 	SwitchAccount(String),
 	SwitchNextAccount,
@@ -100,6 +122,7 @@ pub enum UiCommand {
 	RemoveAccount(String),
 	ViewProfile,
 	ViewMentions,
+	FindHandles,
 	ViewHashtags,
 	ViewBoosts,
 	ViewFavorites,
@@ -108,12 +131,14 @@ pub enum UiCommand {
 	FollowersDialogClosed,
 	FollowingDialogClosed,
 	OpenLinks,
+	CopyAllLinks,
 	ViewInBrowser,
 	PlayMedia,
 	ViewThread,
 	ViewResolvedThread(Box<Status>),
 	PromptForQuote(Box<Status>),
 	ViewQuotedThread,
+	LoadMoreReplies,
 	Vote,
 	LoadMore,
 	LoadMoreBackground,
@@ -121,29 +146,42 @@ pub enum UiCommand {
 	HomePressed,
 	ToggleContentWarning,
 	ToggleFollow,
+	FollowBack,
 	ToggleWindowVisibility,
 	SetQuickActionKeysEnabled(bool),
 	SwitchTimelineByIndex(usize),
-	OAuthResult { result: Result<auth::OAuthResult, String>, instance_url: Url },
+	SwitchToLastTimeline,
+	OAuthResult { result: Result<auth::OAuthResult, String>, instance_url: Url, reauth_id: Option<String>, pkce: bool },
+	InstanceOverviewLoaded { instance_url: Url, overview: Option<InstanceOverview> },
 	CancelAuth,
 	EditProfile,
 	ViewHelp,
 	ViewPost,
 	Search,
 	CheckForUpdates,
+	OpenLogFolder,
+	ShowLastError,
+	ShowInstanceInfo,
 	ManageFilters,
 	ManageLists,
+	ManageCurrentList,
 	ManageListsDialogClosed,
 	ManageListMembersDialogClosed,
 	OpenList,
-	AddUserToList(String),
+	AddUserToList { account_id: String, acct: String },
+	AddAuthorToList,
+	ToggleHiddenBoosts { acct: String, name: String },
+	HideBoostsFromBooster,
+	CreateListFromAuthors,
 	ContinueThread(Box<Status>),
 	Find(String),
 	FindNext,
 	FindPrev,
+	NextMentionOfMe,
 	AppClosing,
 	ExitApp,
 	RecoverDraft,
+	ResumeDraft,
 	PollNonStreaming,
 }
 
@@ -155,7 +193,11 @@ pub fn refresh_timeline(state: &AppState, live_region: &crate::ui::timeline_list
 	};
 	match &state.network_handle {
 		Some(handle) => {
-			handle.send(NetworkCommand::FetchTimeline { timeline_type, limit: Some(40), max_id: None });
+			handle.send(NetworkCommand::FetchTimeline {
+				timeline_type,
+				limit: state.config.page_limit(),
+				max_id: None,
+			});
 		}
 		None => {
 			live_region.announce("Network not available");
@@ -163,19 +205,211 @@ pub fn refresh_timeline(state: &AppState, live_region: &crate::ui::timeline_list
 	}
 }
 
+/// Tears down every stream handle and starts fresh ones, refetching so posts missed while disconnected show up.
+/// Returns how many timelines were reconnected.
+fn restart_streams(state: &mut AppState) -> usize {
+	let streamed: Vec<TimelineType> = state
+		.timeline_manager
+		.timelines()
+		.iter()
+		.filter(|t| t.timeline_type.stream_params().is_some())
+		.map(|t| t.timeline_type.clone())
+		.collect();
+	let count = streamed.len();
+	for timeline_type in streamed {
+		if let Some(timeline) = state.timeline_manager.get_mut(&timeline_type) {
+			timeline.stream_handle = None;
+			timeline.stream_down_since = None;
+			timeline.stream_outage_announced = false;
+		}
+		start_streaming_for_timeline(state, &timeline_type);
+		if let Some(handle) = &state.network_handle {
+			handle.send(NetworkCommand::FetchTimeline {
+				timeline_type,
+				limit: state.config.page_limit(),
+				max_id: None,
+			});
+		}
+	}
+	count
+}
+
+pub fn reconnect_streams(state: &mut AppState, live_region: &crate::ui::timeline_list::TimelineList) {
+	if state.timeline_manager.timelines().iter().all(|t| t.timeline_type.stream_params().is_none()) {
+		live_region.announce("No open timelines use live updates");
+		return;
+	}
+	live_region.announce("Reconnecting live updates");
+	restart_streams(state);
+}
+
 pub fn poll_non_streaming_timelines(state: &AppState) {
 	let Some(handle) = &state.network_handle else { return };
 	for timeline in state.timeline_manager.timelines() {
 		if timeline.stream_handle.is_none() && timeline.timeline_type.stream_params().is_some() {
 			handle.send(NetworkCommand::FetchTimeline {
 				timeline_type: timeline.timeline_type.clone(),
-				limit: Some(40),
+				limit: state.config.page_limit(),
 				max_id: None,
 			});
 		}
 	}
 }
 
+/// Once one of the account's filters expires, refetches the filter set and every open timeline, so posts it hid, or
+/// dropped outright, show up again.
+fn refresh_after_filter_expiry(state: &mut AppState) {
+	if state.next_filter_expiry.is_none_or(|at| at > chrono::Utc::now()) {
+		return;
+	}
+	state.next_filter_expiry = None;
+	let Some(handle) = &state.network_handle else { return };
+	handle.send(NetworkCommand::FetchFilters);
+	for timeline in state.timeline_manager.timelines() {
+		handle.send(NetworkCommand::FetchTimeline {
+			timeline_type: timeline.timeline_type.clone(),
+			limit: state.config.page_limit(),
+			max_id: None,
+		});
+	}
+}
+
+/// Batch-fetches relationships for accounts that are neither cached nor already being fetched.
+pub fn request_relationships(state: &mut AppState, account_ids: Vec<String>) {
+	let Some(handle) = &state.network_handle else { return };
+	let mut missing: Vec<String> = Vec::new();
+	for id in account_ids {
+		if state.relationships.contains_key(&id) || state.pending_relationships.contains(&id) || missing.contains(&id) {
+			continue;
+		}
+		missing.push(id);
+	}
+	for chunk in missing.chunks(RELATIONSHIP_BATCH_SIZE) {
+		handle.send(NetworkCommand::FetchRelationships { account_ids: chunk.to_vec() });
+	}
+	state.pending_relationships.extend(missing);
+}
+
+/// Fetches relationships for authors in the active timeline that are not cached yet, when follow markers are on.
+pub fn request_missing_relationships(state: &mut AppState) {
+	if !state.config.show_follow_markers {
+		return;
+	}
+	let Some(active) = state.timeline_manager.active() else { return };
+	let author_ids: Vec<String> = active
+		.entries
+		.iter()
+		.filter_map(TimelineEntry::author_id)
+		.filter(|id| state.current_user_id.as_deref() != Some(*id))
+		.map(ToString::to_string)
+		.collect();
+	request_relationships(state, author_ids);
+}
+
+/// Marks the selected direct conversation as read, locally and on the server, once it has been opened.
+fn mark_selected_conversation_read(
+	state: &mut AppState,
+	selector: ListBox,
+	suppress_selection: &Cell<bool>,
+	frame: &Frame,
+) {
+	let Some(conversation_id) = get_selected_status(state).and_then(|status| status.conversation_id.clone()) else {
+		return;
+	};
+	let Some(active) = state.timeline_manager.active_mut() else { return };
+	if !active.mark_conversation_read(&conversation_id) {
+		return;
+	}
+	if let Some(handle) = &state.network_handle {
+		handle.send(NetworkCommand::MarkConversationRead { conversation_id });
+	}
+	refresh_timeline_names(state, selector, suppress_selection, frame);
+}
+
+/// Rewrites the timeline selector's labels, e.g. after cached list settings change.
+pub fn refresh_timeline_names(state: &AppState, selector: ListBox, suppress_selection: &Cell<bool>, frame: &Frame) {
+	selector.clear();
+	for name in state.timeline_manager.display_names(&state.lists) {
+		selector.append(&name);
+	}
+	with_suppressed_selection(suppress_selection, || {
+		selector.set_selection(u32::try_from(state.timeline_manager.active_index()).unwrap(), true);
+	});
+	update_window_title(state, frame);
+}
+
+impl UiCommand {
+	/// Whether focus should go back to the main window once this command has been handled. Almost any command can
+	/// show a dialog, so only the ones that run in the background, track focus themselves or close the window are left
+	/// out.
+	pub const fn returns_focus(&self) -> bool {
+		!matches!(
+			self,
+			Self::TimelineListFocused
+				| Self::TimelineSelectionChanged(_)
+				| Self::TimelineEntrySelectionChanged(_)
+				| Self::LoadMoreBackground
+				| Self::PollNonStreaming
+				| Self::SystemResumed
+				| Self::ToggleWindowVisibility
+				| Self::SetQuickActionKeysEnabled(_)
+				| Self::AppClosing
+				| Self::ExitApp
+		)
+	}
+}
+
+/// A main window control that can hold keyboard focus, remembered so focus can go back to it after a dialog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusTarget {
+	TimelinesSelector,
+	TimelineList,
+	DetailPane,
+}
+
+impl FocusTarget {
+	/// The main window control that has focus now, if any.
+	pub fn current(
+		state: &AppState,
+		timelines_selector: ListBox,
+		timeline_list: &crate::ui::timeline_list::TimelineList,
+	) -> Option<Self> {
+		if timelines_selector.has_focus() {
+			Some(Self::TimelinesSelector)
+		} else if timeline_list.has_focus() {
+			Some(Self::TimelineList)
+		} else if state.detail_pane.is_some_and(|pane| pane.has_focus()) {
+			Some(Self::DetailPane)
+		} else {
+			None
+		}
+	}
+}
+
+/// Puts keyboard focus back where it was before a dialog opened, or on the timeline when that isn't known. Without
+/// this, focus can be left on the frame itself and screen readers go silent. Focus that already landed on a main window
+/// control is left alone, as is focus in another window such as a modeless dialog.
+pub fn restore_focus(
+	previous: Option<FocusTarget>,
+	state: &AppState,
+	frame: &Frame,
+	timelines_selector: ListBox,
+	timeline_list: &crate::ui::timeline_list::TimelineList,
+	tray_hidden: &Cell<bool>,
+) {
+	if tray_hidden.get()
+		|| !app_shell::is_window_active(frame)
+		|| FocusTarget::current(state, timelines_selector, timeline_list).is_some()
+	{
+		return;
+	}
+	match (previous, state.detail_pane) {
+		(Some(FocusTarget::TimelinesSelector), _) => timelines_selector.set_focus(),
+		(Some(FocusTarget::DetailPane), Some(pane)) if pane.is_shown() => pane.set_focus(),
+		_ => timeline_list.set_focus(),
+	}
+}
+
 /// Handles a UI command, updating state and UI as needed.
 pub struct UiCommandContext<'a> {
 	pub state: &'a mut AppState,
@@ -205,35 +439,97 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 	let sort_order_cell = ctx.sort_order_cell;
 	let tray_hidden = ctx.tray_hidden;
 	let ui_tx = ctx.ui_tx;
+	// Enter on a thread's "load more replies" row loads them, whatever Enter does on a post.
+	let cmd = match cmd {
+		UiCommand::ViewThread | UiCommand::ViewPost | UiCommand::Reply { .. }
+			if matches!(get_selected_entry(state), Some(TimelineEntry::MoreReplies(_))) =>
+		{
+			UiCommand::LoadMoreReplies
+		}
+		cmd => cmd,
+	};
+	// Just moving past a direct message doesn't count as reading it.
+	if matches!(cmd, UiCommand::ViewThread | UiCommand::ViewPost | UiCommand::Reply { .. }) {
+		mark_selected_conversation_read(state, timelines_selector, suppress_selection, frame);
+	}
 	match cmd {
 		UiCommand::NewPost => {
-			let (has_account, max_post_chars, poll_limits, enter_to_send) = (
+			let (has_account, max_post_chars, poll_limits, media_limits, enter_to_send) = (
 				state.active_account().is_some(),
 				state.max_post_chars,
 				state.poll_limits.clone(),
+				state.media_limits.clone(),
 				state.config.enter_to_send,
 			);
 			if !has_account {
 				live_region.announce("No account configured");
 				return;
 			}
-			let default_visibility =
-				if state.timeline_manager.active().is_some_and(|t| t.timeline_type == TimelineType::Direct) {
-					Some(dialogs::PostVisibility::Direct)
-				} else {
+			let composed = if let Some(direct) =
+				state.timeline_manager.active().filter(|t| t.timeline_type == TimelineType::Direct)
+			{
+				let self_account = state.active_account();
+				let mut suggestions: Vec<crate::mastodon::Account> = Vec::new();
+				for entry in &direct.entries {
+					let Some(status) = entry.as_status() else { continue };
+					if !self_account.is_some_and(|account| status.account.is_self(account))
+						&& !suggestions.iter().any(|known| known.id == status.account.id)
+					{
+						suggestions.push(status.account.clone());
+					}
+				}
+				let (Some(client), Some(token)) = (state.client.clone(), state.access_token.clone()) else {
+					live_region.announce("Network not available");
+					return;
+				};
+				let search = move |query: &str| {
+					client.search(&token, query, SearchType::Accounts, Some(20), None).map(|results| results.accounts)
+				};
+				let Some(recipients) = dialogs::prompt_for_dm_recipients(frame, suggestions, search) else {
+					return;
+				};
+				dialogs::prompt_for_direct_message(
+					frame,
+					max_post_chars,
+					&poll_limits,
+					&media_limits,
+					enter_to_send,
+					state.config.cw_marks_sensitive,
+					&state.config.auto_cw_rules,
+					state.config.confirm_discard_compose,
+					&mut state.config.dialog_geometry,
+					&recipients,
+				)
+			} else {
+				let default_visibility =
 					state.active_account().and_then(|a| a.default_post_visibility.as_deref()).and_then(|v| match v {
 						"public" => Some(dialogs::PostVisibility::Public),
 						"unlisted" => Some(dialogs::PostVisibility::Unlisted),
 						"private" => Some(dialogs::PostVisibility::Private),
 						"direct" => Some(dialogs::PostVisibility::Direct),
 						_ => None,
-					})
-				};
-			let Some((post, config)) =
-				dialogs::prompt_for_post(frame, max_post_chars, &poll_limits, enter_to_send, default_visibility)
-			else {
+					});
+				dialogs::prompt_for_post(
+					frame,
+					max_post_chars,
+					&poll_limits,
+					&media_limits,
+					enter_to_send,
+					state.config.cw_marks_sensitive,
+					&state.config.auto_cw_rules,
+					state.config.confirm_discard_compose,
+					&mut state.config.dialog_geometry,
+					default_visibility,
+				)
+			};
+			let Some((post, config)) = composed else {
 				return;
 			};
+			if post.save_as_draft {
+				let draft = crate::PendingPost { config, operation: crate::PostOperation::NewPost, last_result: post };
+				save_draft(state, live_region, draft);
+				return;
+			}
 			if let Some(handle) = &state.network_handle {
 				state.pending_thread_continuation = post.continue_thread;
 				state.pending_post = Some(crate::PendingPost {
@@ -251,19 +547,29 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				status.visibility = "unlisted".to_string();
 			}
 			let (max_post_chars, enter_to_send) = (state.max_post_chars, state.config.enter_to_send);
-			let self_acct = state.active_account().and_then(|account| account.acct.as_deref());
+			let self_account = state.active_account().cloned();
 			let Some((reply, config)) = dialogs::prompt_for_reply(
 				frame,
 				&status,
 				max_post_chars,
 				&state.poll_limits,
+				&state.media_limits,
 				true,
-				self_acct,
+				self_account.as_ref(),
 				enter_to_send,
+				state.config.cw_marks_sensitive,
+				&state.config.auto_cw_rules,
+				state.config.confirm_discard_compose,
+				&mut state.config.dialog_geometry,
 				true,
 			) else {
 				return;
 			};
+			if reply.save_as_draft {
+				let operation = crate::PostOperation::Reply { in_reply_to_id: status.id.clone() };
+				save_draft(state, live_region, crate::PendingPost { config, operation, last_result: reply });
+				return;
+			}
 			if let Some(handle) = &state.network_handle {
 				state.pending_thread_continuation = reply.continue_thread;
 				state.pending_post = Some(crate::PendingPost {
@@ -296,19 +602,36 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				return;
 			};
 			let target = status.reblog.as_ref().map_or(&status, std::convert::AsRef::as_ref);
-			let self_acct = state.active_account().and_then(|account| account.acct.as_deref());
+			let self_account = state.active_account().cloned();
+			let mentions_others = target.mentions.iter().any(|mention| {
+				mention.id != target.account.id
+					&& !self_account.as_ref().is_some_and(|account| mention.is_self(account))
+			});
+			if mentions_others {
+				live_region.announce(if reply_all { "Replying to all" } else { "Replying to author only" });
+			}
 			let Some((reply, config)) = dialogs::prompt_for_reply(
 				frame,
 				target,
 				max_post_chars,
 				&state.poll_limits,
+				&state.media_limits,
 				reply_all,
-				self_acct,
+				self_account.as_ref(),
 				enter_to_send,
+				state.config.cw_marks_sensitive,
+				&state.config.auto_cw_rules,
+				state.config.confirm_discard_compose,
+				&mut state.config.dialog_geometry,
 				false,
 			) else {
 				return;
 			};
+			if reply.save_as_draft {
+				let operation = crate::PostOperation::Reply { in_reply_to_id: target.id.clone() };
+				save_draft(state, live_region, crate::PendingPost { config, operation, last_result: reply });
+				return;
+			}
 			if let Some(handle) = &state.network_handle {
 				state.pending_thread_continuation = reply.continue_thread;
 				state.pending_post = Some(crate::PendingPost {
@@ -380,10 +703,20 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				&target,
 				state.max_post_chars,
 				&state.poll_limits,
+				&state.media_limits,
 				state.config.enter_to_send,
+				state.config.cw_marks_sensitive,
+				&state.config.auto_cw_rules,
+				state.config.confirm_discard_compose,
+				&mut state.config.dialog_geometry,
 			) else {
 				return;
 			};
+			if post.save_as_draft {
+				let operation = crate::PostOperation::Quote { quoted_status_id: target_id };
+				save_draft(state, live_region, crate::PendingPost { config, operation, last_result: post });
+				return;
+			}
 			if let Some(handle) = &state.network_handle {
 				state.pending_thread_continuation = post.continue_thread;
 				state.pending_post = Some(crate::PendingPost {
@@ -505,6 +838,24 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 			let _ = clipboard.set_text(&text);
 			live_region.announce("Post copied");
 		}
+		UiCommand::CopyRawJson => {
+			if !state.config.debug_logging {
+				live_region.announce("Turn on debug logging in Options to copy raw JSON");
+				return;
+			}
+			let Some(status) = get_selected_status(state) else {
+				live_region.announce("No post selected");
+				return;
+			};
+			let Some(raw) = status.raw_json.as_deref() else {
+				live_region.announce("No raw JSON for this post. Refresh the timeline to capture it");
+				return;
+			};
+			let Ok(json) = serde_json::to_string_pretty(raw) else { return };
+			let clipboard = Clipboard::get();
+			let _ = clipboard.set_text(&json);
+			live_region.announce("Raw JSON copied");
+		}
 		UiCommand::CopyPostLink => {
 			let Some(entry) = get_selected_entry(state) else {
 				live_region.announce("No post selected");
@@ -523,23 +874,108 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				live_region.announce("Selected item is not a post");
 			}
 		}
+		UiCommand::AnnouncePostStats => {
+			let Some(status) = get_selected_status(state) else {
+				live_region.announce("No post selected");
+				return;
+			};
+			let target = status.reblog.as_deref().unwrap_or(status);
+			live_region.announce(&target.reading_stats());
+		}
 		UiCommand::Favorite => {
-			do_favorite(state, live_region);
+			do_favorite(state, live_region, frame);
 		}
 		UiCommand::Bookmark => {
 			do_bookmark(state, live_region);
 		}
 		UiCommand::Boost => {
-			do_boost(state, live_region);
+			do_boost(state, live_region, frame);
 		}
 		UiCommand::Pin => {
 			do_pin(state, live_region);
 		}
+		UiCommand::MuteConversation => {
+			do_mute_conversation(state, live_region);
+		}
+		UiCommand::TagBookmark => {
+			do_tag_bookmark(state, frame, live_region);
+		}
+		UiCommand::OpenMediaTimeline => {
+			let scopes = PublicScope::all();
+			let choices: Vec<&str> = scopes.iter().map(|scope| scope.display_name()).collect();
+			let dialog =
+				SingleChoiceDialog::builder(frame, "Show posts with media from", "Media-Only Timeline", &choices)
+					.build();
+			if dialog.show_modal() != ID_OK {
+				return;
+			}
+			let Some(&scope) = usize::try_from(dialog.get_selection()).ok().and_then(|idx| scopes.get(idx)) else {
+				return;
+			};
+			open_timeline(
+				state,
+				timelines_selector,
+				timeline_list,
+				&TimelineType::Media { scope },
+				suppress_selection,
+				live_region,
+				frame,
+			);
+		}
+		UiCommand::FilterBookmarksByTag => {
+			let Some(timeline) = state.timeline_manager.active() else { return };
+			if timeline.timeline_type != TimelineType::Bookmarks {
+				live_region.announce("Tag filters only apply to the Bookmarks timeline");
+				return;
+			}
+			let tags = state.config.known_bookmark_tags();
+			if tags.is_empty() {
+				live_region.announce("No bookmarks have been tagged yet");
+				return;
+			}
+			let mut options = vec!["All bookmarks".to_string()];
+			options.extend(tags.iter().cloned());
+			let options_refs: Vec<&str> = options.iter().map(AsRef::as_ref).collect();
+			let dialog =
+				SingleChoiceDialog::builder(frame, "Show bookmarks tagged", "Filter Bookmarks", &options_refs).build();
+			if let Some(current) = &timeline.bookmark_tag_filter
+				&& let Some(pos) = tags.iter().position(|t| t.eq_ignore_ascii_case(current))
+			{
+				dialog.set_selection(i32::try_from(pos + 1).unwrap_or(0));
+			}
+			if dialog.show_modal() != ID_OK {
+				return;
+			}
+			let Ok(idx) = usize::try_from(dialog.get_selection()) else { return };
+			let filter = if idx == 0 { None } else { tags.get(idx - 1).cloned() };
+			if let Some(timeline) = state.timeline_manager.active_mut() {
+				timeline.bookmark_tag_filter.clone_from(&filter);
+			}
+			match &filter {
+				Some(tag) => live_region.announce(&format!("Showing bookmarks tagged {tag}")),
+				None => live_region.announce("Showing all bookmarks"),
+			}
+			refresh_timeline(state, live_region);
+		}
 		UiCommand::Refresh => {
 			refresh_timeline(state, live_region);
 		}
+		UiCommand::ReconnectStreams => {
+			reconnect_streams(state, live_region);
+		}
+		UiCommand::SystemResumed => {
+			if state.last_resume_refresh.is_some_and(|at| at.elapsed() < RESUME_REFRESH_COOLDOWN) {
+				return;
+			}
+			state.last_resume_refresh = Some(Instant::now());
+			restart_streams(state);
+			if state.timeline_manager.active().is_some_and(|t| t.timeline_type.stream_params().is_none()) {
+				refresh_timeline(state, live_region);
+			}
+		}
 		UiCommand::PollNonStreaming => {
 			poll_non_streaming_timelines(state);
+			refresh_after_filter_expiry(state);
 		}
 		UiCommand::OpenTimeline(timeline_type) => {
 			open_timeline(
@@ -555,6 +991,88 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 		UiCommand::CloseTimeline => {
 			close_timeline(state, timelines_selector, timeline_list, suppress_selection, live_region, false, frame);
 		}
+		UiCommand::TogglePinTimeline => {
+			let Some(active_type) = state.timeline_manager.active().map(|t| t.timeline_type.clone()) else { return };
+			let name = active_type.display_name_with_lists(&state.lists);
+			let Some(account) = state.active_account_mut() else { return };
+			let message = if let Some(pos) = account.pinned_timelines.iter().position(|t| *t == active_type) {
+				account.pinned_timelines.remove(pos);
+				format!("Unpinned {name}")
+			} else {
+				account.pinned_timelines.push(active_type);
+				format!("Pinned {name}")
+			};
+			state.timeline_manager.apply_pins(state.config.pinned_timelines());
+			let _ = config::ConfigStore::new().save(&state.config);
+			refresh_timeline_selector(state, timelines_selector, timeline_list, suppress_selection, frame);
+			if let Some(mb) = frame.get_menu_bar() {
+				update_menu_labels(&mb, state);
+			}
+			live_region.announce(&message);
+		}
+		UiCommand::ToggleFollowLive => {
+			let view_options =
+				state.timeline_manager.active().map(|a| state.timeline_view_options_for(&a.timeline_type));
+			let active_index = state.timeline_manager.active_index();
+			let Some(view_options) = view_options else { return };
+			let Some(active) = state.timeline_manager.active_mut() else { return };
+			active.follow_live = !active.follow_live;
+			let message = if active.follow_live {
+				active.select_newest();
+				update_active_timeline_ui(
+					timeline_list,
+					active,
+					suppress_selection,
+					&view_options,
+					&state.cw_expanded,
+					active_index,
+				);
+				"Following live"
+			} else {
+				"Stopped following live"
+			};
+			if let Some(mb) = frame.get_menu_bar() {
+				update_menu_labels(&mb, state);
+			}
+			live_region.announce(message);
+		}
+		UiCommand::TimelineListFocused => {
+			if !state.config.announce_timeline_on_focus {
+				return;
+			}
+			let names = state.timeline_manager.display_names(&state.lists);
+			let index = state.timeline_manager.active_index();
+			if let Some(name) = names.get(index) {
+				live_region.announce(&format!("{name}, {} of {}", index + 1, names.len()));
+			}
+		}
+		UiCommand::RefreshPost => {
+			let Some(status) = get_selected_status(state) else {
+				live_region.announce("No post selected");
+				return;
+			};
+			let target = status.reblog.as_deref().unwrap_or(status);
+			if foreign_url(state, target.url.as_ref()).is_some() {
+				live_region.announce("Posts can only be refreshed from your own instance");
+				return;
+			}
+			let Some(handle) = &state.network_handle else {
+				live_region.announce("Network not available");
+				return;
+			};
+			handle.send(NetworkCommand::RefreshStatus { status_id: target.id.clone() });
+			live_region.announce("Refreshing post");
+		}
+		UiCommand::CloseSecondaryTimelines => {
+			let closed = state.timeline_manager.close_secondary(state.config.pinned_timelines());
+			if closed == 0 {
+				live_region.announce("No secondary timelines to close");
+				return;
+			}
+			refresh_timeline_selector(state, timelines_selector, timeline_list, suppress_selection, frame);
+			let noun = if closed == 1 { "timeline" } else { "timelines" };
+			live_region.announce(&format!("Closed {closed} {noun}"));
+		}
 		UiCommand::LoadMoreBackground => {
 			if let Some(active) = state.timeline_manager.active_mut() {
 				active.loading_more_in_background = true;
@@ -589,6 +1107,14 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				&& !active.entries.is_empty()
 				&& active.timeline_type.supports_paging()
 			{
+				if active.end_reached {
+					if !active.loading_more_in_background {
+						let is_search = matches!(active.timeline_type, TimelineType::Search { .. });
+						live_region.announce(if is_search { "No more results" } else { "No more posts" });
+					}
+					active.loading_more_in_background = false;
+					return;
+				}
 				if active.loading_more {
 					if active.loading_more_in_background {
 						active.loading_more_in_background = false;
@@ -612,7 +1138,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 							handle.send(NetworkCommand::Search {
 								query: query.clone(),
 								search_type,
-								limit: Some(u32::from(state.config.fetch_limit)),
+								limit: state.config.page_limit(),
 								offset: Some(u32::try_from(active.entries.len()).unwrap()),
 							});
 						} else {
@@ -621,12 +1147,13 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 								// Regular timelines use max_id pagination
 								handle.send(NetworkCommand::FetchTimeline {
 									timeline_type: active.timeline_type.clone(),
-									limit: Some(u32::from(state.config.fetch_limit)),
+									limit: state.config.page_limit(),
 									max_id: Some(max_id),
 								});
 							} else {
 								active.loading_more = false;
-								live_region.announce("No more posts available");
+								active.end_reached = true;
+								live_region.announce("No more posts");
 							}
 						}
 					}
@@ -634,10 +1161,11 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 			}
 		}
 		UiCommand::ToggleContentWarning => {
-			if state.config.content_warning_display != ContentWarningDisplay::WarningOnly {
+			let cw_toggles = state.config.content_warning_display == ContentWarningDisplay::WarningOnly;
+			if !cw_toggles && !state.config.hide_sensitive_media {
 				return;
 			}
-			let _text_options = state.timeline_manager.active().map_or_else(
+			let text_options = state.timeline_manager.active().map_or_else(
 				|| TimelineTextOptions::from_config_default(&state.config),
 				|a| TimelineTextOptions::from_config(&state.config, &a.timeline_type),
 			);
@@ -665,7 +1193,8 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				return;
 			};
 			let target = status.reblog.as_ref().map_or(status, std::convert::AsRef::as_ref);
-			if target.spoiler_text.trim().is_empty() {
+			let hides_media = target.hides_media(&text_options, &text_options.filter_context);
+			if (!cw_toggles || target.spoiler_text.trim().is_empty()) && !hides_media {
 				live_region.announce("No content warning");
 				return;
 			}
@@ -673,6 +1202,9 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 			let expanded = state.cw_expanded.contains(entry_id);
 			if expanded {
 				state.cw_expanded.remove(entry_id);
+				if hides_media {
+					live_region.announce("Sensitive content hidden");
+				}
 			} else {
 				state.cw_expanded.insert(entry_id.to_string());
 			}
@@ -702,7 +1234,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 		}
 		UiCommand::SwitchTimelineByIndex(index) => {
 			if index < state.timeline_manager.len() {
-				if let Some(name) = state.timeline_manager.display_names().get(index) {
+				if let Some(name) = state.timeline_manager.display_names(&state.lists).get(index) {
 					live_region.announce(name);
 				}
 				handle_ui_command(UiCommand::TimelineSelectionChanged(index), ctx);
@@ -710,6 +1242,10 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				live_region.announce("No timeline at this position");
 			}
 		}
+		UiCommand::SwitchToLastTimeline => match state.timeline_manager.len().checked_sub(1) {
+			Some(last) => handle_ui_command(UiCommand::SwitchTimelineByIndex(last), ctx),
+			None => live_region.announce("No timelines open"),
+		},
 		UiCommand::TimelineSelectionChanged(index) => {
 			if index < state.timeline_manager.len() {
 				if let Some(active) = state.timeline_manager.active_mut() {
@@ -744,25 +1280,42 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				if let Some(mb) = frame.get_menu_bar() {
 					update_menu_labels(&mb, state);
 				}
+				request_missing_relationships(state);
 			}
 		}
 		UiCommand::TimelineEntrySelectionChanged(index) => {
+			let mut moved = false;
 			if let Some(active) = state.timeline_manager.active_mut() {
 				let effective_sort_order = active.effective_sort_order(&state.config);
-				active.selected_index = Some(index);
-				active.selected_id = list_index_to_entry_index(index, active.entries.len(), effective_sort_order)
+				let selected_id = list_index_to_entry_index(index, active.entries.len(), effective_sort_order)
 					.map(|entry_index| active.entries[entry_index].id().to_string());
+				moved = selected_id != active.selected_id;
+				let left_newest = active.entries.first().map(TimelineEntry::id) != selected_id.as_deref();
+				active.selected_index = Some(index);
+				active.selected_id = selected_id;
+				if moved && active.follow_live && left_newest {
+					active.follow_live = false;
+					live_region.announce("Stopped following live");
+				}
 			}
 			if let Some(mb) = frame.get_menu_bar() {
 				update_menu_labels(&mb, state);
 			}
+			if moved
+				&& state.config.announce_interaction_counts
+				&& let Some(status) = get_selected_status(state)
+				&& let Some(summary) = status.reblog.as_deref().unwrap_or(status).interaction_summary()
+			{
+				live_region.announce(&summary);
+			}
 		}
 		UiCommand::ShowOptions => {
 			if let Some(options) = dialogs::prompt_for_options(
 				frame,
 				dialogs::OptionsDialogInput {
 					enter_to_send: state.config.enter_to_send,
-					always_show_link_dialog: state.config.always_show_link_dialog,
+					link_open_mode: state.config.link_open_mode,
+					link_prompt_threshold: state.config.link_prompt_threshold,
 					show_link_previews: state.config.show_link_previews,
 					strip_tracking: state.config.strip_tracking,
 					quick_action_keys: state.config.quick_action_keys,
@@ -782,11 +1335,37 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 					filters: state.config.filters.clone(),
 					find_loading_mode: state.config.find_loading_mode,
 					window_title_template: state.config.window_title_template.clone(),
+					oauth_scopes: state.config.oauth_scopes.clone(),
+					announce_stream_status: state.config.announce_stream_status,
+					show_follow_markers: state.config.show_follow_markers,
+					timeline_enter_action: state.config.timeline_enter_action,
+					announce_interaction_counts: state.config.announce_interaction_counts,
+					reply_all_default: state.config.reply_all_default,
+					cw_marks_sensitive: state.config.cw_marks_sensitive,
+					notification_templates: state.config.notification_templates.clone(),
+					confirm_own_interactions: state.config.confirm_own_interactions,
+					show_media_indicator: state.config.show_media_indicator,
+					debug_logging: state.config.debug_logging,
+					proxy_url: state.config.proxy_url.clone(),
+					client_name: state.config.client_name.clone(),
+					client_website: state.config.client_website.clone(),
+					user_agent: state.config.user_agent.clone(),
+					hide_warned_posts: state.config.hide_warned_posts,
+					hide_sensitive_media: state.config.hide_sensitive_media,
+					auto_cw_rules: state.config.auto_cw_rules.clone(),
+					mark_mentions_of_me: state.config.mark_mentions_of_me,
+					mention_sound: state.config.mention_sound,
+					confirm_discard_compose: state.config.confirm_discard_compose,
+					announce_timeline_on_focus: state.config.announce_timeline_on_focus,
+					show_detail_pane: state.config.show_detail_pane,
+					timeline_row_length: state.config.timeline_row_length,
+					show_instance_overview: state.config.show_instance_overview,
 				},
 			) {
 				let dialogs::OptionsDialogResult {
 					enter_to_send,
-					always_show_link_dialog,
+					link_open_mode,
+					link_prompt_threshold,
 					show_link_previews,
 					strip_tracking,
 					quick_action_keys,
@@ -806,6 +1385,31 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 					filters,
 					find_loading_mode,
 					window_title_template,
+					oauth_scopes,
+					announce_stream_status,
+					show_follow_markers,
+					timeline_enter_action,
+					announce_interaction_counts,
+					reply_all_default,
+					cw_marks_sensitive,
+					notification_templates,
+					confirm_own_interactions,
+					show_media_indicator,
+					debug_logging,
+					proxy_url,
+					client_name,
+					client_website,
+					user_agent,
+					hide_warned_posts,
+					hide_sensitive_media,
+					auto_cw_rules,
+					mark_mentions_of_me,
+					mention_sound,
+					confirm_discard_compose,
+					announce_timeline_on_focus,
+					show_detail_pane,
+					timeline_row_length,
+					show_instance_overview,
 				} = options;
 				let needs_refresh = state.config.sort_order != sort_order
 					|| state.config.content_warning_display != content_warning_display
@@ -813,11 +1417,20 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 					|| state.config.preserve_thread_order != preserve_thread_order
 					|| state.config.show_link_previews != show_link_previews
 					|| state.config.templates != templates
+					|| state.config.notification_templates != notification_templates
 					|| state.config.filters != filters
-					|| state.config.window_title_template != window_title_template;
+					|| state.config.window_title_template != window_title_template
+					|| state.config.show_follow_markers != show_follow_markers
+					|| state.config.show_media_indicator != show_media_indicator
+					|| state.config.hide_warned_posts != hide_warned_posts
+					|| state.config.hide_sensitive_media != hide_sensitive_media
+					|| state.config.mark_mentions_of_me != mark_mentions_of_me
+					|| state.config.timeline_row_length != timeline_row_length;
 				let hotkey_changed = state.config.hotkey != hotkey;
+				let connection_changed = state.config.proxy_url != proxy_url || state.config.user_agent != user_agent;
 				state.config.enter_to_send = enter_to_send;
-				state.config.always_show_link_dialog = always_show_link_dialog;
+				state.config.link_open_mode = link_open_mode;
+				state.config.link_prompt_threshold = link_prompt_threshold;
 				state.config.show_link_previews = show_link_previews;
 				state.config.strip_tracking = strip_tracking;
 				state.config.quick_action_keys = quick_action_keys;
@@ -834,11 +1447,41 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				state.config.notification_preference = notification_preference;
 				state.config.hotkey = hotkey;
 				state.config.templates = templates;
+				state.config.notification_templates = notification_templates;
 				state.config.filters = filters;
 				state.config.find_loading_mode = find_loading_mode;
 				state.config.window_title_template = window_title_template;
+				state.config.oauth_scopes = oauth_scopes;
+				state.config.announce_stream_status = announce_stream_status;
+				state.config.show_follow_markers = show_follow_markers;
+				state.config.timeline_enter_action = timeline_enter_action;
+				state.enter_action.set(timeline_enter_action);
+				state.config.announce_interaction_counts = announce_interaction_counts;
+				state.config.reply_all_default = reply_all_default;
+				state.reply_all_default.set(reply_all_default);
+				state.config.cw_marks_sensitive = cw_marks_sensitive;
+				state.config.confirm_own_interactions = confirm_own_interactions;
+				state.config.show_media_indicator = show_media_indicator;
+				state.config.debug_logging = debug_logging;
+				crate::debug_log::set_enabled(debug_logging);
+				state.config.proxy_url = proxy_url;
+				state.config.client_name = client_name;
+				state.config.client_website = client_website;
+				state.config.user_agent = user_agent;
+				state.config.hide_warned_posts = hide_warned_posts;
+				state.config.hide_sensitive_media = hide_sensitive_media;
+				state.config.auto_cw_rules = auto_cw_rules;
+				state.config.mark_mentions_of_me = mark_mentions_of_me;
+				state.config.mention_sound = mention_sound;
+				state.config.confirm_discard_compose = confirm_discard_compose;
+				state.config.announce_timeline_on_focus = announce_timeline_on_focus;
+				state.config.show_detail_pane = show_detail_pane;
+				state.config.timeline_row_length = timeline_row_length;
+				state.config.show_instance_overview = show_instance_overview;
 				update_window_title(state, frame);
-				if state.config.content_warning_display != ContentWarningDisplay::WarningOnly {
+				if state.config.content_warning_display != ContentWarningDisplay::WarningOnly
+					&& !state.config.hide_sensitive_media
+				{
 					state.cw_expanded.clear();
 				}
 				quick_action_keys_enabled.set(quick_action_keys);
@@ -857,6 +1500,16 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				if let Err(err) = store.save(&state.config) {
 					dialogs::show_error(frame, &err);
 				}
+				if connection_changed {
+					// The Options dialog only accepts addresses that parse.
+					let _ = crate::proxy::set_proxy_url(state.config.proxy_url.as_deref());
+					crate::mastodon::set_user_agent(state.config.user_agent.as_deref());
+					live_region.announce("Reconnecting with the new connection settings");
+					switch_to_account(state, frame, timelines_selector, timeline_list, suppress_selection, false, None);
+					if let Some(handle) = &state.network_handle {
+						handle.send(NetworkCommand::CheckConnection);
+					}
+				}
 				if needs_refresh {
 					let view_options =
 						state.timeline_manager.active().map(|a| state.timeline_view_options_for(&a.timeline_type));
@@ -873,14 +1526,36 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 							active_index,
 						);
 					}
+					request_missing_relationships(state);
+				}
+				if let Some(pane) = state.detail_pane {
+					crate::ui::window::set_shown(&pane, state.config.show_detail_pane);
 				}
+				update_detail_pane(state);
+			}
+		}
+		UiCommand::AddAccount => {
+			let _ = start_add_account_flow(frame, ui_tx, state);
+		}
+		UiCommand::SignInAgain => {
+			if state.pending_reauth_account_id.is_some() {
+				live_region.announce("Already signing in");
+				return;
 			}
+			let Some(account_id) = state.active_account().map(|account| account.id.clone()) else {
+				live_region.announce("No account signed in");
+				return;
+			};
+			state.declined_reauth_account_id = None;
+			state.pending_reauth_account_id = Some(account_id.clone());
+			start_reauth_flow(frame, ui_tx, state, &account_id);
 		}
 		UiCommand::ManageAccounts => {
 			let result = dialogs::show_manage_accounts_dialog(
 				frame,
 				&state.config.accounts,
 				state.active_account().map(|a| a.id.as_str()),
+				state.config.display_name_emoji_mode,
 			);
 			match result {
 				dialogs::ManageAccountsResult::Add => {
@@ -935,7 +1610,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 			}
 			let current = state.timeline_manager.active_index();
 			let next = (current + 1) % state.timeline_manager.len();
-			if let Some(name) = state.timeline_manager.display_names().get(next) {
+			if let Some(name) = state.timeline_manager.display_names(&state.lists).get(next) {
 				live_region.announce(name);
 			}
 			handle_ui_command(UiCommand::TimelineSelectionChanged(next), ctx);
@@ -946,15 +1621,20 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 			}
 			let current = state.timeline_manager.active_index();
 			let prev = (current + state.timeline_manager.len() - 1) % state.timeline_manager.len();
-			if let Some(name) = state.timeline_manager.display_names().get(prev) {
+			if let Some(name) = state.timeline_manager.display_names(&state.lists).get(prev) {
 				live_region.announce(name);
 			}
 			handle_ui_command(UiCommand::TimelineSelectionChanged(prev), ctx);
 		}
 		UiCommand::MoveTimelineLeft => {
+			if crosses_pin_boundary(state, state.timeline_manager.active_index().checked_sub(1)) {
+				live_region.announce("Pinned timelines stay ahead of the others");
+				return;
+			}
 			if let Some(new_index) = state.timeline_manager.move_active_left() {
+				sync_pinned_order(state);
 				timelines_selector.clear();
-				let display_names = state.timeline_manager.display_names();
+				let display_names = state.timeline_manager.display_names(&state.lists);
 				for name in &display_names {
 					timelines_selector.append(name);
 				}
@@ -978,9 +1658,14 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 			}
 		}
 		UiCommand::MoveTimelineRight => {
+			if crosses_pin_boundary(state, Some(state.timeline_manager.active_index() + 1)) {
+				live_region.announce("Pinned timelines stay ahead of the others");
+				return;
+			}
 			if let Some(new_index) = state.timeline_manager.move_active_right() {
+				sync_pinned_order(state);
 				timelines_selector.clear();
-				let display_names = state.timeline_manager.display_names();
+				let display_names = state.timeline_manager.display_names(&state.lists);
 				for name in &display_names {
 					timelines_selector.append(name);
 				}
@@ -1005,6 +1690,15 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 		}
 		UiCommand::RemoveAccount(id) => {
 			let is_active = state.config.active_account_id.as_ref() == Some(&id);
+			let revoke = state.config.accounts.iter().find(|a| a.id == id).and_then(|account| {
+				Some(NetworkCommand::RevokeToken {
+					instance: Url::parse(&account.instance).ok()?,
+					client_id: account.client_id.clone()?,
+					client_secret: account.client_secret.clone()?,
+					token: account.access_token.clone()?,
+					handle: account.full_handle(),
+				})
+			});
 			state.config.accounts.retain(|a| a.id != id);
 			state.account_timelines.remove(&id);
 			state.account_cw_expanded.remove(&id);
@@ -1012,33 +1706,47 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 			if is_active {
 				let next_id = state.config.accounts.first().map(|a| a.id.clone());
 				if next_id.is_none() {
-					if !start_add_account_flow(frame, ui_tx, state) {
-						frame.close(true);
-						return;
+					// No network loop is left to report back, so revoke in the background and move on.
+					if let Some(NetworkCommand::RevokeToken { instance, client_id, client_secret, token, .. }) = revoke
+					{
+						std::thread::spawn(move || {
+							if let Ok(client) = MastodonClient::new(instance) {
+								let _ = client.revoke_token(&client_id, &client_secret, &token);
+							}
+						});
 					}
-					// If flow started, we return and wait for OAuthResult
+					show_signed_out_state(state, frame, timelines_selector, timeline_list);
+					live_region.announce("Account removed. No accounts left");
 					return;
 				}
 				switch_to_account(state, frame, timelines_selector, timeline_list, suppress_selection, true, next_id);
 			} else {
 				let _ = config::ConfigStore::new().save(&state.config);
 			}
+			if let Some(revoke) = revoke
+				&& let Some(handle) = &state.network_handle
+			{
+				handle.send(revoke);
+			}
+		}
+		UiCommand::InstanceOverviewLoaded { instance_url, overview } => {
+			continue_add_account_flow(frame, ui_tx, state, instance_url, overview);
 		}
-		UiCommand::OAuthResult { result, instance_url } => {
+		UiCommand::OAuthResult { result, instance_url, reauth_id: reauth_account_id, pkce: supports_pkce } => {
 			if let Some(dialog) = state.pending_auth_dialog.take() {
 				dialog.destroy();
 			}
 			// frame.enable(true) is not needed as we don't disable it anymore
 			frame.raise();
 
-			let mut account = Account::new(instance_url.to_string());
+			let mut account = reauth_account_id
+				.as_ref()
+				.and_then(|id| state.config.accounts.iter().find(|a| &a.id == id).cloned())
+				.unwrap_or_else(|| Account::new(instance_url.to_string()));
 			let client = match MastodonClient::new(instance_url.clone()) {
 				Ok(c) => c,
 				Err(e) => {
 					dialogs::show_error(frame, &anyhow::anyhow!(e));
-					if state.config.accounts.is_empty() {
-						frame.close(true);
-					}
 					return;
 				}
 			};
@@ -1052,7 +1760,9 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				}
 				Err(_) => {
 					// Fallback to OOB
-					if let Some(acc) = try_oob_oauth(frame, &client, &instance_url, &mut account) {
+					if let Some(acc) =
+						try_oob_oauth(frame, &client, &instance_url, &mut account, &state.config, supports_pkce)
+					{
 						account = acc;
 						true
 					} else {
@@ -1067,22 +1777,57 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				}
 			};
 
-			if success {
+			if reauth_account_id.is_some() {
+				state.pending_reauth_account_id = None;
+			}
+			if success
+				&& reauth_account_id.is_some()
+				&& let Some(token) = account.access_token.as_deref()
+			{
+				// Signing in as someone else here would quietly turn this account, and its saved state, into theirs.
+				match client.verify_credentials(token) {
+					Ok(signed_in) if account.user_id.as_ref().is_some_and(|user_id| *user_id != signed_in.id) => {
+						let handle = account.full_handle();
+						let message = format!(
+							"You signed in as @{}, not {handle}. The session was not updated; sign in again as \
+							 {handle} to keep using this account.",
+							signed_in.acct
+						);
+						dialogs::show_warning(frame, &message, "Wrong Account");
+						return;
+					}
+					Ok(signed_in) => account.user_id = Some(signed_in.id),
+					Err(err) => {
+						dialogs::show_error(frame, &err.context("Couldn't check which account you signed in as"));
+						return;
+					}
+				}
+			}
+			if success && reauth_account_id.is_some() {
+				let id = account.id.clone();
+				let handle = account.full_handle();
+				if let Some(existing) = state.config.accounts.iter_mut().find(|a| a.id == id) {
+					*existing = account;
+				}
+				let _ = config::ConfigStore::new().save(&state.config);
+				if state.config.active_account_id.as_ref() == Some(&id) {
+					// Restart networking with the new token; the open timelines are kept as they are.
+					switch_to_account(state, frame, timelines_selector, timeline_list, suppress_selection, false, None);
+					refresh_timeline(state, live_region);
+				}
+				live_region.announce(&format!("Re-authenticated {handle}"));
+			} else if success {
 				let id = account.id.clone();
 				state.config.accounts.push(account);
 				let _ = config::ConfigStore::new().save(&state.config);
 				handle_ui_command(UiCommand::SwitchAccount(id), ctx);
-			} else if state.config.accounts.is_empty() {
-				frame.close(true);
 			}
 		}
 		UiCommand::CancelAuth => {
 			if let Some(dialog) = state.pending_auth_dialog.take() {
 				dialog.destroy();
 			}
-			if state.config.accounts.is_empty() {
-				frame.close(true);
-			}
+			state.pending_reauth_account_id = None;
 		}
 		UiCommand::ViewProfile => {
 			let Some(entry) = get_selected_entry(state) else {
@@ -1103,7 +1848,12 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 								format!("{} (author)", author.display_name_or_username()),
 							];
 							let label_refs: Vec<&str> = labels.iter().map(std::string::String::as_str).collect();
-							match dialogs::prompt_for_account_selection(frame, &accounts, &label_refs) {
+							match dialogs::prompt_for_account_selection(
+								frame,
+								"View Profile of Booster or Author",
+								&accounts,
+								&label_refs,
+							) {
 								Some((acc, act)) => (acc, act),
 								None => return,
 							}
@@ -1120,7 +1870,14 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 					live_region.announce("Cannot view profile for a hashtag");
 					return;
 				}
+				TimelineEntry::MoreReplies(_) => {
+					live_region.announce("No post selected");
+					return;
+				}
 			};
+			if matches!(action, dialogs::UserLookupAction::Profile) {
+				live_region.announce(&format!("Opening profile for @{}", account.acct));
+			}
 
 			if let Some(url) = foreign_url(state, Some(&account.url)) {
 				state.pending_user_lookup_action = Some(action);
@@ -1146,6 +1903,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 							frame,
 							account,
 							state.current_user_id.as_deref(),
+							&state.config,
 							net_tx,
 							ui_tx.clone(),
 							move || {
@@ -1197,7 +1955,12 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 								format!("{} (author)", author.display_name_or_username()),
 							];
 							let label_refs: Vec<&str> = labels.iter().map(std::string::String::as_str).collect();
-							match dialogs::prompt_for_account_choice(frame, &accounts, &label_refs) {
+							match dialogs::prompt_for_account_choice(
+								frame,
+								"Open Timeline of Booster or Author",
+								&accounts,
+								&label_refs,
+							) {
 								Some(acc) => (acc, dialogs::UserLookupAction::Timeline),
 								None => return,
 							}
@@ -1214,6 +1977,10 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 					live_region.announce("Cannot view user timeline for a hashtag");
 					return;
 				}
+				TimelineEntry::MoreReplies(_) => {
+					live_region.announce("No post selected");
+					return;
+				}
 			};
 
 			if let Some(url) = foreign_url(state, Some(&account.url)) {
@@ -1240,6 +2007,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 							frame,
 							account,
 							state.current_user_id.as_deref(),
+							&state.config,
 							net_tx,
 							ui_tx.clone(),
 							move || {
@@ -1275,7 +2043,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 		UiCommand::OpenUserTimelineByInput => {
 			let mut suggestions: Vec<String> = Vec::new();
 			let mut default_value: Option<String> = None;
-			let self_acct = state.active_account().and_then(|a| a.acct.as_deref()).map(|a| format!("@{a}"));
+			let self_acct = state.active_account().map(config::Account::full_handle);
 
 			let mut push_unique = |suggestions: &mut Vec<String>, handle: String| {
 				if self_acct.as_deref() != Some(handle.as_str()) && !suggestions.contains(&handle) {
@@ -1319,7 +2087,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 						default_value = Some(handle.clone());
 						push_unique(&mut suggestions, handle);
 					}
-					TimelineEntry::Hashtag(_) => {}
+					TimelineEntry::Hashtag(_) | TimelineEntry::MoreReplies(_) => {}
 				}
 			}
 
@@ -1339,7 +2107,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 						TimelineEntry::Account(account) => {
 							push_unique(&mut suggestions, format!("@{}", account.full_acct()));
 						}
-						TimelineEntry::Hashtag(_) => {}
+						TimelineEntry::Hashtag(_) | TimelineEntry::MoreReplies(_) => {}
 					}
 				}
 			}
@@ -1383,6 +2151,30 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				}
 			}
 		}
+		UiCommand::GoToPost => {
+			let dialog = TextEntryDialog::builder(frame, "Enter a post ID or link:", "Go to Post").build();
+			if dialog.show_modal() != ID_OK {
+				return;
+			}
+			let input = dialog.get_value().unwrap_or_default().trim().to_string();
+			if input.is_empty() {
+				return;
+			}
+			let Some(command) = go_to_post_command(&input) else {
+				dialogs::show_warning(
+					frame,
+					&format!("\"{input}\" isn't a post ID or a link to a post."),
+					"Invalid Post",
+				);
+				return;
+			};
+			let Some(net) = &state.network_handle else {
+				live_region.announce("Network not available");
+				return;
+			};
+			net.send(command);
+			live_region.announce("Loading post");
+		}
 		UiCommand::ViewMentions => {
 			let Some(status) = get_selected_status(state) else {
 				live_region.announce("No post selected");
@@ -1410,7 +2202,10 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				live_region.announce("No mentions in this post");
 				return;
 			}
-			if let Some((mention, action)) = dialogs::prompt_for_mentions(frame, &all_mentions) {
+			let title = format!("Mentions in Post by @{}", target.account.acct);
+			if let Some((mention, action)) =
+				dialogs::prompt_for_mentions(frame, &title, "Users mentioned in post:", &all_mentions)
+			{
 				if let Some(url) = foreign_url(state, Some(&mention.url)) {
 					state.pending_user_lookup_action = Some(action);
 					if let Some(net) = &state.network_handle {
@@ -1466,6 +2261,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 								frame,
 								account,
 								state.current_user_id.as_deref(),
+								&state.config,
 								net_tx,
 								ui_tx.clone(),
 								move || {
@@ -1491,6 +2287,44 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				}
 			}
 		}
+		UiCommand::FindHandles => {
+			let Some(status) = get_selected_status(state) else {
+				live_region.announce("No post selected");
+				return;
+			};
+			let target = status.reblog.as_deref().unwrap_or(status);
+			let text = format!("{}\n{}", target.spoiler_text, html::strip_html(&target.content));
+			let handles: Vec<crate::mastodon::Mention> = crate::text::find_handles(&text)
+				.into_iter()
+				.filter_map(|acct| {
+					let (username, domain) = acct.split_once('@')?;
+					Some(crate::mastodon::Mention {
+						id: String::new(),
+						username: username.to_string(),
+						url: format!("https://{domain}/@{username}"),
+						acct: acct.clone(),
+					})
+				})
+				.collect();
+			if handles.is_empty() {
+				live_region.announce("No handles in this post");
+				return;
+			}
+			let title = format!("Handles in Post by @{}", target.account.acct);
+			let Some((handle, action)) =
+				dialogs::prompt_for_mentions(frame, &title, "Handles found in post text:", &handles)
+			else {
+				return;
+			};
+			let Some(net) = &state.network_handle else {
+				live_region.announce("Network not available");
+				return;
+			};
+			// Plain text handles are often unknown to the local instance, so resolve them instead of looking them up.
+			state.pending_user_lookup_action = Some(action);
+			net.send(NetworkCommand::ResolveAccount { url: format!("@{}", handle.acct) });
+			live_region.announce(&format!("Looking up @{}", handle.acct));
+		}
 		UiCommand::ViewHashtags => {
 			let Some(status) = get_selected_status(state) else {
 				live_region.announce("No post selected");
@@ -1554,31 +2388,12 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 		}
 		UiCommand::OpenLinks => {
 			let Some(status) = get_selected_status(state) else { return };
-			let target = status.reblog.as_ref().map_or(status, std::convert::AsRef::as_ref);
-			let mut links = html::extract_links(&target.content);
-			if let Some(quote) = &target.quote
-				&& let Some(quoted_status) = &quote.quoted_status
-			{
-				let mut quote_links = html::extract_links(&quoted_status.content);
-				links.append(&mut quote_links);
-				if let Some(quote_url) = &quoted_status.url {
-					links.retain(|link| link.url != *quote_url);
-				}
-			}
-			// Remove duplicates while preserving order
-			let mut seen = std::collections::HashSet::new();
-			links.retain(|link| seen.insert(link.url.clone()));
-
+			let links = post_links(status, state.config.strip_tracking);
 			if links.is_empty() {
 				live_region.announce("No links in this post");
 				return;
 			}
-			if state.config.strip_tracking {
-				for link in &mut links {
-					link.url = html::clean_url(&link.url);
-				}
-			}
-			let url_to_open = if links.len() == 1 && !state.config.always_show_link_dialog {
+			let url_to_open = if !state.config.prompts_for_links(links.len()) {
 				Some(links[0].url.clone())
 			} else {
 				dialogs::show_link_selection_dialog(frame, &links)
@@ -1588,6 +2403,21 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				let _ = launch_default_browser(&url, BrowserLaunchFlags::Default);
 			}
 		}
+		UiCommand::CopyAllLinks => {
+			let Some(status) = get_selected_status(state) else {
+				live_region.announce("No post selected");
+				return;
+			};
+			let links = post_links(status, state.config.strip_tracking);
+			if links.is_empty() {
+				live_region.announce("No links in this post");
+				return;
+			}
+			let text = links.iter().map(|link| link.url.as_str()).collect::<Vec<_>>().join("\n");
+			let _ = Clipboard::get().set_text(&text);
+			let noun = if links.len() == 1 { "link" } else { "links" };
+			live_region.announce(&format!("Copied {} {noun}", links.len()));
+		}
 		UiCommand::ToggleFollow => {
 			let Some(status) = get_selected_status(state) else {
 				live_region.announce("No post selected");
@@ -1641,6 +2471,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 					"Select User",
 					"Select user to follow/unfollow:",
 					&all_users,
+					state.config.display_name_emoji_mode,
 				) {
 					acc
 				} else {
@@ -1658,6 +2489,20 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				live_region.announce("Network not available");
 			}
 		}
+		UiCommand::FollowBack => {
+			let Some(notification) = get_selected_follow_notification(state) else {
+				live_region.announce("No follow notification selected");
+				return;
+			};
+			if let Some(net) = &state.network_handle {
+				net.send(NetworkCommand::FollowBack {
+					account_id: notification.account.id.clone(),
+					target_name: format!("@{}", notification.account.acct),
+				});
+			} else {
+				live_region.announce("Network not available");
+			}
+		}
 		UiCommand::PlayMedia => {
 			let Some(status) = get_selected_status(state) else {
 				live_region.announce("No post selected");
@@ -1708,7 +2553,11 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				}
 			};
 
-			crate::ui::dialogs::show_media_player(frame, media.url.clone(), state.access_token.clone());
+			let Some(url) = media.playable_url() else {
+				live_region.announce("This media has no link to play");
+				return;
+			};
+			crate::ui::dialogs::show_media_player(frame, url.to_string(), state.access_token.clone());
 		}
 		UiCommand::ViewInBrowser => {
 			let Some(status) = get_selected_status(state) else {
@@ -1795,6 +2644,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 							frame,
 							account.clone(),
 							state.current_user_id.as_deref(),
+							&state.config,
 							net_tx,
 							ui_tx.clone(),
 							move || {
@@ -1822,7 +2672,11 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 						frame,
 					);
 					if let Some(handle) = &state.network_handle {
-						handle.send(NetworkCommand::FetchTimeline { timeline_type, limit: Some(40), max_id: None });
+						handle.send(NetworkCommand::FetchTimeline {
+							timeline_type,
+							limit: state.config.page_limit(),
+							max_id: None,
+						});
 					}
 				}
 				TimelineEntry::Notification(notification) if notification.kind == "follow_request" => {
@@ -1867,6 +2721,16 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 						}
 						return;
 					}
+					// A notification carries the post as it was when it arrived, so fetch it again before opening.
+					if matches!(entry, TimelineEntry::Notification(_)) {
+						let Some(handle) = &state.network_handle else {
+							live_region.announce("Network not available");
+							return;
+						};
+						handle.send(NetworkCommand::FetchStatus { id: target.id.clone() });
+						live_region.announce("Loading post");
+						return;
+					}
 
 					let name = format!("Thread: {}", target.account.display_name_or_username());
 					let timeline_type = TimelineType::Thread { id: target.id.clone(), name };
@@ -1886,6 +2750,9 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 					};
 					handle.send(NetworkCommand::FetchThread { timeline_type, focus: Box::new(target.clone()) });
 				}
+				TimelineEntry::MoreReplies(_) => {
+					live_region.announce("No post selected");
+				}
 			}
 		}
 		UiCommand::ViewResolvedThread(focus) => {
@@ -1905,6 +2772,36 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				handle.send(NetworkCommand::FetchThread { timeline_type, focus });
 			}
 		}
+		UiCommand::LoadMoreReplies => {
+			let Some(timeline) = state.timeline_manager.active() else { return };
+			if !matches!(timeline.timeline_type, TimelineType::Thread { .. }) {
+				return;
+			}
+			let status_id = match get_selected_entry(state) {
+				Some(TimelineEntry::MoreReplies(more)) => more.parent_id.clone(),
+				Some(entry) => match entry.as_status() {
+					Some(status) if timeline.has_unloaded_replies(status) => status.id.clone(),
+					Some(_) => {
+						live_region.announce("No more replies");
+						return;
+					}
+					None => {
+						live_region.announce("No post selected");
+						return;
+					}
+				},
+				None => {
+					live_region.announce("No post selected");
+					return;
+				}
+			};
+			let Some(handle) = &state.network_handle else {
+				live_region.announce("Network not available");
+				return;
+			};
+			live_region.announce("Loading more replies");
+			handle.send(NetworkCommand::FetchThreadMore { timeline_type: timeline.timeline_type.clone(), status_id });
+		}
 		UiCommand::ViewQuotedThread => {
 			let quoted_info = get_selected_status(state).map_or_else(
 				|| {
@@ -1955,7 +2852,13 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				return;
 			};
 			let post_text = target.display_text();
-			if let Some(choices) = dialogs::prompt_for_vote(frame, poll, &post_text) {
+			if let Some(choices) = dialogs::prompt_for_vote(
+				frame,
+				&format!("@{}", target.account.acct),
+				poll,
+				&post_text,
+				state.config.display_name_emoji_mode,
+			) {
 				if let Some(handle) = &state.network_handle {
 					let is_foreign = matches!(
 						state.timeline_manager.active().map(|t| &t.timeline_type),
@@ -1984,6 +2887,10 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 			}
 		}
 		UiCommand::ViewHelp => {
+			let shortcuts = crate::keymap::shortcuts(&state.config);
+			if !dialogs::show_shortcuts_dialog(frame, shortcuts) {
+				return;
+			}
 			if let Ok(mut path) = std::env::current_exe() {
 				path.pop();
 				path.push("readme.html");
@@ -2009,8 +2916,10 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				live_region.announce("No post selected");
 				return;
 			};
-			let target = status.reblog.as_ref().map_or(status, std::convert::AsRef::as_ref);
-			if let Some(next_cmd) = crate::ui::dialogs::show_post_view_dialog(frame, target) {
+			let target = status.reblog.as_deref().unwrap_or(status).clone();
+			if let Some(next_cmd) =
+				crate::ui::dialogs::show_post_view_dialog(frame, &target, &mut state.config.dialog_geometry)
+			{
 				handle_ui_command(next_cmd, ctx);
 			}
 		}
@@ -2027,12 +2936,46 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 					frame,
 				);
 				if let Some(handle) = &state.network_handle {
-					handle.send(NetworkCommand::Search { query, search_type, limit: Some(40), offset: None });
+					handle.send(NetworkCommand::Search {
+						query,
+						search_type,
+						limit: state.config.page_limit(),
+						offset: None,
+					});
 				}
 			}
 		}
 		UiCommand::CheckForUpdates => {
-			crate::ui::update_check::run_update_check(*frame, false);
+			live_region.announce("Checking for updates");
+			crate::ui::update_check::run_update_check(*frame, state.config.update_channel, false);
+		}
+		UiCommand::ShowLastError => {
+			if let Some(details) = &state.last_error {
+				dialogs::show_error_details_dialog(frame, details);
+			} else {
+				live_region.announce("No recent errors");
+			}
+		}
+		UiCommand::ShowInstanceInfo => {
+			if let Some(info) = &state.instance_info {
+				dialogs::show_instance_details_dialog(frame, &info.display());
+			} else {
+				live_region.announce("Instance information isn't available");
+			}
+		}
+		UiCommand::OpenLogFolder => {
+			let dir = crate::debug_log::log_dir();
+			if state.config.debug_logging {
+				live_region.announce("Opening log folder");
+			} else {
+				live_region.announce("Opening log folder. Debug logging is turned off in Options");
+			}
+			if !wxdragon::utils::launch_default_browser(
+				&dir.to_string_lossy(),
+				wxdragon::utils::BrowserLaunchFlags::Default,
+			) {
+				dialogs::show_error(frame, &anyhow::anyhow!("Could not open {}", dir.display()));
+			}
 		}
 		UiCommand::OpenList => {
 			if let Some(handle) = &state.network_handle {
@@ -2121,11 +3064,41 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 							}
 							Err(e) => dialogs::show_error(frame, &e),
 						},
+						dialogs::ManageFiltersResult::Export => {
+							if let Some(path) = dialogs::prompt_filter_file(frame, true) {
+								let written = serde_json::to_string_pretty(&filters)
+									.map_err(anyhow::Error::from)
+									.and_then(|json| std::fs::write(&path, json).map_err(anyhow::Error::from));
+								match written {
+									Ok(()) => live_region.announce(&format!("Exported {} filters", filters.len())),
+									Err(e) => dialogs::show_error(frame, &e),
+								}
+							}
+						}
+						dialogs::ManageFiltersResult::Import => {
+							if let Some(path) = dialogs::prompt_filter_file(frame, false) {
+								match import_filters(client, token, &filters, &path) {
+									Ok(report) => {
+										if let Ok(new_filters) = client.get_filters(token) {
+											filters = new_filters;
+										}
+										let dialog = MessageDialog::builder(frame, &report, "Import Filters")
+											.with_style(MessageDialogStyle::OK | MessageDialogStyle::IconInformation)
+											.build();
+										dialog.show_modal();
+									}
+									Err(e) => dialogs::show_error(frame, &e),
+								}
+							}
+						}
 						dialogs::ManageFiltersResult::None => break,
 					}
 				},
 				Err(e) => dialogs::show_error(frame, &e),
 			}
+			if let Some(handle) = &state.network_handle {
+				handle.send(NetworkCommand::FetchFilters);
+			}
 		}
 		UiCommand::ManageLists => {
 			if let Some(handle) = &state.network_handle {
@@ -2146,8 +3119,112 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				live_region.announce("Network not available");
 			}
 		}
-		UiCommand::AddUserToList(account_id) => {
-			state.pending_add_to_list_user = Some(account_id);
+		UiCommand::ManageCurrentList => {
+			let Some(TimelineType::List { id, .. }) = state.timeline_manager.active().map(|t| &t.timeline_type) else {
+				live_region.announce("The current timeline is not a list");
+				return;
+			};
+			if let Some(dlg) = &state.manage_list_members_dialog
+				&& dlg.get_list_id() == id
+			{
+				dlg.show();
+				return;
+			}
+			let Some(handle) = &state.network_handle else {
+				live_region.announce("Network not available");
+				return;
+			};
+			handle.send(NetworkCommand::FetchListAccounts { list_id: id.clone() });
+			state.pending_manage_list_id = Some(id.clone());
+		}
+		UiCommand::AddAuthorToList => {
+			let Some(account) = get_selected_entry(state).and_then(TimelineEntry::author) else {
+				live_region.announce("No author selected");
+				return;
+			};
+			let (account_id, acct) = (account.id.clone(), account.acct.clone());
+			handle_ui_command(UiCommand::AddUserToList { account_id, acct }, ctx);
+		}
+		UiCommand::ToggleHiddenBoosts { acct, name } => {
+			let key = acct.to_lowercase();
+			if state.config.hide_boosts_from.remove(&key) {
+				live_region.announce(&format!("Showing boosts from {name} again. Refresh to load them"));
+			} else {
+				state.config.hide_boosts_from.insert(key);
+				for timeline in state.timeline_manager.iter_mut() {
+					timeline
+						.entries
+						.retain(|entry| entry.as_status().is_none_or(|s| !s.is_hidden_boost(&state.config)));
+				}
+				let view_options =
+					state.timeline_manager.active().map(|a| state.timeline_view_options_for(&a.timeline_type));
+				let active_index = state.timeline_manager.active_index();
+				if let Some(view_options) = view_options
+					&& let Some(active) = state.timeline_manager.active_mut()
+				{
+					update_active_timeline_ui(
+						timeline_list,
+						active,
+						suppress_selection,
+						&view_options,
+						&state.cw_expanded,
+						active_index,
+					);
+				}
+				live_region.announce(&format!("Hiding boosts from {name}"));
+			}
+			let _ = config::ConfigStore::new().save(&state.config);
+		}
+		UiCommand::HideBoostsFromBooster => {
+			let Some(status) = get_selected_status(state).filter(|s| s.reblog.is_some()) else {
+				live_region.announce("Selected post is not a boost");
+				return;
+			};
+			let acct = status.account.full_acct();
+			let name = status.account.timeline_display_name(state.config.display_name_emoji_mode);
+			handle_ui_command(UiCommand::ToggleHiddenBoosts { acct, name }, ctx);
+		}
+		UiCommand::CreateListFromAuthors => {
+			let mut seen = std::collections::HashSet::new();
+			let authors: Vec<_> = state
+				.timeline_manager
+				.active()
+				.map(|active| {
+					active
+						.entries
+						.iter()
+						.filter_map(TimelineEntry::author)
+						.filter(|account| state.current_user_id.as_deref() != Some(account.id.as_str()))
+						.filter(|account| seen.insert(account.id.clone()))
+						.cloned()
+						.collect()
+				})
+				.unwrap_or_default();
+			if authors.is_empty() {
+				live_region.announce("No authors in this timeline");
+				return;
+			}
+			let Some(members) = dialogs::prompt_for_list_members(frame, &authors) else {
+				return;
+			};
+			if members.is_empty() {
+				live_region.announce("No authors selected");
+				return;
+			}
+			let Some((title, policy, exclusive)) = dialogs::show_list_edit_dialog(frame, None, None, false) else {
+				return;
+			};
+			let Some(handle) = &state.network_handle else {
+				live_region.announce("Network not available");
+				return;
+			};
+			live_region.announce(&format!("Creating list '{title}'"));
+			state.pending_new_list_members =
+				Some((title.clone(), members.into_iter().map(|account| account.id).collect()));
+			handle.send(NetworkCommand::CreateList { title, policy, exclusive });
+		}
+		UiCommand::AddUserToList { account_id, acct } => {
+			state.pending_add_to_list_user = Some((account_id, acct));
 			if let Some(handle) = &state.network_handle {
 				handle.send(NetworkCommand::FetchLists);
 			} else {
@@ -2262,6 +3339,28 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 				live_region.announce("No active search");
 			}
 		}
+		UiCommand::NextMentionOfMe => {
+			let Some(account) = state.active_account().cloned() else {
+				live_region.announce("Not logged in");
+				return;
+			};
+			let Some(active) = state.timeline_manager.active_mut() else { return };
+			let effective_sort_order = active.effective_sort_order(&state.config);
+			let start_index = active.selected_index.map_or(0, |i| i + 1);
+			let Some(index) = active.next_mention_of(start_index, &state.config, &account) else {
+				live_region.announce("No mentions of you found");
+				return;
+			};
+			let Some(list_index) =
+				crate::ui::timeline_view::entry_index_to_list_index(index, active.entries.len(), effective_sort_order)
+			else {
+				return;
+			};
+			active.selected_index = Some(list_index);
+			active.selected_id = Some(active.entries[index].id().to_string());
+			timeline_list
+				.set_selection(active.selected_id.as_deref().map(crate::ui::timeline_view::entry_id_to_node_id));
+		}
 		UiCommand::FindPrev => {
 			if let Some(active) = state.timeline_manager.active_mut()
 				&& active.find_query.is_some()
@@ -2366,7 +3465,16 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 			}
 		}
 		UiCommand::AppClosing => {
-			state.config.saved_timelines = state.timeline_manager.open_timeline_types();
+			let open_timelines = state.timeline_manager.open_timeline_types();
+			if let Some(account) = state.active_account_mut() {
+				account.saved_timelines = open_timelines;
+			}
+			for (id, manager) in &state.account_timelines {
+				if let Some(account) = state.config.accounts.iter_mut().find(|a| a.id == *id) {
+					account.saved_timelines = manager.open_timeline_types();
+				}
+			}
+			state.config.saved_timelines.clear();
 			state.config.saved_active_timeline = state.timeline_manager.active().map(|t| t.timeline_type.clone());
 			state.config.saved_selected_post_id = state.timeline_manager.active().and_then(|t| t.selected_id.clone());
 			let _ = config::ConfigStore::new().save(&state.config);
@@ -2375,27 +3483,62 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 		UiCommand::ExitApp => {
 			ctx.frame.close(true);
 		}
+		UiCommand::ResumeDraft => {
+			let Some(account) = state.active_account_mut() else { return };
+			let index = match account.drafts.len() {
+				0 => {
+					live_region.announce("No saved drafts");
+					return;
+				}
+				1 => 0,
+				_ => {
+					let labels: Vec<String> = account.drafts.iter().map(draft_label).collect();
+					let label_refs: Vec<&str> = labels.iter().map(AsRef::as_ref).collect();
+					let dialog =
+						SingleChoiceDialog::builder(frame, "Choose a draft to resume", "Resume Draft", &label_refs)
+							.build();
+					dialog.set_selection(i32::try_from(labels.len() - 1).unwrap_or(0));
+					if dialog.show_modal() != ID_OK {
+						return;
+					}
+					let Ok(index) = usize::try_from(dialog.get_selection()) else { return };
+					index
+				}
+			};
+			if index >= account.drafts.len() {
+				return;
+			}
+			let draft = account.drafts.remove(index);
+			let _ = config::ConfigStore::new().save(&state.config);
+			state.pending_post = Some(draft);
+			handle_ui_command(UiCommand::RecoverDraft, ctx);
+		}
 		UiCommand::RecoverDraft => {
 			let Some(pending) = state.pending_post.take() else { return };
-			let mut config = pending.config;
-			config.initial_content = pending.last_result.content;
-			config.initial_cw = pending.last_result.spoiler_text;
-			config.initial_sensitive = pending.last_result.sensitive;
-			config.initial_language = pending.last_result.language;
-			config.default_visibility = pending.last_result.visibility;
-			config.initial_thread_mode = pending.last_result.continue_thread;
+			let config = reopen_compose_config(pending.config, &pending.last_result);
 
 			let Some((new_post, new_config)) = dialogs::prompt_for_compose(
 				ctx.frame,
 				state.max_post_chars,
 				&state.poll_limits,
+				&state.media_limits,
 				state.config.enter_to_send,
+				state.config.cw_marks_sensitive,
+				&state.config.auto_cw_rules,
+				state.config.confirm_discard_compose,
+				&mut state.config.dialog_geometry,
 				config,
 				pending.last_result.media,
 				pending.last_result.poll,
 			) else {
 				return;
 			};
+			if new_post.save_as_draft {
+				let draft =
+					crate::PendingPost { config: new_config, operation: pending.operation, last_result: new_post };
+				save_draft(state, live_region, draft);
+				return;
+			}
 
 			let quoted_id = match &pending.operation {
 				crate::PostOperation::Quote { quoted_status_id } => Some(quoted_status_id.clone()),
@@ -2419,21 +3562,7 @@ pub fn handle_ui_command(cmd: UiCommand, ctx: &mut UiCommandContext<'_>) {
 					scheduled_at: post_data.scheduled_at,
 				},
 				crate::PostOperation::Edit { ref status_id } => {
-					let media = new_post
-						.media
-						.clone()
-						.into_iter()
-						.map(|item| {
-							if item.is_existing {
-								network::EditMedia::Existing(item.path)
-							} else {
-								network::EditMedia::New(network::MediaUpload {
-									path: item.path,
-									description: item.description,
-								})
-							}
-						})
-						.collect();
+					let media = edit_media(new_post.media.clone());
 					NetworkCommand::EditStatus {
 						status_id: status_id.clone(),
 						content: post_data.content,
@@ -2480,11 +3609,65 @@ pub fn get_selected_entry(state: &AppState) -> Option<&TimelineEntry> {
 	timeline.entries.get(final_index)
 }
 
+/// Shows the selected entry laid out in full in the detail pane while it is turned on.
+pub fn update_detail_pane(state: &AppState) {
+	let Some(pane) = state.detail_pane else { return };
+	if !state.config.show_detail_pane {
+		return;
+	}
+	let text = state
+		.timeline_manager
+		.active()
+		.zip(get_selected_entry(state))
+		.map(|(timeline, entry)| {
+			let options = state.timeline_view_options_for(&timeline.timeline_type);
+			let text_options = options.text_options.with_thread_replies(timeline);
+			entry.detail_text(&text_options, state.cw_expanded.contains(entry.id()))
+		})
+		.unwrap_or_default();
+	if pane.get_value() != text {
+		pane.set_value(&text);
+	}
+}
+
 /// Gets the currently selected status (unwrapping from notification if needed).
 pub fn get_selected_status(state: &AppState) -> Option<&Status> {
 	get_selected_entry(state)?.as_status()
 }
 
+/// Gets the selected notification if it is a new follower notification.
+pub fn get_selected_follow_notification(state: &AppState) -> Option<&crate::mastodon::Notification> {
+	match get_selected_entry(state)? {
+		TimelineEntry::Notification(notification) if notification.kind == "follow" => Some(notification),
+		_ => None,
+	}
+}
+
+/// Links in a post and any post it quotes, in order and without duplicates. A link to the quoted post itself is left
+/// out.
+fn post_links(status: &Status, strip_tracking: bool) -> Vec<html::Link> {
+	let target = status.reblog.as_deref().unwrap_or(status);
+	let mut links = html::extract_links(&target.content);
+	if let Some(quote) = &target.quote
+		&& let Some(quoted_status) = &quote.quoted_status
+	{
+		let mut quote_links = html::extract_links(&quoted_status.content);
+		links.append(&mut quote_links);
+		if let Some(quote_url) = &quoted_status.url {
+			links.retain(|link| link.url != *quote_url);
+		}
+	}
+	// Remove duplicates while preserving order
+	let mut seen = std::collections::HashSet::new();
+	links.retain(|link| seen.insert(link.url.clone()));
+	if strip_tracking {
+		for link in &mut links {
+			link.url = html::clean_url(&link.url);
+		}
+	}
+	links
+}
+
 /// Returns the URL if the active timeline is for a foreign instance.
 pub fn foreign_url(state: &AppState, url: Option<&String>) -> Option<String> {
 	if matches!(state.timeline_manager.active().map(|t| &t.timeline_type), Some(TimelineType::InstanceLocal { .. })) {
@@ -2495,7 +3678,31 @@ pub fn foreign_url(state: &AppState, url: Option<&String>) -> Option<String> {
 }
 
 /// Sends a favorite or unfavorite request for the selected status.
-fn do_favorite(state: &AppState, live_region: &crate::ui::timeline_list::TimelineList) {
+/// Asks before boosting or favoriting one of your own posts, if that's turned on. Returns false when the user declines.
+fn confirm_own_post_interaction(
+	frame: &Frame,
+	state: &AppState,
+	target: &crate::mastodon::Status,
+	action: &str,
+	live_region: &crate::ui::timeline_list::TimelineList,
+) -> bool {
+	if !state.config.confirm_own_interactions
+		|| !state.active_account().is_some_and(|account| target.account.is_self(account))
+	{
+		return true;
+	}
+	let confirm = MessageDialog::builder(frame, &format!("This is your own post. {action} it anyway?"), action)
+		.with_style(MessageDialogStyle::YesNo | MessageDialogStyle::IconQuestion)
+		.build();
+	if confirm.show_modal() == ID_YES {
+		true
+	} else {
+		live_region.announce(&format!("{action} cancelled"));
+		false
+	}
+}
+
+fn do_favorite(state: &AppState, live_region: &crate::ui::timeline_list::TimelineList, frame: &Frame) {
 	let Some(status) = get_selected_status(state) else {
 		live_region.announce("No post selected");
 		return;
@@ -2505,6 +3712,9 @@ fn do_favorite(state: &AppState, live_region: &crate::ui::timeline_list::Timelin
 		return;
 	};
 	let target = status.reblog.as_ref().map_or(status, std::convert::AsRef::as_ref);
+	if !target.favourited && !confirm_own_post_interaction(frame, state, target, "Favorite", live_region) {
+		return;
+	}
 
 	let is_foreign =
 		matches!(state.timeline_manager.active().map(|t| &t.timeline_type), Some(TimelineType::InstanceLocal { .. }));
@@ -2550,6 +3760,16 @@ fn do_bookmark(state: &AppState, live_region: &crate::ui::timeline_list::Timelin
 	}
 }
 
+/// The request that finds the post named in Go to Post: a web link is looked up through search, and anything else has
+/// to look like a status ID.
+fn go_to_post_command(input: &str) -> Option<NetworkCommand> {
+	if let Ok(url) = Url::parse(input) {
+		return (matches!(url.scheme(), "http" | "https") && url.host_str().is_some())
+			.then(|| NetworkCommand::ResolveStatusForThread { url: input.to_string() });
+	}
+	input.chars().all(|c| c.is_ascii_alphanumeric()).then(|| NetworkCommand::FetchStatus { id: input.to_string() })
+}
+
 /// Derive a Mastodon `acct` (`user` or `user@instance`) from the display text and URL of a
 /// mention link parsed out of post HTML.  The display text from Mastodon HTML is already in the
 /// right format (`@user` or `@user@instance`), so we prefer it; the URL is the fallback.
@@ -2604,7 +3824,79 @@ fn do_pin(state: &AppState, live_region: &crate::ui::timeline_list::TimelineList
 	}
 }
 
-fn do_boost(state: &AppState, live_region: &crate::ui::timeline_list::TimelineList) {
+/// Mutes or unmutes notifications from the conversation the selected status belongs to.
+fn do_mute_conversation(state: &AppState, live_region: &crate::ui::timeline_list::TimelineList) {
+	let Some(status) = get_selected_status(state) else {
+		live_region.announce("No post selected");
+		return;
+	};
+	let Some(handle) = &state.network_handle else {
+		live_region.announce("Network not available");
+		return;
+	};
+	if matches!(state.timeline_manager.active().map(|t| &t.timeline_type), Some(TimelineType::InstanceLocal { .. })) {
+		live_region.announce("Conversations can only be muted from your own instance");
+		return;
+	}
+	let target = status.reblog.as_ref().map_or(status, std::convert::AsRef::as_ref);
+	let status_id = target.id.clone();
+	if target.muted {
+		handle.send(NetworkCommand::UnmuteConversation { status_id });
+	} else {
+		handle.send(NetworkCommand::MuteConversation { status_id });
+	}
+}
+
+fn do_tag_bookmark(state: &mut AppState, frame: &Frame, live_region: &crate::ui::timeline_list::TimelineList) {
+	let Some(status) = get_selected_status(state) else {
+		live_region.announce("No post selected");
+		return;
+	};
+	let target = status.reblog.as_ref().map_or(status, std::convert::AsRef::as_ref);
+	if !target.bookmarked {
+		live_region.announce("Only bookmarked posts can be tagged");
+		return;
+	}
+	let status_id = target.id.clone();
+	let current = state.config.bookmark_tags(&status_id).join(", ");
+	let dialog = TextEntryDialog::builder(frame, "Tags for this bookmark, separated by commas:", "Tag Bookmark")
+		.with_default_value(&current)
+		.build();
+	if dialog.show_modal() != ID_OK {
+		dialog.destroy();
+		return;
+	}
+	let tags = config::parse_bookmark_tags(&dialog.get_value().unwrap_or_default());
+	dialog.destroy();
+	let Some(account) = state.active_account_mut() else { return };
+	if tags.is_empty() {
+		account.bookmark_tags.remove(&status_id);
+		live_region.announce("Bookmark tags cleared");
+	} else {
+		live_region.announce(&format!("Bookmark tagged {}", tags.join(", ")));
+		account.bookmark_tags.insert(status_id, tags);
+	}
+	let _ = config::ConfigStore::new().save(&state.config);
+	refetch_tagged_bookmarks(state);
+}
+
+/// Reloads the Bookmarks timeline when it is filtered by tag, since the filter is only applied as posts load.
+pub fn refetch_tagged_bookmarks(state: &AppState) {
+	let filtered = state
+		.timeline_manager
+		.timelines()
+		.iter()
+		.any(|t| t.timeline_type == TimelineType::Bookmarks && t.bookmark_tag_filter.is_some());
+	if filtered && let Some(handle) = &state.network_handle {
+		handle.send(NetworkCommand::FetchTimeline {
+			timeline_type: TimelineType::Bookmarks,
+			limit: state.config.page_limit(),
+			max_id: None,
+		});
+	}
+}
+
+fn do_boost(state: &AppState, live_region: &crate::ui::timeline_list::TimelineList, frame: &Frame) {
 	let Some(status) = get_selected_status(state) else {
 		live_region.announce("No post selected");
 		return;
@@ -2618,6 +3910,9 @@ fn do_boost(state: &AppState, live_region: &crate::ui::timeline_list::TimelineLi
 		live_region.announce("Cannot boost direct messages");
 		return;
 	}
+	if !target.reblogged && !confirm_own_post_interaction(frame, state, target, "Boost", live_region) {
+		return;
+	}
 
 	if let Some(url) = foreign_url(state, target.url.as_ref()) {
 		let interaction = if target.reblogged { ForeignInteraction::Unboost } else { ForeignInteraction::Boost };
@@ -2633,6 +3928,47 @@ fn do_boost(state: &AppState, live_region: &crate::ui::timeline_list::TimelineLi
 	}
 }
 
+/// Creates each filter from an exported file, skipping titles that already exist and ones that have expired. Returns a
+/// line per filter describing what happened to it.
+fn import_filters(client: &MastodonClient, token: &str, existing: &[Filter], path: &str) -> anyhow::Result<String> {
+	let contents = std::fs::read_to_string(path)?;
+	let imported: Vec<Filter> = serde_json::from_str(&contents)?;
+	let mut titles: Vec<String> = existing.iter().map(|f| f.title.to_lowercase()).collect();
+	let now = chrono::Utc::now();
+	let mut lines = Vec::new();
+	let (mut created, mut failed) = (0, 0);
+	for filter in imported {
+		if titles.contains(&filter.title.to_lowercase()) {
+			lines.push(format!("{}: skipped, a filter with this title already exists", filter.title));
+			continue;
+		}
+		let expires_at = filter.expires_at.as_deref().and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok());
+		let expires_in = match expires_at {
+			Some(at) if at <= now => {
+				lines.push(format!("{}: skipped, it has expired", filter.title));
+				continue;
+			}
+			Some(at) => u32::try_from((at.with_timezone(&chrono::Utc) - now).num_seconds()).ok(),
+			None => None,
+		};
+		let contexts: Vec<_> = filter.context.into_iter().filter(|c| *c != FilterContext::Unknown).collect();
+		let keywords: Vec<(String, bool)> = filter.keywords.into_iter().map(|k| (k.keyword, k.whole_word)).collect();
+		match client.create_filter(token, &filter.title, &contexts, &filter.action, &keywords, expires_in) {
+			Ok(_) => {
+				created += 1;
+				lines.push(format!("{}: imported", filter.title));
+				titles.push(filter.title.to_lowercase());
+			}
+			Err(e) => {
+				failed += 1;
+				lines.push(format!("{}: failed, {e}", filter.title));
+			}
+		}
+	}
+	let skipped = lines.len() - created - failed;
+	Ok(format!("Imported {created}, skipped {skipped}, failed {failed}.\n\n{}", lines.join("\n")))
+}
+
 /// Opens a new timeline or switches to it if already open.
 fn open_timeline(
 	state: &mut AppState,
@@ -2678,8 +4014,17 @@ fn open_timeline(
 		live_region.announce("Timeline already open");
 		return;
 	}
-	selector.append(&timeline_type.display_name());
-	let new_index = state.timeline_manager.len() - 1;
+	state.timeline_manager.apply_pins(state.config.pinned_timelines());
+	let new_index = state.timeline_manager.index_of(timeline_type).unwrap_or(state.timeline_manager.len() - 1);
+	if new_index + 1 == state.timeline_manager.len() {
+		selector.append(&timeline_type.display_name_with_lists(&state.lists));
+	} else {
+		// A pinned timeline was opened and moved ahead of the others.
+		selector.clear();
+		for name in state.timeline_manager.display_names(&state.lists) {
+			selector.append(&name);
+		}
+	}
 	state.timeline_manager.set_active(new_index);
 	update_window_title(state, frame);
 	with_suppressed_selection(suppress_selection, || {
@@ -2689,7 +4034,7 @@ fn open_timeline(
 		if let Some(handle) = &state.network_handle {
 			handle.send(NetworkCommand::FetchTimeline {
 				timeline_type: timeline_type.clone(),
-				limit: Some(40),
+				limit: state.config.page_limit(),
 				max_id: None,
 			});
 		}
@@ -2725,13 +4070,62 @@ fn close_timeline(
 		return;
 	}
 	let active_index = state.timeline_manager.active_index();
-	let active_name = state.timeline_manager.display_names().get(active_index).cloned();
+	let active_name = state.timeline_manager.display_names(&state.lists).get(active_index).cloned();
 	if let Some(name) = &active_name {
 		live_region.announce(name);
 	}
+	refresh_timeline_selector(state, selector, timeline_list, suppress_selection, frame);
+}
+
+/// Whether moving the active timeline past `neighbor` would carry it across the line between pinned and unpinned
+/// timelines.
+fn crosses_pin_boundary(state: &AppState, neighbor: Option<usize>) -> bool {
+	let timelines = state.timeline_manager.timelines();
+	let is_pinned =
+		|index: usize| timelines.get(index).is_some_and(|t| state.config.pinned_timelines().contains(&t.timeline_type));
+	neighbor.is_some_and(|neighbor| {
+		neighbor < timelines.len() && is_pinned(neighbor) != is_pinned(state.timeline_manager.active_index())
+	})
+}
+
+/// Saves the order of pinned timelines after they were moved among themselves. Pinned timelines that aren't open keep
+/// their place after the open ones.
+fn sync_pinned_order(state: &mut AppState) {
+	let pinned = state.config.pinned_timelines();
+	if pinned.is_empty() {
+		return;
+	}
+	let mut ordered: Vec<TimelineType> = state
+		.timeline_manager
+		.timelines()
+		.iter()
+		.map(|t| t.timeline_type.clone())
+		.filter(|t| pinned.contains(t))
+		.collect();
+	for timeline_type in pinned {
+		if !ordered.contains(timeline_type) {
+			ordered.push(timeline_type.clone());
+		}
+	}
+	if ordered.as_slice() != pinned
+		&& let Some(account) = state.active_account_mut()
+	{
+		account.pinned_timelines = ordered;
+		let _ = config::ConfigStore::new().save(&state.config);
+	}
+}
 
+/// Refills the timeline selector after timelines were closed or reordered and shows the now active one.
+pub fn refresh_timeline_selector(
+	state: &mut AppState,
+	selector: ListBox,
+	timeline_list: &crate::ui::timeline_list::TimelineList,
+	suppress_selection: &Cell<bool>,
+	frame: &Frame,
+) {
+	let active_index = state.timeline_manager.active_index();
 	selector.clear();
-	for name in state.timeline_manager.display_names() {
+	for name in state.timeline_manager.display_names(&state.lists) {
 		selector.append(&name);
 	}
 	with_suppressed_selection(suppress_selection, || {
@@ -2756,36 +4150,158 @@ fn close_timeline(
 	update_window_title(state, frame);
 }
 
+/// Converts compose dialog attachments into edit media, carrying edited descriptions of existing attachments.
+fn edit_media(items: Vec<dialogs::PostMedia>) -> Vec<network::EditMedia> {
+	items
+		.into_iter()
+		.map(|item| {
+			if item.is_existing {
+				let description = item.description_changed.then(|| item.description.unwrap_or_default());
+				network::EditMedia::Existing { id: item.path, description }
+			} else {
+				network::EditMedia::New(network::MediaUpload { path: item.path, description: item.description })
+			}
+		})
+		.collect()
+}
+
+/// Adds a post the compose dialog saved as a draft to the active account's drafts until Resume Draft reopens it.
+fn save_draft(
+	state: &mut AppState,
+	live_region: &crate::ui::timeline_list::TimelineList,
+	mut draft: crate::PendingPost,
+) {
+	let Some(account) = state.active_account_mut() else {
+		live_region.announce("Couldn't save the draft because no account is active");
+		return;
+	};
+	draft.last_result.save_as_draft = false;
+	account.drafts.push(draft);
+	let count = account.drafts.len();
+	let _ = config::ConfigStore::new().save(&state.config);
+	if count == 1 {
+		live_region.announce("Draft saved. Choose Resume Draft from the Post menu to finish it");
+	} else {
+		live_region.announce(&format!("Draft saved. {count} drafts are waiting in Resume Draft on the Post menu"));
+	}
+}
+
+/// Describes a saved draft in the Resume Draft chooser by what kind of post it is and how it starts.
+fn draft_label(draft: &crate::PendingPost) -> String {
+	let kind = match draft.operation {
+		crate::PostOperation::NewPost => "Post",
+		crate::PostOperation::Reply { .. } => "Reply",
+		crate::PostOperation::Edit { .. } => "Edit",
+		crate::PostOperation::Quote { .. } => "Quote",
+	};
+	let text = draft.last_result.content.split_whitespace().collect::<Vec<_>>().join(" ");
+	if text.is_empty() {
+		return format!("{kind}: (no text)");
+	}
+	let preview: String = text.chars().take(80).collect();
+	if preview.len() < text.len() { format!("{kind}: {preview}...") } else { format!("{kind}: {preview}") }
+}
+
+/// Prepares a compose dialog config to reopen with the contents of an earlier result.
+fn reopen_compose_config(
+	mut config: dialogs::ComposeDialogConfig,
+	result: &dialogs::PostResult,
+) -> dialogs::ComposeDialogConfig {
+	config.initial_content = result.content.clone();
+	config.initial_cw = result.spoiler_text.clone();
+	config.initial_sensitive = result.sensitive;
+	config.initial_language = result.language.clone();
+	config.default_visibility = result.visibility;
+	config.initial_thread_mode = result.continue_thread;
+	config
+}
+
+/// Describes the attachments and poll an edit would drop from the original post, if any.
+fn edit_removal_summary(original: &crate::mastodon::Status, edit: &dialogs::PostResult) -> Option<String> {
+	let removed_media = original
+		.media_attachments
+		.iter()
+		.filter(|media| !edit.media.iter().any(|item| item.is_existing && item.path == media.id))
+		.count();
+	let removes_poll = original.poll.is_some() && edit.poll.is_none();
+	let mut parts = Vec::new();
+	match removed_media {
+		0 => {}
+		1 => parts.push("1 attachment".to_string()),
+		count => parts.push(format!("{count} attachments")),
+	}
+	if removes_poll {
+		parts.push("the poll".to_string());
+	}
+	if parts.is_empty() { None } else { Some(parts.join(" and ")) }
+}
+
 pub fn run_edit_post_dialog(
 	frame: &Frame,
 	state: &mut AppState,
+	live_region: &crate::ui::timeline_list::TimelineList,
 	target: &crate::mastodon::Status,
 	source_text: Option<&str>,
 ) {
 	let max_post_chars = state.max_post_chars;
-	let enter_to_send = state.config.enter_to_send;
-	let Some((edit, config)) =
-		dialogs::prompt_for_edit(frame, target, source_text, max_post_chars, &state.poll_limits, enter_to_send)
-	else {
+	let (enter_to_send, cw_marks_sensitive) = (state.config.enter_to_send, state.config.cw_marks_sensitive);
+	let Some((mut edit, mut config)) = dialogs::prompt_for_edit(
+		frame,
+		target,
+		source_text,
+		max_post_chars,
+		&state.poll_limits,
+		&state.media_limits,
+		enter_to_send,
+		cw_marks_sensitive,
+		&state.config.auto_cw_rules,
+		state.config.confirm_discard_compose,
+		&mut state.config.dialog_geometry,
+	) else {
 		return;
 	};
+	while !edit.save_as_draft
+		&& let Some(removed) = edit_removal_summary(target, &edit)
+	{
+		let message =
+			format!("Saving this edit will remove {removed} from the post. Save anyway?\n\nChoose No to keep editing.");
+		let confirm = MessageDialog::builder(frame, &message, "Edit Post")
+			.with_style(MessageDialogStyle::YesNo | MessageDialogStyle::IconWarning)
+			.build();
+		if confirm.show_modal() == ID_YES {
+			break;
+		}
+		let reopened = reopen_compose_config(config, &edit);
+		let Some(next) = dialogs::prompt_for_compose(
+			frame,
+			max_post_chars,
+			&state.poll_limits,
+			&state.media_limits,
+			enter_to_send,
+			cw_marks_sensitive,
+			&state.config.auto_cw_rules,
+			state.config.confirm_discard_compose,
+			&mut state.config.dialog_geometry,
+			reopened,
+			edit.media,
+			edit.poll,
+		) else {
+			return;
+		};
+		(edit, config) = next;
+	}
+	if edit.save_as_draft {
+		let operation = crate::PostOperation::Edit { status_id: target.id.clone() };
+		save_draft(state, live_region, crate::PendingPost { config, operation, last_result: edit });
+		return;
+	}
 	if let Some(handle) = &state.network_handle {
 		state.pending_post = Some(crate::PendingPost {
 			config,
 			operation: crate::PostOperation::Edit { status_id: target.id.clone() },
 			last_result: edit.clone(),
 		});
-		let media = edit
-			.media
-			.into_iter()
-			.map(|item| {
-				if item.is_existing {
-					network::EditMedia::Existing(item.path)
-				} else {
-					network::EditMedia::New(network::MediaUpload { path: item.path, description: item.description })
-				}
-			})
-			.collect();
+		let media = edit_media(edit.media);
 
 		handle.send(NetworkCommand::EditStatus {
 			status_id: target.id.clone(),
@@ -1,5 +1,5 @@
 use std::{
-	collections::HashMap,
+	collections::{HashMap, HashSet},
 	env, fs, io,
 	path::{Path, PathBuf},
 	time::{SystemTime, UNIX_EPOCH},
@@ -10,9 +10,13 @@ use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use url::Url;
 
-use crate::template::{DEFAULT_BOOST_TEMPLATE, DEFAULT_POST_TEMPLATE, DEFAULT_QUOTE_TEMPLATE};
+use crate::template::{
+	DEFAULT_BOOST_TEMPLATE, DEFAULT_FAVORITE_NOTIFICATION_TEMPLATE, DEFAULT_FOLLOW_NOTIFICATION_TEMPLATE,
+	DEFAULT_MENTION_NOTIFICATION_TEMPLATE, DEFAULT_POLL_NOTIFICATION_TEMPLATE, DEFAULT_POST_TEMPLATE,
+	DEFAULT_QUOTE_TEMPLATE,
+};
 
-const APP_NAME: &str = "Fedra";
+pub const APP_NAME: &str = "Fedra";
 const CONFIG_FILENAME: &str = "config.json";
 const CONFIG_VERSION: u32 = 1;
 
@@ -24,8 +28,10 @@ pub struct Config {
 	pub active_account_id: Option<String>,
 	#[serde(default = "default_enter_to_send")]
 	pub enter_to_send: bool,
-	#[serde(default = "default_always_show_link_dialog")]
-	pub always_show_link_dialog: bool,
+	#[serde(default, alias = "always_show_link_dialog", deserialize_with = "deserialize_link_open_mode")]
+	pub link_open_mode: LinkOpenMode,
+	#[serde(default = "default_link_prompt_threshold")]
+	pub link_prompt_threshold: u8,
 	#[serde(default = "default_show_link_previews")]
 	pub show_link_previews: bool,
 	#[serde(default = "default_quick_action_keys")]
@@ -57,6 +63,8 @@ pub struct Config {
 	#[serde(default)]
 	pub templates: PostTemplates,
 	#[serde(default)]
+	pub notification_templates: NotificationTemplates,
+	#[serde(default)]
 	pub filters: TimelineFilters,
 	#[serde(default)]
 	pub find_loading_mode: FindLoadingMode,
@@ -64,12 +72,233 @@ pub struct Config {
 	pub window_title_template: String,
 	#[serde(default = "default_restore_open_timelines")]
 	pub restore_open_timelines: bool,
+	/// Open timelines saved before they were kept per account. Used once, for whichever account is active.
 	#[serde(default)]
 	pub saved_timelines: Vec<crate::timeline::TimelineType>,
 	#[serde(default)]
 	pub saved_active_timeline: Option<crate::timeline::TimelineType>,
 	#[serde(default)]
 	pub saved_selected_post_id: Option<String>,
+	#[serde(default = "default_oauth_scopes")]
+	pub oauth_scopes: String,
+	/// Lowercased full handles of accounts whose boosts Fedra hides, whatever the server's own setting.
+	#[serde(default)]
+	pub hide_boosts_from: HashSet<String>,
+	/// Where each resizable dialog was and how big it was when last closed, keyed by dialog name.
+	#[serde(default)]
+	pub dialog_geometry: HashMap<String, DialogGeometry>,
+	#[serde(default = "default_announce_stream_status")]
+	pub announce_stream_status: bool,
+	#[serde(default = "default_show_follow_markers")]
+	pub show_follow_markers: bool,
+	#[serde(default = "default_timeline_enter_action")]
+	pub timeline_enter_action: TimelineEnterAction,
+	#[serde(default = "default_announce_interaction_counts")]
+	pub announce_interaction_counts: bool,
+	#[serde(default = "default_reply_all_default")]
+	pub reply_all_default: bool,
+	#[serde(default = "default_cw_marks_sensitive")]
+	pub cw_marks_sensitive: bool,
+	#[serde(default = "default_confirm_own_interactions")]
+	pub confirm_own_interactions: bool,
+	#[serde(default = "default_show_media_indicator")]
+	pub show_media_indicator: bool,
+	#[serde(default = "default_debug_logging")]
+	pub debug_logging: bool,
+	#[serde(default = "default_proxy_url")]
+	pub proxy_url: Option<String>,
+	#[serde(default = "default_client_name")]
+	pub client_name: Option<String>,
+	#[serde(default = "default_client_website")]
+	pub client_website: Option<String>,
+	#[serde(default = "default_user_agent")]
+	pub user_agent: Option<String>,
+	#[serde(default = "default_hide_warned_posts")]
+	pub hide_warned_posts: bool,
+	#[serde(default = "default_hide_sensitive_media")]
+	pub hide_sensitive_media: bool,
+	#[serde(default = "default_auto_cw_rules")]
+	pub auto_cw_rules: Vec<AutoCwRule>,
+	#[serde(default = "default_mark_mentions_of_me")]
+	pub mark_mentions_of_me: bool,
+	#[serde(default = "default_mention_sound")]
+	pub mention_sound: bool,
+	#[serde(default = "default_confirm_discard_compose")]
+	pub confirm_discard_compose: bool,
+	#[serde(default = "default_announce_timeline_on_focus")]
+	pub announce_timeline_on_focus: bool,
+	#[serde(default = "default_show_detail_pane")]
+	pub show_detail_pane: bool,
+	#[serde(default = "default_timeline_row_length")]
+	pub timeline_row_length: u16,
+	#[serde(default = "default_show_instance_overview")]
+	pub show_instance_overview: bool,
+}
+
+impl Config {
+	/// The signed-in account, falling back to the first one when none is marked active.
+	pub fn active_account(&self) -> Option<&Account> {
+		self.active_account_id
+			.as_ref()
+			.map_or_else(|| self.accounts.first(), |id| self.accounts.iter().find(|a| &a.id == id))
+	}
+
+	/// The active account's pinned timelines, in order.
+	pub fn pinned_timelines(&self) -> &[crate::timeline::TimelineType] {
+		self.active_account().map_or(&[], |account| account.pinned_timelines.as_slice())
+	}
+
+	/// The active account's local tags on the bookmarked post `status_id`.
+	pub fn bookmark_tags(&self, status_id: &str) -> &[String] {
+		self.active_account().and_then(|account| account.bookmark_tags.get(status_id)).map_or(&[], Vec::as_slice)
+	}
+
+	/// Returns every local bookmark tag the active account uses, sorted case-insensitively.
+	pub fn known_bookmark_tags(&self) -> Vec<String> {
+		let mut tags: Vec<String> = Vec::new();
+		for tag in self.active_account().iter().flat_map(|account| account.bookmark_tags.values()).flatten() {
+			if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+				tags.push(tag.clone());
+			}
+		}
+		tags.sort_by_key(|t| t.to_lowercase());
+		tags
+	}
+
+	/// Whether Open Links should ask which of `link_count` links to open.
+	pub const fn prompts_for_links(&self, link_count: usize) -> bool {
+		match self.link_open_mode {
+			LinkOpenMode::OpenFirst => false,
+			LinkOpenMode::AlwaysPrompt => true,
+			LinkOpenMode::PromptAboveThreshold => link_count > self.link_prompt_threshold as usize,
+		}
+	}
+
+	/// The longest row timeline lists show before cutting it short, or `None` when rows are shown in full.
+	pub const fn row_length(&self) -> Option<usize> {
+		match self.timeline_row_length {
+			0 => None,
+			length => Some(length as usize),
+		}
+	}
+
+	pub fn bookmark_has_tag(&self, status_id: &str, tag: &str) -> bool {
+		self.bookmark_tags(status_id).iter().any(|t| t.eq_ignore_ascii_case(tag))
+	}
+
+	pub fn hides_boosts_from(&self, acct: &str) -> bool {
+		self.hide_boosts_from.contains(&acct.to_lowercase())
+	}
+
+	/// Page size for timeline fetches, refreshes, and searches.
+	pub fn page_limit(&self) -> Option<u32> {
+		Some(u32::from(self.fetch_limit))
+	}
+
+	/// The app name shown to instance admins when registering a new account.
+	pub fn client_name(&self) -> &str {
+		self.client_name.as_deref().unwrap_or(APP_NAME)
+	}
+
+	pub fn client_website(&self) -> &str {
+		self.client_website.as_deref().unwrap_or_default()
+	}
+}
+
+const fn default_announce_stream_status() -> bool {
+	false
+}
+
+const fn default_show_follow_markers() -> bool {
+	false
+}
+
+const fn default_timeline_enter_action() -> TimelineEnterAction {
+	TimelineEnterAction::ViewThread
+}
+
+const fn default_announce_interaction_counts() -> bool {
+	false
+}
+
+const fn default_reply_all_default() -> bool {
+	true
+}
+
+const fn default_cw_marks_sensitive() -> bool {
+	true
+}
+
+const fn default_confirm_own_interactions() -> bool {
+	false
+}
+
+const fn default_show_media_indicator() -> bool {
+	false
+}
+
+const fn default_debug_logging() -> bool {
+	false
+}
+
+const fn default_proxy_url() -> Option<String> {
+	None
+}
+
+const fn default_client_name() -> Option<String> {
+	None
+}
+
+const fn default_client_website() -> Option<String> {
+	None
+}
+
+const fn default_user_agent() -> Option<String> {
+	None
+}
+
+const fn default_hide_warned_posts() -> bool {
+	false
+}
+
+const fn default_hide_sensitive_media() -> bool {
+	false
+}
+
+fn default_auto_cw_rules() -> Vec<AutoCwRule> {
+	Vec::new()
+}
+
+const fn default_mark_mentions_of_me() -> bool {
+	false
+}
+
+const fn default_mention_sound() -> bool {
+	false
+}
+
+const fn default_confirm_discard_compose() -> bool {
+	true
+}
+
+const fn default_announce_timeline_on_focus() -> bool {
+	true
+}
+
+const fn default_show_detail_pane() -> bool {
+	false
+}
+
+const fn default_show_instance_overview() -> bool {
+	true
+}
+
+const fn default_timeline_row_length() -> u16 {
+	0
+}
+
+fn default_oauth_scopes() -> String {
+	crate::mastodon::DEFAULT_SCOPES.to_string()
 }
 
 const fn default_restore_open_timelines() -> bool {
@@ -176,6 +405,18 @@ pub enum DisplayNameEmojiMode {
 	All,
 }
 
+/// What Open Links does with the links in a post.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LinkOpenMode {
+	/// Opens the first link without asking.
+	OpenFirst,
+	/// Always asks which link to open.
+	AlwaysPrompt,
+	/// Asks only when the post has more links than `link_prompt_threshold`.
+	#[default]
+	PromptAboveThreshold,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub enum AutoloadMode {
 	Never,
@@ -184,6 +425,75 @@ pub enum AutoloadMode {
 	AtBoundary,
 }
 
+/// What pressing Enter on a post in the timeline does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum TimelineEnterAction {
+	#[default]
+	ViewThread,
+	Reply,
+	ViewPost,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DialogGeometry {
+	pub x: i32,
+	pub y: i32,
+	pub width: i32,
+	pub height: i32,
+}
+
+/// Turns on a content warning with `spoiler_text` when a draft contains any of `keywords`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoCwRule {
+	pub keywords: Vec<String>,
+	pub spoiler_text: String,
+}
+
+impl AutoCwRule {
+	/// Parses a `keyword, keyword = warning text` line as typed in the options dialog.
+	pub fn parse(line: &str) -> Option<Self> {
+		let (keywords, spoiler_text) = line.split_once('=')?;
+		let keywords: Vec<String> =
+			keywords.split(',').map(str::trim).filter(|k| !k.is_empty()).map(str::to_string).collect();
+		let spoiler_text = spoiler_text.trim();
+		if keywords.is_empty() || spoiler_text.is_empty() {
+			return None;
+		}
+		Some(Self { keywords, spoiler_text: spoiler_text.to_string() })
+	}
+
+	pub fn to_line(&self) -> String {
+		format!("{} = {}", self.keywords.join(", "), self.spoiler_text)
+	}
+
+	/// The first keyword that appears in `text` as a whole word, ignoring case, so "cat" doesn't match "concatenate".
+	pub fn matched_keyword(&self, text: &str) -> Option<&str> {
+		let text = text.to_lowercase();
+		self.keywords.iter().find(|k| contains_word(&text, &k.to_lowercase())).map(String::as_str)
+	}
+}
+
+/// Splits comma-separated bookmark tags, trimming them and dropping blanks and case-insensitive repeats.
+pub fn parse_bookmark_tags(input: &str) -> Vec<String> {
+	let mut tags: Vec<String> = Vec::new();
+	for tag in input.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+		if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+			tags.push(tag.to_string());
+		}
+	}
+	tags
+}
+
+/// Whether `word` appears in `text` without a letter or digit directly on either side of it.
+fn contains_word(text: &str, word: &str) -> bool {
+	!word.is_empty()
+		&& text.match_indices(word).any(|(start, _)| {
+			let end = start + word.len();
+			!text[..start].chars().next_back().is_some_and(char::is_alphanumeric)
+				&& !text[end..].chars().next().is_some_and(char::is_alphanumeric)
+		})
+}
+
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TimelineFilter {
@@ -272,12 +582,46 @@ pub struct PerTimelineTemplates {
 	pub quote: Option<String>,
 }
 
+/// Templates for notification rows, keyed by notification type. Unset entries fall back to the defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct NotificationTemplates {
+	#[serde(default)]
+	pub mention: Option<String>,
+	#[serde(default)]
+	pub favorite: Option<String>,
+	/// Unset means boosts of your posts use the timeline's boost template, as before these templates existed.
+	#[serde(default)]
+	pub boost: Option<String>,
+	#[serde(default)]
+	pub follow: Option<String>,
+	#[serde(default)]
+	pub poll: Option<String>,
+}
+
+impl NotificationTemplates {
+	pub fn resolve_mention_template(&self) -> &str {
+		self.mention.as_deref().unwrap_or(DEFAULT_MENTION_NOTIFICATION_TEMPLATE)
+	}
+
+	pub fn resolve_favorite_template(&self) -> &str {
+		self.favorite.as_deref().unwrap_or(DEFAULT_FAVORITE_NOTIFICATION_TEMPLATE)
+	}
+
+	pub fn resolve_follow_template(&self) -> &str {
+		self.follow.as_deref().unwrap_or(DEFAULT_FOLLOW_NOTIFICATION_TEMPLATE)
+	}
+
+	pub fn resolve_poll_template(&self) -> &str {
+		self.poll.as_deref().unwrap_or(DEFAULT_POLL_NOTIFICATION_TEMPLATE)
+	}
+}
+
 const fn default_enter_to_send() -> bool {
 	true
 }
 
-const fn default_always_show_link_dialog() -> bool {
-	false
+const fn default_link_prompt_threshold() -> u8 {
+	1
 }
 
 const fn default_show_link_previews() -> bool {
@@ -322,6 +666,24 @@ where
 	}
 }
 
+fn deserialize_link_open_mode<'de, D>(deserializer: D) -> Result<LinkOpenMode, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	use serde::de::Error;
+	let value = Value::deserialize(deserializer)?;
+	match value {
+		Value::Bool(b) => Ok(if b { LinkOpenMode::AlwaysPrompt } else { LinkOpenMode::PromptAboveThreshold }),
+		Value::String(s) => match s.as_str() {
+			"OpenFirst" => Ok(LinkOpenMode::OpenFirst),
+			"AlwaysPrompt" => Ok(LinkOpenMode::AlwaysPrompt),
+			"PromptAboveThreshold" => Ok(LinkOpenMode::PromptAboveThreshold),
+			_ => Err(D::Error::custom(format!("unknown link open mode: {s}"))),
+		},
+		_ => Err(D::Error::custom("expected bool or string for link open mode")),
+	}
+}
+
 const fn default_fetch_limit() -> u8 {
 	40
 }
@@ -333,7 +695,8 @@ impl Default for Config {
 			accounts: Vec::new(),
 			active_account_id: None,
 			enter_to_send: true,
-			always_show_link_dialog: false,
+			link_open_mode: LinkOpenMode::default(),
+			link_prompt_threshold: default_link_prompt_threshold(),
 			show_link_previews: false,
 			quick_action_keys: false,
 			autoload: AutoloadMode::default(),
@@ -349,6 +712,7 @@ impl Default for Config {
 			hotkey: HotkeyConfig::default(),
 			strip_tracking: true,
 			templates: PostTemplates::default(),
+			notification_templates: NotificationTemplates::default(),
 			filters: TimelineFilters::default(),
 			find_loading_mode: FindLoadingMode::default(),
 			window_title_template: default_window_title_template(),
@@ -356,6 +720,32 @@ impl Default for Config {
 			saved_timelines: Vec::new(),
 			saved_active_timeline: None,
 			saved_selected_post_id: None,
+			oauth_scopes: default_oauth_scopes(),
+			hide_boosts_from: HashSet::new(),
+			dialog_geometry: HashMap::new(),
+			announce_stream_status: default_announce_stream_status(),
+			show_follow_markers: default_show_follow_markers(),
+			timeline_enter_action: default_timeline_enter_action(),
+			announce_interaction_counts: default_announce_interaction_counts(),
+			reply_all_default: default_reply_all_default(),
+			cw_marks_sensitive: default_cw_marks_sensitive(),
+			confirm_own_interactions: default_confirm_own_interactions(),
+			show_media_indicator: default_show_media_indicator(),
+			debug_logging: default_debug_logging(),
+			proxy_url: default_proxy_url(),
+			client_name: default_client_name(),
+			client_website: default_client_website(),
+			user_agent: default_user_agent(),
+			hide_warned_posts: default_hide_warned_posts(),
+			hide_sensitive_media: default_hide_sensitive_media(),
+			auto_cw_rules: default_auto_cw_rules(),
+			mark_mentions_of_me: default_mark_mentions_of_me(),
+			mention_sound: default_mention_sound(),
+			confirm_discard_compose: default_confirm_discard_compose(),
+			announce_timeline_on_focus: default_announce_timeline_on_focus(),
+			show_detail_pane: default_show_detail_pane(),
+			timeline_row_length: default_timeline_row_length(),
+			show_instance_overview: default_show_instance_overview(),
 		}
 	}
 }
@@ -372,6 +762,18 @@ pub struct Account {
 	pub user_id: Option<String>,
 	#[serde(default)]
 	pub default_post_visibility: Option<String>,
+	/// Timelines this account had open when Fedra last exited.
+	#[serde(default)]
+	pub saved_timelines: Vec<crate::timeline::TimelineType>,
+	/// Timelines kept at the front of the timeline list, in this order, whenever they are open.
+	#[serde(default)]
+	pub pinned_timelines: Vec<crate::timeline::TimelineType>,
+	/// Local tags on this account's bookmarks, keyed by post id.
+	#[serde(default)]
+	pub bookmark_tags: HashMap<String, Vec<String>>,
+	/// Posts saved from the compose dialog to finish later, oldest first.
+	#[serde(default)]
+	pub drafts: Vec<crate::PendingPost>,
 }
 
 impl Account {
@@ -386,6 +788,10 @@ impl Account {
 			display_name: None,
 			user_id: None,
 			default_post_visibility: None,
+			saved_timelines: Vec::new(),
+			pinned_timelines: Vec::new(),
+			bookmark_tags: HashMap::new(),
+			drafts: Vec::new(),
 		}
 	}
 
@@ -443,7 +849,29 @@ pub fn config_dir() -> PathBuf {
 	exe_dir
 }
 
+/// Folder holding compose templates, one text file per template.
+pub fn compose_templates_dir() -> PathBuf {
+	config_dir().join("templates")
+}
+
+/// Compose templates by name, sorted alphabetically.
+pub fn compose_templates() -> Vec<(String, PathBuf)> {
+	let Ok(dir) = fs::read_dir(compose_templates_dir()) else { return Vec::new() };
+	let mut templates: Vec<(String, PathBuf)> = dir
+		.flatten()
+		.map(|entry| entry.path())
+		.filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("txt")))
+		.filter_map(|path| Some((path.file_stem()?.to_string_lossy().into_owned(), path)))
+		.collect();
+	templates.sort_by_key(|(name, _)| name.to_lowercase());
+	templates
+}
+
 fn config_path() -> PathBuf {
+	#[cfg(feature = "mock")]
+	if let Some(path) = crate::mock::config_path() {
+		return path;
+	}
 	let exe_dir = env::current_exe()
 		.ok()
 		.and_then(|path| path.parent().map(std::path::Path::to_path_buf))
@@ -481,3 +909,56 @@ fn new_account_id() -> String {
 	let millis = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
 	format!("acct-{millis}")
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{Account, AutoCwRule, Config, parse_bookmark_tags};
+
+	#[test]
+	fn auto_cw_rules_parse_from_lines() {
+		let rule = AutoCwRule::parse(" spoilers, cw: ,  = Spoilers ").unwrap();
+		assert_eq!(rule.keywords, ["spoilers", "cw:"]);
+		assert_eq!(rule.spoiler_text, "Spoilers");
+		assert_eq!(AutoCwRule::parse(&rule.to_line()), Some(rule));
+		assert_eq!(AutoCwRule::parse("spoilers"), None);
+		assert_eq!(AutoCwRule::parse(" , = Spoilers"), None);
+		assert_eq!(AutoCwRule::parse("spoilers ="), None);
+	}
+
+	#[test]
+	fn auto_cw_keywords_match_whole_words() {
+		let rule = AutoCwRule::parse("cat, cw:, food = Warning").unwrap();
+		assert_eq!(rule.matched_keyword("My CAT did a thing"), Some("cat"));
+		assert_eq!(rule.matched_keyword("cat."), Some("cat"));
+		assert_eq!(rule.matched_keyword("cw: eye contact"), Some("cw:"));
+		assert_eq!(rule.matched_keyword("Time to concatenate some catalogues"), None);
+		assert_eq!(rule.matched_keyword("Seafood for dinner"), None);
+		assert_eq!(rule.matched_keyword("Fast food, then a nap"), Some("food"));
+	}
+
+	#[test]
+	fn bookmark_tags_parse_from_commas() {
+		assert_eq!(parse_bookmark_tags(" Recipes, later ,,recipes, Rust "), ["Recipes", "later", "Rust"]);
+		assert!(parse_bookmark_tags(" , ").is_empty());
+	}
+
+	#[test]
+	fn known_bookmark_tags_belong_to_the_active_account() {
+		let mut config = Config::default();
+		let mut first = Account::new("https://one.example".to_string());
+		first.bookmark_tags.insert("1".to_string(), vec!["rust".to_string(), "Later".to_string()]);
+		first.bookmark_tags.insert("2".to_string(), vec!["rust".to_string()]);
+		let mut second = Account::new("https://two.example".to_string());
+		second.bookmark_tags.insert("1".to_string(), vec!["cooking".to_string()]);
+		config.active_account_id = Some(first.id.clone());
+		let second_id = second.id.clone();
+		config.accounts = vec![first, second];
+		assert_eq!(config.known_bookmark_tags(), ["Later", "rust"]);
+		assert!(config.bookmark_has_tag("2", "RUST"));
+		assert!(!config.bookmark_has_tag("1", "cooking"));
+		config.active_account_id = Some(second_id);
+		assert_eq!(config.known_bookmark_tags(), ["cooking"]);
+		assert!(config.bookmark_has_tag("1", "cooking"));
+		assert!(config.bookmark_tags("2").is_empty());
+	}
+}
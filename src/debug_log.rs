@@ -0,0 +1,51 @@
+use std::{
+	fs::{self, OpenOptions},
+	io::Write,
+	path::PathBuf,
+	sync::atomic::{AtomicBool, Ordering},
+};
+
+use reqwest::{StatusCode, Url};
+
+use crate::config;
+
+const LOG_FILENAME: &str = "debug.log";
+const OLD_LOG_FILENAME: &str = "debug.log.old";
+/// Once the log grows past this size it is moved aside and a fresh one is started.
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+const MAX_BODY_CHARS: usize = 8 * 1024;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub fn set_enabled(enabled: bool) {
+	ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+	ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn log_dir() -> PathBuf {
+	config::config_dir()
+}
+
+/// Records a failed HTTP request when debug logging is enabled. Failures to write the log are ignored.
+pub fn log_failed_request(url: &Url, status: StatusCode, body: &str) {
+	if !enabled() {
+		return;
+	}
+	let dir = log_dir();
+	let path = dir.join(LOG_FILENAME);
+	if fs::metadata(&path).is_ok_and(|meta| meta.len() > MAX_LOG_BYTES) {
+		let _ = fs::rename(&path, dir.join(OLD_LOG_FILENAME));
+	}
+	let Ok(mut file) = OpenOptions::new().create(true).append(true).open(&path) else { return };
+	let body = body.trim();
+	let body = match body.char_indices().nth(MAX_BODY_CHARS) {
+		Some((end, _)) => format!("{}... (truncated)", &body[..end]),
+		None => body.to_string(),
+	};
+	let _ = writeln!(file, "[{}] {} {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"), status, url);
+	let _ = writeln!(file, "{body}");
+	let _ = writeln!(file);
+}
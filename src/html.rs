@@ -1,93 +1,197 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt::Write};
+
+use crate::config::DisplayNameEmojiMode;
 
 pub fn strip_html(html: &str) -> String {
+	strip_html_with_emoji(html, DisplayNameEmojiMode::None)
+}
+
+/// Like `strip_html`, but custom emoji images are kept as `:shortcode:` or dropped depending on `emoji_mode`.
+pub fn strip_html_with_emoji(html: &str, emoji_mode: DisplayNameEmojiMode) -> String {
+	let keep_emoji = matches!(emoji_mode, DisplayNameEmojiMode::None | DisplayNameEmojiMode::UnicodeOnly);
 	let fragment = scraper::Html::parse_fragment(html);
-	let mut output = String::new();
+	let mut builder = TextBuilder { keep_emoji, ..TextBuilder::default() };
 	for child in fragment.root_element().children() {
-		append_text(child, &mut output);
+		builder.append(child);
 	}
-	normalize_text(&output)
+	normalize_text(&builder.output)
 }
 
-fn append_text(node: ego_tree::NodeRef<scraper::node::Node>, output: &mut String) {
-	match node.value() {
-		scraper::node::Node::Text(text) => {
-			output.push_str(text);
-		}
-		scraper::node::Node::Element(element) => {
-			let name = element.name();
-			if name == "br" {
-				push_newline(output);
-				return;
-			}
-			let is_block = matches!(
-				name,
-				"p" | "div"
-					| "li" | "ul" | "ol"
-					| "blockquote" | "pre"
-					| "section" | "article"
-					| "header" | "footer"
-					| "h1" | "h2" | "h3"
-					| "h4" | "h5" | "h6"
-			);
-			if is_block {
-				push_newline(output);
-			}
-			for child in node.children() {
-				append_text(child, output);
-			}
-			if is_block {
-				push_newline(output);
-			}
-		}
-		_ => {}
+/// Returns `:shortcode:` for an instance custom emoji image, taken from its alt or title text.
+fn emoji_shortcode(element: &scraper::node::Element) -> Option<String> {
+	let is_emoji = element
+		.attr("class")
+		.is_some_and(|class| class.split_whitespace().any(|c| c == "emoji" || c == "custom-emoji"));
+	if !is_emoji {
+		return None;
 	}
+	let name = [element.attr("alt"), element.attr("title")]
+		.into_iter()
+		.flatten()
+		.map(|text| text.trim().trim_matches(':'))
+		.find(|text| !text.is_empty())?;
+	Some(format!(":{name}:"))
 }
 
-fn push_newline(output: &mut String) {
-	if !output.ends_with('\n') {
-		output.push('\n');
-	}
+#[derive(Default)]
+struct TextBuilder {
+	output: String,
+	keep_emoji: bool,
+	/// Open lists, innermost last. Ordered lists hold the number of the last item written.
+	lists: Vec<Option<usize>>,
+	/// Set right after a list item marker so a block inside the item doesn't start a new line.
+	after_marker: bool,
+	/// Depth of open `<pre>` and `<code>` elements, whose whitespace is kept as written.
+	preformatted: usize,
 }
 
-fn normalize_text(input: &str) -> String {
-	let mut output = String::with_capacity(input.len());
-	let mut last_was_space = false;
-	let mut last_was_newline = false;
-	for ch in input.chars() {
-		match ch {
-			'\r' => {}
-			'\n' => {
-				if !output.ends_with('\n') {
-					output.push('\n');
+impl TextBuilder {
+	fn append(&mut self, node: ego_tree::NodeRef<scraper::node::Node>) {
+		match node.value() {
+			scraper::node::Node::Text(text) => {
+				if self.after_marker && text.trim().is_empty() {
+					return;
 				}
-				last_was_space = false;
-				last_was_newline = true;
+				self.after_marker = false;
+				self.push_text(text);
 			}
-			// Non-breaking space: always preserve (Mastodon uses &nbsp; for indentation)
-			'\u{00A0}' => {
-				output.push(ch);
-				last_was_space = false;
-				last_was_newline = false;
-			}
-			c if c.is_whitespace() => {
-				if last_was_newline {
-					// Preserve indentation at the start of a line
-					output.push(c);
-				} else if !last_was_space {
-					output.push(' ');
-					last_was_space = true;
+			scraper::node::Node::Element(element) => {
+				let name = element.name();
+				if name == "br" {
+					self.push_newline();
+					return;
+				}
+				if name == "img" {
+					if self.keep_emoji
+						&& let Some(shortcode) = emoji_shortcode(element)
+					{
+						self.after_marker = false;
+						self.output.push_str(&shortcode);
+					}
+					return;
+				}
+				match name {
+					"ul" | "ol" => {
+						self.push_newline();
+						let first = element.attr("start").and_then(|start| start.trim().parse::<usize>().ok());
+						self.lists.push((name == "ol").then(|| first.unwrap_or(1).saturating_sub(1)));
+						self.append_children(node);
+						self.lists.pop();
+						self.push_newline();
+						return;
+					}
+					"blockquote" => {
+						self.push_newline();
+						let mut quote = Self { keep_emoji: self.keep_emoji, ..Self::default() };
+						quote.append_children(node);
+						for line in normalize_text(&quote.output).lines() {
+							self.output.push_str(if line.is_empty() { ">" } else { "> " });
+							self.output.push_str(line);
+							self.output.push('\n');
+						}
+						return;
+					}
+					"pre" | "code" => {
+						let is_block = name == "pre";
+						if is_block {
+							self.push_newline();
+						}
+						self.preformatted += 1;
+						self.append_children(node);
+						self.preformatted -= 1;
+						if is_block {
+							self.push_newline();
+						}
+						return;
+					}
+					"li" => {
+						self.push_newline();
+						self.push_list_marker();
+						self.append_children(node);
+						self.after_marker = false;
+						self.push_newline();
+						return;
+					}
+					_ => {}
+				}
+				let is_block = matches!(
+					name,
+					"p" | "div" | "section" | "article" | "header" | "footer" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6"
+				);
+				if is_block {
+					self.push_newline();
+				}
+				self.append_children(node);
+				if is_block {
+					self.push_newline();
 				}
 			}
-			c => {
-				output.push(c);
-				last_was_space = false;
-				last_was_newline = false;
+			_ => {}
+		}
+	}
+
+	fn append_children(&mut self, node: ego_tree::NodeRef<scraper::node::Node>) {
+		for child in node.children() {
+			self.append(child);
+		}
+	}
+
+	fn push_list_marker(&mut self) {
+		let depth = self.lists.len().max(1);
+		self.output.push_str(&"  ".repeat(depth - 1));
+		match self.lists.last_mut() {
+			Some(Some(number)) => {
+				*number += 1;
+				let _ = write!(self.output, "{number}. ");
+			}
+			_ => self.output.push_str("- "),
+		}
+		self.after_marker = true;
+	}
+
+	/// Appends text, collapsing runs of whitespace except for indentation at the start of a line.
+	fn push_text(&mut self, text: &str) {
+		if self.preformatted > 0 {
+			self.output.extend(text.chars().filter(|&c| c != '\r'));
+			return;
+		}
+		for ch in text.chars() {
+			match ch {
+				'\r' => {}
+				'\n' => self.push_newline(),
+				// Non-breaking space: always preserve (Mastodon uses &nbsp; for indentation)
+				'\u{00A0}' => self.output.push(ch),
+				c if c.is_whitespace() => {
+					let at_line_start = self
+						.output
+						.rfind('\n')
+						.is_some_and(|i| self.output[i + 1..].chars().all(|c| c.is_whitespace() && c != '\u{00A0}'));
+					if at_line_start {
+						// Preserve indentation at the start of a line
+						self.output.push(c);
+					} else if !self.output.ends_with(' ') {
+						self.output.push(' ');
+					}
+				}
+				c => self.output.push(c),
 			}
 		}
 	}
+
+	fn push_newline(&mut self) {
+		if self.after_marker {
+			return;
+		}
+		if !self.output.ends_with('\n') {
+			self.output.push('\n');
+		}
+	}
+}
+
+/// Trims trailing whitespace from each line and caps runs of blank lines. Whitespace within lines is already collapsed.
+fn normalize_text(input: &str) -> String {
 	let mut cleaned = String::new();
-	for line in output.lines() {
+	for line in input.lines() {
 		if !cleaned.is_empty() {
 			cleaned.push('\n');
 		}
@@ -162,3 +266,92 @@ pub fn extract_links(html: &str) -> Vec<Link> {
 	}
 	links
 }
+
+#[cfg(test)]
+mod tests {
+	use super::{strip_html, strip_html_with_emoji};
+	use crate::config::DisplayNameEmojiMode;
+
+	const EMOJI_POST: &str = r#"<p>Hello <img class="emoji" alt=":blobcat:" title=":blobcat:" src="https://example.com/blobcat.png"> world</p>"#;
+
+	#[test]
+	fn converts_emoji_images_to_shortcodes() {
+		assert_eq!(strip_html(EMOJI_POST), "Hello :blobcat: world");
+	}
+
+	#[test]
+	fn uses_title_when_alt_is_missing() {
+		let html = r#"<p><img class="custom-emoji" title="party" src="x.png"> time</p>"#;
+		assert_eq!(strip_html(html), ":party: time");
+	}
+
+	#[test]
+	fn drops_emoji_images_when_instance_emoji_are_filtered() {
+		assert_eq!(strip_html_with_emoji(EMOJI_POST, DisplayNameEmojiMode::InstanceOnly), "Hello world");
+		assert_eq!(strip_html_with_emoji(EMOJI_POST, DisplayNameEmojiMode::All), "Hello world");
+		assert_eq!(strip_html_with_emoji(EMOJI_POST, DisplayNameEmojiMode::UnicodeOnly), "Hello :blobcat: world");
+	}
+
+	#[test]
+	fn puts_list_items_on_their_own_lines() {
+		let html = "<p>Shopping:</p><ul><li>Eggs</li><li>Milk</li></ul><p>Thanks</p>";
+		assert_eq!(strip_html(html), "Shopping:\n- Eggs\n- Milk\nThanks");
+	}
+
+	#[test]
+	fn numbers_ordered_list_items() {
+		assert_eq!(strip_html("<ol><li>One</li><li>Two</li></ol>"), "1. One\n2. Two");
+		assert_eq!(strip_html(r#"<ol start="3"><li>Three</li><li>Four</li></ol>"#), "3. Three\n4. Four");
+	}
+
+	#[test]
+	fn indents_nested_lists() {
+		let html = "<ul><li>Fruit<ul><li>Apples</li><li>Pears</li></ul></li><li>Bread<ol><li>Rye</li></ol></li></ul>";
+		assert_eq!(strip_html(html), "- Fruit\n  - Apples\n  - Pears\n- Bread\n  1. Rye");
+	}
+
+	#[test]
+	fn keeps_paragraphs_inside_list_items_on_the_marker_line() {
+		let html = "<ul>\n<li><p>First</p></li>\n<li><p>Second<br>continued</p></li>\n</ul>";
+		assert_eq!(strip_html(html), "- First\n- Second\ncontinued");
+	}
+
+	#[test]
+	fn separates_paragraphs_and_line_breaks() {
+		let html = "<p>Line one<br>Line two</p><p>Next paragraph</p>";
+		assert_eq!(strip_html(html), "Line one\nLine two\nNext paragraph");
+	}
+
+	#[test]
+	fn prefixes_blockquote_lines() {
+		let html = "<blockquote><p>First line<br>Second line</p></blockquote><p>My reply</p>";
+		assert_eq!(strip_html(html), "> First line\n> Second line\nMy reply");
+	}
+
+	#[test]
+	fn nests_blockquote_prefixes() {
+		let html = "<blockquote><p>Outer</p><blockquote><p>Inner</p></blockquote></blockquote>";
+		assert_eq!(strip_html(html), "> Outer\n> > Inner");
+	}
+
+	#[test]
+	fn preserves_whitespace_in_code_blocks() {
+		let html = "<p>Try this:</p><pre><code>fn main() {\n    let  x = 1;\n\n    println!(\"{x}\");\n}\n</code></pre><p>Done</p>";
+		assert_eq!(strip_html(html), "Try this:\nfn main() {\n    let  x = 1;\n\n    println!(\"{x}\");\n}\nDone");
+	}
+
+	#[test]
+	fn preserves_whitespace_in_inline_code() {
+		assert_eq!(strip_html("<p>Run <code>ls   -la</code>   now</p>"), "Run ls   -la now");
+	}
+
+	#[test]
+	fn collapses_whitespace_outside_code() {
+		assert_eq!(strip_html("<p>Too    many   spaces</p>"), "Too many spaces");
+	}
+
+	#[test]
+	fn ignores_images_that_are_not_emoji() {
+		assert_eq!(strip_html(r#"<p>Look <img alt="a cat" src="cat.png"> here</p>"#), "Look here");
+	}
+}
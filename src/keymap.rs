@@ -0,0 +1,216 @@
+use crate::config::{Config, HotkeyConfig, TimelineEnterAction};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutCategory {
+	Global,
+	Navigation,
+	Timelines,
+	Actions,
+	Compose,
+}
+
+impl ShortcutCategory {
+	pub const fn all() -> &'static [Self] {
+		&[Self::Global, Self::Navigation, Self::Timelines, Self::Actions, Self::Compose]
+	}
+
+	pub const fn display_name(self) -> &'static str {
+		match self {
+			Self::Global => "Global",
+			Self::Navigation => "Navigation",
+			Self::Timelines => "Timelines",
+			Self::Actions => "Post Actions",
+			Self::Compose => "Compose",
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Shortcut {
+	pub category: ShortcutCategory,
+	pub keys: String,
+	pub description: &'static str,
+}
+
+impl Shortcut {
+	pub fn matches_query(&self, query: &str) -> bool {
+		let query = query.to_lowercase();
+		self.keys.to_lowercase().contains(&query)
+			|| self.description.to_lowercase().contains(&query)
+			|| self.category.display_name().to_lowercase().contains(&query)
+	}
+}
+
+pub fn hotkey_display(hotkey: &HotkeyConfig) -> String {
+	let mut parts = Vec::new();
+	if hotkey.ctrl {
+		parts.push("Ctrl".to_string());
+	}
+	if hotkey.alt {
+		parts.push("Alt".to_string());
+	}
+	if hotkey.shift {
+		parts.push("Shift".to_string());
+	}
+	if hotkey.win {
+		parts.push("Win".to_string());
+	}
+	parts.push(hotkey.key.to_ascii_uppercase().to_string());
+	parts.join("+")
+}
+
+/// Shortcuts for replying to everyone and replying to the author only, in that order.
+pub const fn reply_shortcuts(quick_action_keys: bool, reply_all_default: bool) -> (&'static str, &'static str) {
+	let (primary, secondary) = if quick_action_keys { ("R", "Ctrl+R") } else { ("Ctrl+R", "Ctrl+Shift+R") };
+	if reply_all_default { (primary, secondary) } else { (secondary, primary) }
+}
+
+/// Keys for viewing post details and viewing the thread, in that order, given what Enter does in a timeline. Empty
+/// when the action has no key of its own.
+pub const fn enter_shortcuts(enter_action: TimelineEnterAction) -> (&'static str, &'static str) {
+	match enter_action {
+		TimelineEnterAction::ViewThread => ("Shift+Enter", "Enter"),
+		TimelineEnterAction::ViewPost => ("Enter", ""),
+		TimelineEnterAction::Reply => ("Shift+Enter", ""),
+	}
+}
+
+/// Opens the links in the selected post.
+pub const OPEN_LINKS_SHORTCUT: &str = "Alt+Enter";
+
+/// A menu label with `keys` as its accelerator, or with none when `keys` is empty.
+pub fn menu_label(label: &str, keys: &str) -> String {
+	if keys.is_empty() { label.to_string() } else { format!("{label}\t{keys}") }
+}
+
+/// Builds the list of shortcuts that are currently active, reflecting quick action keys and the global hotkey.
+pub fn shortcuts(config: &Config) -> Vec<Shortcut> {
+	use ShortcutCategory::{Actions, Compose, Global, Navigation, Timelines};
+
+	let q = config.quick_action_keys;
+	let pick = |quick: &str, normal: &str| if q { quick.to_string() } else { normal.to_string() };
+	let mut list = Vec::new();
+	let mut add = |category, keys: String, description| list.push(Shortcut { category, keys, description });
+
+	add(Global, hotkey_display(&config.hotkey), "Show or hide the main window");
+	add(Global, "F1".into(), "Show keyboard shortcuts");
+	add(Global, "Ctrl+,".into(), "Open options");
+	add(Global, "Ctrl+Alt+A".into(), "Manage accounts");
+	add(Global, "Ctrl+Shift+E".into(), "Edit current profile");
+	add(Global, "Ctrl+Shift+Q".into(), "Toggle quick action keys");
+
+	add(Navigation, "Left Arrow".into(), "Previous timeline");
+	add(Navigation, "Right Arrow".into(), "Next timeline");
+	add(Navigation, "Ctrl+1..Ctrl+9".into(), "Switch to timeline 1-9");
+	add(Navigation, "Ctrl+0".into(), "Switch to the last timeline");
+	if q {
+		add(Navigation, "1..9".into(), "Switch to timeline 1-9");
+		add(Navigation, "0".into(), "Switch to the last timeline");
+	}
+	add(Navigation, "Shift+Left Arrow".into(), "Move current timeline left");
+	add(Navigation, "Shift+Right Arrow".into(), "Move current timeline right");
+	add(Navigation, "Ctrl+[".into(), "Previous account");
+	add(Navigation, "Ctrl+]".into(), "Next account");
+	add(Navigation, pick("Backspace", "Ctrl+W"), "Close current timeline");
+	add(Navigation, "F3".into(), "Find next occurrence");
+	add(Navigation, "Shift+F3".into(), "Find previous occurrence");
+	add(Navigation, "Ctrl+Shift+N".into(), "Jump to the next post that mentions you");
+	add(Navigation, "Ctrl+Shift+L".into(), "Follow live updates in the current timeline");
+
+	add(Timelines, pick("T", "Ctrl+T"), "Open selected user's timeline");
+	add(Timelines, pick("U", "Ctrl+U"), "Open user by handle");
+	add(Timelines, pick("Shift+I", "Ctrl+Shift+I"), "Open an instance's local timeline");
+	add(Timelines, pick("/", "Ctrl+/"), "Search");
+	add(Timelines, "Ctrl+G".into(), "Go to a post by ID or link");
+	add(Timelines, "Ctrl+L".into(), "Open Local timeline");
+	add(Timelines, "Ctrl+D".into(), "Open Direct Messages timeline");
+	add(Timelines, "Ctrl+Shift+M".into(), "Open Mentions timeline");
+	add(Timelines, "Ctrl+F".into(), "Find in current timeline");
+	add(Timelines, pick(".", "Ctrl+."), "Load more posts");
+	add(Timelines, "F5".into(), "Refresh current timeline");
+	add(Timelines, "Ctrl+Shift+F5".into(), "Reconnect live updates for all timelines");
+
+	add(Actions, pick("C", "Ctrl+N"), "New post");
+	let (reply_all_keys, reply_author_keys) = reply_shortcuts(q, config.reply_all_default);
+	add(Actions, reply_all_keys.into(), "Reply to all mentioned users");
+	add(Actions, reply_author_keys.into(), "Reply to author only");
+	add(Actions, pick("Q", "Ctrl+Q"), "Quote selected post");
+	let enter_description = match config.timeline_enter_action {
+		TimelineEnterAction::ViewThread => "View thread",
+		TimelineEnterAction::Reply if config.reply_all_default => "Reply to all mentioned users",
+		TimelineEnterAction::Reply => "Reply to author only",
+		TimelineEnterAction::ViewPost => "View post details",
+	};
+	add(Actions, "Enter".into(), enter_description);
+	add(Actions, "Shift+Enter".into(), "View post details");
+	add(Actions, OPEN_LINKS_SHORTCUT.into(), "Open links in selected post");
+	add(Actions, pick("F", "Ctrl+Shift+F"), "Favorite or unfavorite");
+	add(Actions, pick("K", "Ctrl+Shift+K"), "Bookmark or unbookmark");
+	add(Actions, pick("B", "Ctrl+Shift+B"), "Boost or unboost");
+	add(Actions, pick("E", "Ctrl+E"), "Edit selected post");
+	add(Actions, "Delete".into(), "Delete selected post");
+	add(Actions, pick("V", "Ctrl+V"), "Vote in poll");
+	add(Actions, pick("I", "Ctrl+I"), "Play media");
+	add(Actions, pick("P", "Ctrl+P"), "View author's profile");
+	add(Actions, "Alt+F".into(), "Follow or unfollow the post's author");
+	add(Actions, pick("M", "Ctrl+M"), "View mentions");
+	add(Actions, pick("H", "Ctrl+H"), "View hashtags");
+	add(Actions, pick("O", "Ctrl+Shift+O"), "Open selected post in browser");
+	add(Actions, "Ctrl+Shift+C".into(), "Copy selected post text");
+	add(Actions, "Ctrl+C".into(), "Copy selected post link");
+	if config.debug_logging {
+		add(Actions, "Ctrl+Shift+J".into(), "Copy the selected post's raw JSON");
+	}
+	add(Actions, "Ctrl+Shift+S".into(), "Announce word count and reading time");
+	add(Actions, "Shift+F5".into(), "Refresh selected post");
+	add(Actions, pick("X", "Ctrl+X"), "Toggle content warning");
+
+	if config.enter_to_send {
+		add(Compose, "Enter".into(), "Send post");
+		add(Compose, "Shift+Enter".into(), "Insert a new line");
+	} else {
+		add(Compose, "Ctrl+Enter".into(), "Send post");
+		add(Compose, "Enter".into(), "Insert a new line");
+	}
+	add(Compose, "Escape".into(), "Cancel");
+	list
+}
+
+#[cfg(test)]
+mod tests {
+	use super::shortcuts;
+	use crate::config::{Config, TimelineEnterAction};
+
+	/// Fixed accelerators written into menu labels, such as "F5" from "&Refresh\tF5". Labels built with `format!`
+	/// take their keys from this module already and are skipped.
+	fn menu_accelerators(source: &str) -> Vec<&str> {
+		source
+			.split("\\t")
+			.skip(1)
+			.filter_map(|rest| rest.split('"').next())
+			.filter(|keys| !keys.is_empty() && !keys.contains('{'))
+			.collect()
+	}
+
+	#[test]
+	fn menu_accelerators_are_listed() {
+		let mut listed = Vec::new();
+		for quick_action_keys in [false, true] {
+			for enter_action in [TimelineEnterAction::ViewThread, TimelineEnterAction::ViewPost] {
+				let config = Config {
+					quick_action_keys,
+					timeline_enter_action: enter_action,
+					debug_logging: true,
+					..Config::default()
+				};
+				listed.extend(shortcuts(&config).into_iter().map(|shortcut| shortcut.keys));
+			}
+		}
+		let sources = [include_str!("ui/menu.rs"), include_str!("ui/window.rs")];
+		let accelerators: Vec<&str> = sources.iter().flat_map(|source| menu_accelerators(source)).collect();
+		assert!(accelerators.len() > 20);
+		for keys in accelerators {
+			assert!(listed.iter().any(|listed| listed == keys), "{keys} is in a menu but not in the shortcuts list");
+		}
+	}
+}
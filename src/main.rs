@@ -7,10 +7,15 @@ mod accounts;
 mod auth;
 mod commands;
 mod config;
+mod debug_log;
 mod html;
+mod keymap;
 mod mastodon;
+#[cfg(feature = "mock")]
+mod mock;
 mod network;
 mod notifications;
+mod proxy;
 mod responses;
 mod streaming;
 mod template;
@@ -18,6 +23,7 @@ mod text;
 mod timeline;
 mod ui;
 mod ui_wake;
+mod updater;
 
 use std::{
 	cell::Cell,
@@ -30,6 +36,7 @@ use std::{
 	time::{Duration, Instant},
 };
 
+use serde::{Deserialize, Serialize};
 use wxdragon::prelude::*;
 
 pub(crate) use crate::ui::ids::{
@@ -43,10 +50,10 @@ pub(crate) use crate::ui::ids::{
 	ID_VOTE, KEY_DELETE,
 };
 use crate::{
-	accounts::{start_add_account_flow, switch_to_account},
-	commands::{UiCommand, UiCommandContext, handle_ui_command},
-	config::Config,
-	mastodon::{MastodonClient, PollLimits},
+	accounts::{show_signed_out_state, start_add_account_flow, switch_to_account},
+	commands::{FocusTarget, UiCommand, UiCommandContext, handle_ui_command, restore_focus},
+	config::{Config, TimelineEnterAction},
+	mastodon::{InstanceInfo, InstanceOverview, List, MastodonClient, MediaLimits, PollLimits, Relationship},
 	network::NetworkHandle,
 	responses::{NetworkResponseContext, process_network_responses, process_stream_events},
 	timeline::TimelineManager,
@@ -61,15 +68,35 @@ use crate::{
 #[derive(Copy, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ContextMenuState {
+	pub has_entry: bool,
+	pub has_status: bool,
 	pub favourited: bool,
 	pub reblogged: bool,
 	pub bookmarked: bool,
 	pub pinned: bool,
+	pub muted: bool,
 	pub is_direct: bool,
 	pub is_own: bool,
+	pub is_follow_notification: bool,
+	pub is_notification: bool,
+	pub has_related_post: bool,
+	pub has_poll: bool,
+	pub has_media: bool,
+	pub has_quote: bool,
+	pub has_url: bool,
+	pub has_mentions: bool,
+	pub has_tags: bool,
+	pub has_boosts: bool,
+	pub has_favorites: bool,
+	pub has_raw_json: bool,
+	pub has_more_replies: bool,
+	pub is_boost: bool,
 	pub quick_action_keys: bool,
+	pub enter_action: TimelineEnterAction,
+	pub reply_all_default: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) enum PostOperation {
 	NewPost,
 	Reply { in_reply_to_id: String },
@@ -77,6 +104,7 @@ pub(crate) enum PostOperation {
 	Quote { quoted_status_id: String },
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct PendingPost {
 	pub config: ui::dialogs::ComposeDialogConfig,
 	pub operation: PostOperation,
@@ -93,6 +121,8 @@ pub(crate) struct AppState {
 	pub(crate) access_token: Option<String>,
 	pub(crate) max_post_chars: Option<usize>,
 	pub(crate) poll_limits: PollLimits,
+	pub(crate) media_limits: MediaLimits,
+	pub(crate) instance_info: Option<InstanceInfo>,
 	pub(crate) hashtag_dialog: Option<ui::dialogs::HashtagDialog>,
 	pub(crate) profile_dialog: Option<ui::dialogs::ProfileDialog>,
 	pub(crate) followers_dialog: Option<ui::dialogs::FollowListDialog>,
@@ -106,17 +136,41 @@ pub(crate) struct AppState {
 	pub(crate) current_user_id: Option<String>,
 	pub(crate) app_shell: Option<Rc<ui::app_shell::AppShell>>,
 	pub(crate) context_menu_state: Rc<Cell<ContextMenuState>>,
+	pub(crate) enter_action: Rc<Cell<TimelineEnterAction>>,
+	pub(crate) reply_all_default: Rc<Cell<bool>>,
 	pub(crate) media_ctrl: Option<MediaCtrl>,
 	pub(crate) ui_waker: UiWaker,
 	pub(crate) _instance_checker: Option<SingleInstanceChecker>,
 	pub(crate) pending_thread_continuation: bool,
 	pub(crate) pending_restore_post_id: Option<(crate::timeline::TimelineType, String)>,
 	pub(crate) pending_post: Option<PendingPost>,
-	pub(crate) pending_add_to_list_user: Option<String>,
+	pub(crate) pending_add_to_list_user: Option<(String, String)>,
+	pub(crate) pending_manage_list_id: Option<String>,
+	pub(crate) pending_new_list_members: Option<(String, Vec<String>)>,
+	pub(crate) lists: Vec<List>,
+	pub(crate) instance_overviews: HashMap<String, InstanceOverview>,
+	pub(crate) pending_reauth_account_id: Option<String>,
+	/// An account whose expired session the user chose not to sign in to again, so they aren't asked again until
+	/// they switch accounts or use Sign In Again.
+	pub(crate) declined_reauth_account_id: Option<String>,
+	pub(crate) last_resume_refresh: Option<Instant>,
+	/// When the soonest of the active account's filters expires, at which point the filters and timelines are
+	/// refetched.
+	pub(crate) next_filter_expiry: Option<chrono::DateTime<chrono::Utc>>,
+	pub(crate) relationships: HashMap<String, Relationship>,
+	pub(crate) pending_relationships: HashSet<String>,
+	/// Full details of the most recent failed request, cleared once a later request succeeds.
+	pub(crate) last_error: Option<String>,
+	/// Shown in the main window only while no account is signed in.
+	pub(crate) add_account_button: Option<Button>,
+	/// Read-only view of the selected entry's full text beneath the timeline list, shown when the option is on.
+	pub(crate) detail_pane: Option<TextCtrl>,
 }
 
 impl AppState {
 	fn new(config: Config, ui_waker: UiWaker, instance_checker: Option<SingleInstanceChecker>) -> Self {
+		let enter_action = Rc::new(Cell::new(config.timeline_enter_action));
+		let reply_all_default = Rc::new(Cell::new(config.reply_all_default));
 		Self {
 			config,
 			timeline_manager: TimelineManager::new(),
@@ -127,6 +181,8 @@ impl AppState {
 			access_token: None,
 			max_post_chars: None,
 			poll_limits: PollLimits::default(),
+			media_limits: MediaLimits::default(),
+			instance_info: None,
 			hashtag_dialog: None,
 			profile_dialog: None,
 			followers_dialog: None,
@@ -140,6 +196,8 @@ impl AppState {
 			current_user_id: None,
 			app_shell: None,
 			context_menu_state: Rc::new(Cell::new(ContextMenuState::default())),
+			enter_action,
+			reply_all_default,
 			media_ctrl: None,
 			ui_waker,
 			_instance_checker: instance_checker,
@@ -147,14 +205,24 @@ impl AppState {
 			pending_restore_post_id: None,
 			pending_post: None,
 			pending_add_to_list_user: None,
+			pending_manage_list_id: None,
+			pending_new_list_members: None,
+			lists: Vec::new(),
+			instance_overviews: HashMap::new(),
+			pending_reauth_account_id: None,
+			declined_reauth_account_id: None,
+			last_resume_refresh: None,
+			next_filter_expiry: None,
+			relationships: HashMap::new(),
+			pending_relationships: HashSet::new(),
+			last_error: None,
+			add_account_button: None,
+			detail_pane: None,
 		}
 	}
 
 	pub(crate) fn active_account(&self) -> Option<&config::Account> {
-		self.config
-			.active_account_id
-			.as_ref()
-			.map_or_else(|| self.config.accounts.first(), |id| self.config.accounts.iter().find(|a| &a.id == id))
+		self.config.active_account()
 	}
 
 	pub(crate) fn active_account_mut(&mut self) -> Option<&mut config::Account> {
@@ -166,7 +234,17 @@ impl AppState {
 	}
 
 	pub(crate) fn timeline_view_options_for(&self, timeline_type: &timeline::TimelineType) -> TimelineViewOptions {
-		TimelineViewOptions::from_config(&self.config, timeline_type)
+		let mut options = TimelineViewOptions::from_config(&self.config, timeline_type);
+		if self.config.show_follow_markers {
+			options.text_options.follow_states = Some(
+				self.relationships
+					.iter()
+					.filter(|(id, _)| self.current_user_id.as_ref() != Some(*id))
+					.map(|(id, rel)| (id.clone(), rel.following))
+					.collect(),
+			);
+		}
+		options
 	}
 }
 
@@ -180,7 +258,19 @@ pub fn get_sound_path() -> std::path::PathBuf {
 
 fn drain_ui_commands(ui_rx: &mpsc::Receiver<UiCommand>, ctx: &mut UiCommandContext<'_>) {
 	while let Ok(cmd) = ui_rx.try_recv() {
+		let previous_focus =
+			cmd.returns_focus().then(|| FocusTarget::current(ctx.state, ctx.timelines_selector, &ctx.timeline_list));
 		handle_ui_command(cmd, ctx);
+		if let Some(previous_focus) = previous_focus {
+			restore_focus(
+				previous_focus,
+				ctx.state,
+				ctx.frame,
+				ctx.timelines_selector,
+				&ctx.timeline_list,
+				ctx.tray_hidden,
+			);
+		}
 	}
 }
 
@@ -221,6 +311,9 @@ fn main() {
 		let tray_hidden = Rc::new(Cell::new(false));
 		let store = config::ConfigStore::new();
 		let config = store.load();
+		debug_log::set_enabled(config.debug_logging);
+		let proxy_error = proxy::set_proxy_url(config.proxy_url.as_deref()).err();
+		mastodon::set_user_agent(config.user_agent.as_deref());
 		let ui_alive = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
 		let ui_waker = UiWaker::new(frame, ui_alive.clone());
 		let ui_tx = UiCommandSender::new(ui_tx_raw, ui_waker.clone());
@@ -234,20 +327,37 @@ fn main() {
 			mc.load(&sound_path.to_string_lossy());
 		}
 		state.media_ctrl = Some(mc);
-		if state.config.accounts.is_empty() && !start_add_account_flow(&frame, &ui_tx, &mut state) {
-			frame.close(true);
-			return;
+		state.add_account_button = Some(window_parts.add_account_button);
+		state.detail_pane = Some(window_parts.detail_pane);
+		if state.config.show_detail_pane {
+			ui::window::set_shown(&window_parts.detail_pane, true);
+		}
+		if state.config.accounts.is_empty() {
+			// Cancelling leaves the window signed out rather than closing it.
+			let _ = start_add_account_flow(&frame, &ui_tx, &mut state);
+		}
+		if let Some(message) = proxy_error {
+			ui::dialogs::show_warning(
+				&frame,
+				&format!(
+					"{message}\n\nFedra will use the system proxy settings until the address is fixed in Options."
+				),
+				"Invalid Proxy",
+			);
 		}
 		if let Some(mb) = frame.get_menu_bar() {
 			update_menu_labels(&mb, &state);
 		}
 		switch_to_account(&mut state, &frame, timelines_selector, &timeline_list, &suppress_selection, false, None);
+		if state.config.accounts.is_empty() {
+			show_signed_out_state(&mut state, &frame, timelines_selector, &timeline_list);
+		}
 		let app_shell = Rc::new(ui::app_shell::install_app_shell(&frame, ui_tx.clone(), &state.config.hotkey));
 		let app_shell_close = app_shell.clone();
 		state.app_shell = Some(app_shell);
 
 		if state.config.check_for_updates_on_startup {
-			crate::ui::update_check::run_update_check(frame, true);
+			crate::ui::update_check::run_update_check(frame, state.config.update_channel, true);
 		}
 
 		let shutdown_wake = is_shutting_down.clone();
@@ -259,6 +369,8 @@ fn main() {
 		let timeline_list_wake = timeline_list;
 		let mut state = state;
 		let context_menu_state_for_handlers = state.context_menu_state.clone();
+		let enter_action_for_handlers = state.enter_action.clone();
+		let reply_all_default_for_handlers = state.reply_all_default.clone();
 		let ui_waker_handler = ui_waker.clone();
 		let quick_action_keys_drain = quick_action_keys_enabled.clone();
 		let autoload_drain = autoload_mode.clone();
@@ -294,7 +406,13 @@ fn main() {
 				busy_wake.set(false);
 				return;
 			}
-			process_stream_events(&mut state, &timeline_list_wake, &suppress_wake, &frame_wake);
+			process_stream_events(
+				&mut state,
+				timelines_selector_wake,
+				&timeline_list_wake,
+				&suppress_wake,
+				&frame_wake,
+			);
 			{
 				let mut network_ctx = NetworkResponseContext {
 					frame: &frame_wake,
@@ -359,6 +477,8 @@ fn main() {
 			autoload_mode,
 			sort_order_cell,
 			context_menu_state_for_handlers,
+			enter_action_for_handlers,
+			reply_all_default_for_handlers,
 		);
 		let shutdown_close = is_shutting_down;
 		let frame_close = frame;
@@ -1,25 +1,85 @@
-use std::{cmp, fmt::Write, thread, time::Duration};
+use std::{
+	cmp,
+	fmt::Write,
+	sync::{Arc, RwLock},
+	thread,
+	time::Duration,
+};
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Local, Utc};
 use chrono_humanize::HumanTime;
 use reqwest::{
 	StatusCode, Url,
-	blocking::{Client, multipart},
+	blocking::{Client, Response, multipart},
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::{
+	auth::Pkce,
 	config::{ContentWarningDisplay, DisplayNameEmojiMode, TimestampFormat},
-	html::strip_html,
-	template::{PostTemplateVars, render_template},
-	text::strip_display_name_emojis,
+	debug_log,
+	html::{strip_html, strip_html_with_emoji},
+	proxy,
+	template::{NotificationTemplateVars, PostTemplateVars, render_notification_template, render_template},
+	text::{poll_option_title, strip_display_name_emojis},
 	timeline::{TimelineTextOptions, TimelineType},
 };
 
 pub const DEFAULT_SCOPES: &str = "read write follow";
 
+static USER_AGENT: RwLock<Option<String>> = RwLock::new(None);
+
+/// Overrides the User-Agent sent by clients created from now on. `None` restores the default.
+pub fn set_user_agent(value: Option<&str>) {
+	if let Ok(mut user_agent) = USER_AGENT.write() {
+		*user_agent = value.map(str::trim).filter(|value| !value.is_empty()).map(str::to_string);
+	}
+}
+
+pub fn default_user_agent() -> String {
+	format!("Fedra/{}", env!("CARGO_PKG_VERSION"))
+}
+
+pub fn user_agent() -> String {
+	USER_AGENT.read().ok().and_then(|user_agent| user_agent.clone()).unwrap_or_else(default_user_agent)
+}
+
+/// A non-success HTTP status returned by the instance.
+#[derive(Debug)]
+pub struct HttpStatusError {
+	pub status: StatusCode,
+	url: Url,
+}
+
+impl std::fmt::Display for HttpStatusError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let kind = if self.status.is_client_error() { "client error" } else { "server error" };
+		write!(f, "HTTP status {kind} ({}) for url ({})", self.status, self.url)
+	}
+}
+
+impl std::error::Error for HttpStatusError {}
+
+trait CheckStatus: Sized {
+	/// Like `error_for_status`, but reads the body of a failed response so it can be written to the debug log.
+	fn check_status(self) -> Result<Self>;
+}
+
+impl CheckStatus for Response {
+	fn check_status(self) -> Result<Self> {
+		let status = self.status();
+		if !status.is_client_error() && !status.is_server_error() {
+			return Ok(self);
+		}
+		let url = self.url().clone();
+		let body = self.text().unwrap_or_default();
+		debug_log::log_failed_request(&url, status, &body);
+		Err(HttpStatusError { status, url }.into())
+	}
+}
+
 #[derive(Debug, Clone)]
 pub struct MastodonClient {
 	base_url: Url,
@@ -63,9 +123,11 @@ pub enum PostSubmission {
 pub struct Status {
 	pub id: String,
 	pub url: Option<String>,
+	#[serde(default, deserialize_with = "deserialize_string_or_empty")]
 	pub content: String,
 	pub created_at: String,
 	pub account: Account,
+	#[serde(default, deserialize_with = "deserialize_string_or_empty")]
 	pub spoiler_text: String,
 	pub reblog: Option<Box<Self>>,
 	pub quote: Option<Quote>,
@@ -73,6 +135,8 @@ pub struct Status {
 	#[serde(default)]
 	pub media_attachments: Vec<MediaAttachment>,
 	pub application: Option<Application>,
+	/// Kept as the raw API value, since other servers add their own, such as "local".
+	#[serde(default = "default_visibility")]
 	pub visibility: String,
 	#[serde(default)]
 	pub sensitive: bool,
@@ -91,6 +155,8 @@ pub struct Status {
 	#[serde(default)]
 	pub bookmarked: bool,
 	#[serde(default)]
+	pub muted: bool,
+	#[serde(default)]
 	pub conversation_id: Option<String>,
 	pub in_reply_to_id: Option<String>,
 	pub in_reply_to_account_id: Option<String>,
@@ -105,6 +171,9 @@ pub struct Status {
 	pub card: Option<Card>,
 	#[serde(default)]
 	pub filtered: Vec<FilterResult>,
+	/// The JSON this post was parsed from, only kept while debug logging is on.
+	#[serde(skip)]
+	pub raw_json: Option<Arc<Value>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -123,7 +192,7 @@ pub struct FilterResult {
 	pub filter: Filter,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[allow(dead_code)]
 pub struct Filter {
 	pub id: String,
@@ -136,7 +205,31 @@ pub struct Filter {
 	pub expires_at: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl Filter {
+	pub fn expires_at_time(&self) -> Option<DateTime<Utc>> {
+		self.expires_at.as_deref().and_then(|at| at.parse().ok())
+	}
+
+	/// Whether the expiry time has passed. The server stops applying the filter then, but posts fetched earlier still
+	/// carry it.
+	pub fn is_expired(&self) -> bool {
+		self.expires_at_time().is_some_and(|at| at <= Utc::now())
+	}
+
+	/// Remaining time as shown in the filter manager, such as "expires in 3 hours".
+	pub fn expiry_label(&self) -> Option<String> {
+		let at = self.expires_at_time()?;
+		if at <= Utc::now() { Some("expired".to_string()) } else { Some(format!("expires {}", HumanTime::from(at))) }
+	}
+}
+
+/// The soonest expiry among filters that haven't expired yet.
+pub fn next_filter_expiry(filters: &[Filter]) -> Option<DateTime<Utc>> {
+	let now = Utc::now();
+	filters.iter().filter_map(Filter::expires_at_time).filter(|at| *at > now).min()
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[allow(dead_code)]
 pub struct FilterKeyword {
 	pub id: String,
@@ -153,7 +246,7 @@ pub struct List {
 	pub exclusive: bool,
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum FilterContext {
 	Home,
@@ -188,6 +281,18 @@ impl<'de> serde::Deserialize<'de> for FilterAction {
 	}
 }
 
+impl serde::Serialize for FilterAction {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		match self {
+			Self::Other(s) => serializer.serialize_str(s),
+			_ => serializer.serialize_str(&self.to_string().to_lowercase()),
+		}
+	}
+}
+
 impl std::fmt::Display for FilterAction {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 		match self {
@@ -226,6 +331,38 @@ pub struct Poll {
 	pub options: Vec<PollOption>,
 	pub voted: Option<bool>,
 	pub own_votes: Option<Vec<u32>>,
+	#[serde(default)]
+	pub hide_totals: Option<bool>,
+}
+
+impl Poll {
+	/// Seconds until the poll closes, or None if it has no end time or has already ended.
+	pub fn expires_in(&self) -> Option<u32> {
+		let expires_at: DateTime<Utc> = self.expires_at.as_deref()?.trim().parse().ok()?;
+		let remaining = (expires_at - Utc::now()).num_seconds();
+		u32::try_from(remaining).ok().filter(|secs| *secs > 0)
+	}
+
+	/// Which option is ahead and by how much, e.g. "Tea leading with 75%", or "Tea and Coffee tied at 50%". None
+	/// before anyone has voted or when the server hides the totals.
+	pub fn leader_summary(&self, emoji_mode: DisplayNameEmojiMode) -> Option<String> {
+		if self.votes_count == 0 {
+			return None;
+		}
+		let top = self.options.iter().filter_map(|option| option.votes_count).max()?;
+		let pct = top.saturating_mul(100).saturating_add(self.votes_count / 2) / self.votes_count;
+		let leaders: Vec<String> = self
+			.options
+			.iter()
+			.filter(|option| option.votes_count == Some(top))
+			.map(|option| poll_option_title(&option.title, emoji_mode))
+			.collect();
+		match leaders.as_slice() {
+			[leader] => Some(format!("{leader} leading with {pct}%")),
+			[rest @ .., last] => Some(format!("{} and {last} tied at {pct}%", rest.join(", "))),
+			[] => None,
+		}
+	}
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -275,6 +412,41 @@ impl Mention {
 			self.acct.clone()
 		}
 	}
+
+	pub fn is_self(&self, account: &crate::config::Account) -> bool {
+		is_self_reference(account, &self.id, &self.acct, &self.url)
+	}
+}
+
+/// Whether an account reference from the API is the signed-in account. Matches the account id first, then the handle,
+/// ignoring case and accepting either the handle's domain or the instance host.
+pub fn is_self_reference(account: &crate::config::Account, id: &str, acct: &str, url: &str) -> bool {
+	if account.user_id.as_deref() == Some(id) {
+		return true;
+	}
+	let Some(self_acct) = account.acct.as_deref().map(|a| a.trim().trim_start_matches('@')).filter(|a| !a.is_empty())
+	else {
+		return false;
+	};
+	let host_of = |raw: &str| Url::parse(raw).ok().and_then(|u| u.host_str().map(str::to_ascii_lowercase));
+	let instance_host = host_of(&account.instance);
+	let (self_user, self_domain) = match self_acct.split_once('@') {
+		Some((user, domain)) => (user, Some(domain.to_ascii_lowercase())),
+		None => (self_acct, instance_host.clone()),
+	};
+	let acct = acct.trim().trim_start_matches('@');
+	let url_host = host_of(url);
+	let (user, domain) = match acct.split_once('@') {
+		Some((user, domain)) => (user, Some(domain.to_ascii_lowercase())),
+		None => (acct, url_host.clone()),
+	};
+	if !user.eq_ignore_ascii_case(self_user) {
+		return false;
+	}
+	domain.is_none()
+		|| domain == self_domain
+		|| domain == instance_host
+		|| (url_host.is_some() && url_host == instance_host)
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -359,19 +531,36 @@ impl Status {
 		strip_html(&self.content)
 	}
 
+	/// Word and character counts of the post text with an estimated reading time, such as "412 words, 2380
+	/// characters, about 3 minutes to read".
+	pub fn reading_stats(&self) -> String {
+		const WORDS_PER_MINUTE: usize = 200;
+		let text = self.display_text();
+		let words = text.split_whitespace().count();
+		let chars = text.chars().filter(|c| !c.is_whitespace()).count();
+		let minutes = words.div_ceil(WORDS_PER_MINUTE);
+		let reading_time = match minutes {
+			0 | 1 => "under a minute to read".to_string(),
+			_ => format!("about {minutes} minutes to read"),
+		};
+		let word_label = if words == 1 { "word" } else { "words" };
+		let char_label = if chars == 1 { "character" } else { "characters" };
+		format!("{words} {word_label}, {chars} {char_label}, {reading_time}")
+	}
+
 	pub fn simple_display(&self) -> String {
 		let mut out = String::new();
-		let content = self.content_with_cw(ContentWarningDisplay::Inline, true);
+		let content = self.content_with_cw(ContentWarningDisplay::Inline, true, DisplayNameEmojiMode::None);
 		if !content.is_empty() {
 			out.push_str(&content);
 		}
-		if let Some(media) = self.media_summary(ContentWarningDisplay::Inline, true) {
+		if let Some(media) = self.media_summary(ContentWarningDisplay::Inline, true, false) {
 			if !out.is_empty() {
 				out.push(' ');
 			}
 			out.push_str(&media);
 		}
-		if let Some(poll_text) = self.poll_summary() {
+		if let Some(poll_text) = self.poll_summary(DisplayNameEmojiMode::None) {
 			if !out.is_empty() {
 				out.push(' ');
 			}
@@ -380,8 +569,13 @@ impl Status {
 		out
 	}
 
-	pub fn should_hide(&self, filter_ctx: &FilterContext) -> bool {
-		self.filtered.iter().any(|f| f.filter.action == FilterAction::Hide && f.filter.context.contains(filter_ctx))
+	/// Whether a server filter hides this post. With `hide_warned`, filters that only warn hide it too.
+	pub fn should_hide(&self, filter_ctx: &FilterContext, hide_warned: bool) -> bool {
+		self.filtered.iter().any(|f| {
+			!f.filter.is_expired()
+				&& (f.filter.action == FilterAction::Hide || (hide_warned && f.filter.action == FilterAction::Warn))
+				&& f.filter.context.contains(filter_ctx)
+		})
 	}
 
 	pub fn matches_filter(&self, filter: &crate::config::TimelineFilter, current_user_id: Option<&str>) -> bool {
@@ -437,10 +631,17 @@ impl Status {
 		true
 	}
 
+	/// Whether this is a boost by an account whose boosts are hidden in Fedra.
+	pub fn is_hidden_boost(&self, config: &crate::config::Config) -> bool {
+		self.reblog.is_some() && config.hides_boosts_from(&self.account.full_acct())
+	}
+
 	fn filter_warning(&self, filter_ctx: &FilterContext) -> Option<String> {
 		self.filtered
 			.iter()
-			.find(|f| f.filter.action == FilterAction::Warn && f.filter.context.contains(filter_ctx))
+			.find(|f| {
+				f.filter.action == FilterAction::Warn && f.filter.context.contains(filter_ctx) && !f.filter.is_expired()
+			})
 			.map(|f| f.filter.title.clone())
 	}
 
@@ -469,6 +670,12 @@ impl Status {
 				render_template(boost_template, &vars)
 			},
 		);
+		let target = self.reblog.as_deref().unwrap_or(self);
+		let hide_media = target.hides_media(options, filter_ctx);
+		let text = match target.media_summary(options.cw_display, cw_expanded, hide_media) {
+			Some(media) if options.media_first => format!("{media} - {text}"),
+			_ => text,
+		};
 		if self.pinned { format!("Pinned: {text}") } else { text }
 	}
 
@@ -500,9 +707,14 @@ impl Status {
 			filter_cw.map_or_else(|| (self.spoiler_text.trim().to_string(), false), |fw| (fw, true));
 
 		let mut content = if is_filtered {
-			self.content_with_spoiler(options.cw_display, cw_expanded, &content_warning)
+			self.content_with_spoiler(
+				options.cw_display,
+				cw_expanded,
+				&content_warning,
+				options.display_name_emoji_mode,
+			)
 		} else {
-			self.content_with_cw(options.cw_display, cw_expanded)
+			self.content_with_cw(options.cw_display, cw_expanded, options.display_name_emoji_mode)
 		};
 		if options.show_link_previews
 			&& let Some(card) = self.card_summary()
@@ -516,12 +728,24 @@ impl Status {
 		let relative_time = friendly_time(&self.created_at, TimestampFormat::Relative).unwrap_or_default();
 		let absolute_time = friendly_time(&self.created_at, TimestampFormat::Absolute).unwrap_or_default();
 		let visibility = self.visibility_display();
-		let reply_count = count_label(self.replies_count, "reply", "replies");
+		let reply_count = match &options.loaded_replies {
+			Some(loaded) => thread_reply_label(
+				loaded.get(&self.id).copied().unwrap_or(0),
+				options.filtered_replies.get(&self.id).copied().unwrap_or(0),
+				self.replies_count,
+			),
+			None => count_label(self.replies_count, "reply", "replies"),
+		};
 		let boost_count = count_label(self.reblogs_count, "boost", "boosts");
 		let favorite_count = count_label(self.favourites_count, "favorite", "favorites");
 		let client = self.client_name().unwrap_or_default();
-		let media = self.media_summary(options.cw_display, cw_expanded).unwrap_or_default();
-		let poll = self.poll_summary().map_or_else(String::new, |p| format!(" {p}"));
+		let media = if options.media_first {
+			String::new()
+		} else {
+			self.media_summary(options.cw_display, cw_expanded, self.hides_media(options, filter_ctx))
+				.unwrap_or_default()
+		};
+		let poll = self.poll_summary(options.display_name_emoji_mode).map_or_else(String::new, |p| format!(" {p}"));
 
 		let (quote_author, quote_username, quote_content, quote_media, quote_poll) =
 			self.quote.as_ref().and_then(|q| q.quoted_status.as_ref()).map_or_else(
@@ -536,12 +760,15 @@ impl Status {
 					}
 					let author = quote.account.timeline_display_name(options.display_name_emoji_mode);
 					let username = format!("@{}", quote.account.acct);
-					let content = quote.content_with_cw(options.cw_display, cw_expanded);
+					let content =
+						quote.content_with_cw(options.cw_display, cw_expanded, options.display_name_emoji_mode);
 					let media = quote
-						.media_summary(options.cw_display, cw_expanded)
+						.media_summary(options.cw_display, cw_expanded, quote.hides_media(options, filter_ctx))
 						.map(|s| format!(" {s}"))
 						.unwrap_or_default();
-					let poll = quote.poll_summary().map_or_else(String::new, |p| format!(" {p}"));
+					let poll = quote
+						.poll_summary(options.display_name_emoji_mode)
+						.map_or_else(String::new, |p| format!(" {p}"));
 					(author, username, content, media, poll)
 				},
 			);
@@ -570,22 +797,118 @@ impl Status {
 		}
 	}
 
+	/// Spoken summary of boost, favorite and reply counts, or None when the post has no interactions.
+	pub fn interaction_summary(&self) -> Option<String> {
+		let parts: Vec<String> = [
+			count_label(self.reblogs_count, "boost", "boosts"),
+			count_label(self.favourites_count, "favorite", "favorites"),
+			count_label(self.replies_count, "reply", "replies"),
+		]
+		.into_iter()
+		.filter(|part| !part.is_empty())
+		.collect();
+		if parts.is_empty() { None } else { Some(parts.join(", ")) }
+	}
+
+	/// The whole post on separate lines for the detail pane: author, warnings, content, quote, media descriptions,
+	/// poll, link preview, when and how it was posted, and its counts. Anything behind a content warning stays hidden
+	/// until the post is expanded, as it does in the timeline.
+	pub fn detail_text(&self, options: &TimelineTextOptions, cw_expanded: bool) -> String {
+		let emoji_mode = options.display_name_emoji_mode;
+		if let Some(boosted) = self.reblog.as_deref() {
+			let booster = self.account.timeline_display_name(emoji_mode);
+			return format!(
+				"Boosted by {booster} (@{})\n{}",
+				self.account.acct,
+				boosted.detail_text(options, cw_expanded)
+			);
+		}
+		let mut lines = vec![format!("{} (@{})", self.account.timeline_display_name(emoji_mode), self.account.acct)];
+		let filter_warning = self.filter_warning(&options.filter_context);
+		let spoiler = self.spoiler_text.trim();
+		if let Some(filter) = &filter_warning {
+			lines.push(format!("Filtered: {filter}"));
+		}
+		if !spoiler.is_empty() {
+			lines.push(format!("Content warning: {spoiler}"));
+		}
+		let warned = filter_warning.is_some() || !spoiler.is_empty();
+		if warned && options.cw_display == ContentWarningDisplay::WarningOnly && !cw_expanded {
+			lines.push("Expand the post to read the rest.".to_string());
+		} else {
+			let content = strip_html_with_emoji(&self.content, emoji_mode);
+			if !content.is_empty() {
+				lines.push(content);
+			}
+			if let Some(quote) = self.quote.as_ref().and_then(|q| q.quoted_status.as_deref()) {
+				lines.push(format!(
+					"Quoting {} (@{}): {}",
+					quote.account.timeline_display_name(emoji_mode),
+					quote.account.acct,
+					quote.content_with_cw(options.cw_display, cw_expanded, emoji_mode)
+				));
+			}
+			if self.hides_media(options, &options.filter_context) && !cw_expanded {
+				lines.push(format!("{} sensitive media hidden", self.media_attachments.len()));
+			} else {
+				for (index, media) in self.media_attachments.iter().enumerate() {
+					let description = media
+						.description
+						.as_deref()
+						.map(str::trim)
+						.filter(|text| !text.is_empty())
+						.unwrap_or("(missing)");
+					lines.push(format!("Media {} ({}): {description}", index + 1, media.kind));
+				}
+			}
+			if let Some(poll) = &self.poll {
+				lines.extend(poll_details(poll, emoji_mode));
+			}
+			if let Some(card) = self.card_summary() {
+				lines.push(card);
+			}
+		}
+		let mut posted = format!(
+			"Posted {}, {}",
+			friendly_time(&self.created_at, TimestampFormat::Absolute).unwrap_or_default(),
+			self.visibility_display()
+		);
+		if let Some(client) = self.client_name() {
+			let _ = write!(posted, ", via {client}");
+		}
+		lines.push(posted);
+		lines.push(self.interaction_summary().unwrap_or_else(|| "No boosts, favorites, or replies".to_string()));
+		lines.join("\n")
+	}
+
 	fn visibility_display(&self) -> String {
 		match self.visibility.as_str() {
 			"public" => "Public".to_string(),
 			"unlisted" => "Unlisted".to_string(),
 			"private" => "Followers only".to_string(),
 			"direct" => "Direct".to_string(),
+			"local" => "Local only".to_string(),
 			other => other.to_string(),
 		}
 	}
 
-	pub fn content_with_cw(&self, cw_display: ContentWarningDisplay, cw_expanded: bool) -> String {
-		self.content_with_spoiler(cw_display, cw_expanded, self.spoiler_text.trim())
+	pub fn content_with_cw(
+		&self,
+		cw_display: ContentWarningDisplay,
+		cw_expanded: bool,
+		emoji_mode: DisplayNameEmojiMode,
+	) -> String {
+		self.content_with_spoiler(cw_display, cw_expanded, self.spoiler_text.trim(), emoji_mode)
 	}
 
-	fn content_with_spoiler(&self, cw_display: ContentWarningDisplay, cw_expanded: bool, spoiler: &str) -> String {
-		let content = self.display_text();
+	fn content_with_spoiler(
+		&self,
+		cw_display: ContentWarningDisplay,
+		cw_expanded: bool,
+		spoiler: &str,
+		emoji_mode: DisplayNameEmojiMode,
+	) -> String {
+		let content = strip_html_with_emoji(&self.content, emoji_mode);
 		if spoiler.is_empty() {
 			return content;
 		}
@@ -610,11 +933,33 @@ impl Status {
 			.map(std::string::ToString::to_string)
 	}
 
-	fn media_summary(&self, cw_display: ContentWarningDisplay, cw_expanded: bool) -> Option<String> {
+	/// A short list of attachment types and counts for the start of a timeline row, e.g. "[2 images, 1 video]".
+	pub fn media_indicator(&self) -> Option<String> {
+		media_type_counts(self.media_attachments.iter().map(|media| media.kind.as_str()))
+			.map(|counts| format!("[{counts}]"))
+	}
+
+	/// Whether this post's media descriptions stay hidden until expanded, because it is marked sensitive or a blur
+	/// filter matched it.
+	pub fn hides_media(&self, options: &TimelineTextOptions, filter_ctx: &FilterContext) -> bool {
+		options.hide_sensitive_media
+			&& !self.media_attachments.is_empty()
+			&& (self.sensitive
+				|| self.filtered.iter().any(|f| {
+					f.filter.action == FilterAction::Blur
+						&& f.filter.context.contains(filter_ctx)
+						&& !f.filter.is_expired()
+				}))
+	}
+
+	fn media_summary(&self, cw_display: ContentWarningDisplay, cw_expanded: bool, hide: bool) -> Option<String> {
 		if self.media_attachments.is_empty() {
 			return None;
 		}
 		let count = self.media_attachments.len();
+		if hide && !cw_expanded {
+			return Some(format!("{count} sensitive media hidden"));
+		}
 		let types = self
 			.media_attachments
 			.iter()
@@ -656,7 +1001,7 @@ impl Status {
 		}
 	}
 
-	fn poll_summary(&self) -> Option<String> {
+	fn poll_summary(&self, emoji_mode: DisplayNameEmojiMode) -> Option<String> {
 		let poll = self.poll.as_ref()?;
 		let show_results = poll.voted.unwrap_or(false) || poll.expired;
 
@@ -668,12 +1013,13 @@ impl Status {
 				.map(|opt| {
 					let votes = opt.votes_count.unwrap_or(0);
 					let pct = votes.saturating_mul(100).saturating_add(total / 2) / total;
-					format!("{}: {}%", opt.title, pct)
+					format!("{}: {}%", poll_option_title(&opt.title, emoji_mode), pct)
 				})
 				.collect();
 			Some(format!("[Poll Results: {}]", options.join(", ")))
 		} else {
-			let options: Vec<String> = poll.options.iter().map(|opt| opt.title.clone()).collect();
+			let options: Vec<String> =
+				poll.options.iter().map(|opt| poll_option_title(&opt.title, emoji_mode)).collect();
 			Some(format!("[Poll: {}]", options.join(", ")))
 		}
 	}
@@ -693,6 +1039,84 @@ impl Status {
 	}
 }
 
+/// Reply count for a post in a thread: the replies shown, how many filters hid, and how many more the server has when
+/// some aren't loaded, e.g. "1 reply, 1 hidden by filters, 2 more not loaded".
+fn thread_reply_label(loaded: u64, filtered: u64, on_server: u64) -> String {
+	let mut parts = Vec::new();
+	if loaded > 0 {
+		parts.push(count_label(loaded, "reply", "replies"));
+	}
+	if filtered > 0 {
+		let hidden = if parts.is_empty() { count_label(filtered, "reply", "replies") } else { filtered.to_string() };
+		parts.push(format!("{hidden} hidden by filters"));
+	}
+	match on_server.saturating_sub(loaded + filtered) {
+		0 => {}
+		unloaded if parts.is_empty() => parts.push(format!("{} not loaded", count_label(unloaded, "reply", "replies"))),
+		unloaded => parts.push(format!("{unloaded} more not loaded")),
+	}
+	parts.join(", ")
+}
+
+/// A poll one option per line, with results once the user has voted or the poll has ended.
+fn poll_details(poll: &Poll, emoji_mode: DisplayNameEmojiMode) -> Vec<String> {
+	let show_results = poll.voted.unwrap_or(false) || poll.expired;
+	let total = poll.votes_count.max(1);
+	let heading = if poll.expired {
+		"Poll, ended:"
+	} else if poll.multiple {
+		"Poll, choose one or more:"
+	} else {
+		"Poll:"
+	};
+	let mut lines = vec![heading.to_string()];
+	for (index, option) in poll.options.iter().enumerate() {
+		let title = poll_option_title(&option.title, emoji_mode);
+		let own_vote = poll.own_votes.as_ref().is_some_and(|votes| votes.iter().any(|&vote| vote as usize == index));
+		let marker = if own_vote { " (your vote)" } else { "" };
+		if show_results {
+			let votes = option.votes_count.unwrap_or(0);
+			let pct = votes.saturating_mul(100).saturating_add(total / 2) / total;
+			lines.push(format!("{title}: {pct}%{marker}"));
+		} else {
+			lines.push(title);
+		}
+	}
+	let voters = count_label(poll.voters_count.unwrap_or(poll.votes_count), "person voted", "people voted");
+	if !voters.is_empty() {
+		lines.push(voters);
+	}
+	lines
+}
+
+/// Counts attachments by type in order of first appearance, e.g. "2 images, 1 video".
+fn media_type_counts<'a>(kinds: impl IntoIterator<Item = &'a str>) -> Option<String> {
+	let mut counts: Vec<(&str, &str, u64)> = Vec::new();
+	for kind in kinds {
+		let (singular, plural) = match kind {
+			"image" => ("image", "images"),
+			"video" => ("video", "videos"),
+			"gifv" => ("GIF", "GIFs"),
+			"audio" => ("audio file", "audio files"),
+			_ => ("attachment", "attachments"),
+		};
+		match counts.iter_mut().find(|(name, _, _)| *name == singular) {
+			Some((_, _, count)) => *count += 1,
+			None => counts.push((singular, plural, 1)),
+		}
+	}
+	if counts.is_empty() {
+		return None;
+	}
+	Some(
+		counts
+			.into_iter()
+			.map(|(singular, plural, count)| count_label(count, singular, plural))
+			.collect::<Vec<_>>()
+			.join(", "),
+	)
+}
+
 fn count_label(count: u64, singular: &str, plural: &str) -> String {
 	if count == 0 {
 		String::new()
@@ -714,15 +1138,28 @@ pub struct Application {
 #[allow(dead_code)]
 pub struct MediaAttachment {
 	pub id: String,
-	#[serde(rename = "type")]
+	#[serde(rename = "type", default, deserialize_with = "deserialize_string_or_empty")]
 	pub kind: String,
+	/// Null on some servers until remote media has been fetched. Use `playable_url` rather than reading it directly.
+	#[serde(default, deserialize_with = "deserialize_string_or_empty")]
 	pub url: String,
 	#[serde(default)]
+	pub remote_url: Option<String>,
+	#[serde(default)]
 	pub preview_url: Option<String>,
 	#[serde(default)]
 	pub description: Option<String>,
 }
 
+impl MediaAttachment {
+	/// The local copy of the file if the server has one, otherwise the original on the remote server.
+	pub fn playable_url(&self) -> Option<&str> {
+		Some(self.url.as_str())
+			.filter(|url| !url.is_empty())
+			.or_else(|| self.remote_url.as_deref().filter(|url| !url.is_empty()))
+	}
+}
+
 #[derive(Debug, Clone, Deserialize)]
 #[allow(dead_code)]
 pub struct Report {
@@ -748,7 +1185,9 @@ pub struct Notification {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct StatusContext {
+	#[serde(default, deserialize_with = "deserialize_lenient_list")]
 	pub ancestors: Vec<Status>,
+	#[serde(default, deserialize_with = "deserialize_lenient_list")]
 	pub descendants: Vec<Status>,
 }
 
@@ -781,23 +1220,26 @@ impl Notification {
 
 	pub fn timeline_display(&self, options: &TimelineTextOptions, cw_expanded: bool) -> String {
 		let actor = self.account.timeline_display_name(options.display_name_emoji_mode);
+		let templates = &options.notification_templates;
+		let render =
+			|template: &str| render_notification_template(template, &self.template_vars(&actor, options, cw_expanded));
 		match self.kind.as_str() {
-			"mention" | "status" => self.status_text(options, cw_expanded),
-			"reblog" => self.status.as_ref().map_or_else(
-				|| format!("{actor} boosted a post"),
-				|status| {
+			"mention" | "status" => render(templates.resolve_mention_template()),
+			"reblog" => match (&self.status, &templates.boost) {
+				(None, _) => format!("{actor} (@{}) boosted a post", self.account.acct),
+				(Some(_), Some(template)) => render(template),
+				(Some(status), None) => {
 					let mut vars = status.build_template_vars(options, cw_expanded, &options.filter_context);
-					vars.booster.clone_from(&actor);
 					vars.booster_username = format!("@{}", self.account.acct);
+					// Naming the booster by handle as well keeps them apart from the author of the boosted post.
+					vars.booster = format!("{actor} ({})", vars.booster_username);
 					render_template(&options.boost_template, &vars)
-				},
-			),
-			"favourite" => {
-				format!("{} favorited {}", actor, self.status_text(options, cw_expanded))
-			}
-			"follow" => format!("{actor} followed you"),
+				}
+			},
+			"favourite" => render(templates.resolve_favorite_template()),
+			"follow" => render(templates.resolve_follow_template()),
 			"follow_request" => format!("{actor} requested to follow you"),
-			"poll" => format!("Poll ended: {}", self.status_text(options, cw_expanded)),
+			"poll" => render(templates.resolve_poll_template()),
 			"update" => format!("{} edited {}", actor, self.status_text(options, cw_expanded)),
 			"admin.sign_up" => format!("{actor} signed up"),
 			"admin.report" => self.format_admin_report(&actor, options.display_name_emoji_mode),
@@ -845,6 +1287,25 @@ impl Notification {
 		)
 	}
 
+	fn template_vars(&self, actor: &str, options: &TimelineTextOptions, cw_expanded: bool) -> NotificationTemplateVars {
+		let actor_username = format!("@{}", self.account.acct);
+		let mut status = self
+			.status
+			.as_ref()
+			.map(|status| status.build_template_vars(options, cw_expanded, &options.filter_context))
+			.unwrap_or_default();
+		if self.kind == "reblog" {
+			status.booster = actor.to_string();
+			status.booster_username.clone_from(&actor_username);
+		}
+		NotificationTemplateVars {
+			actor: actor.to_string(),
+			actor_username,
+			post: self.status_text(options, cw_expanded),
+			status,
+		}
+	}
+
 	fn status_text(&self, options: &TimelineTextOptions, cw_expanded: bool) -> String {
 		self.status_text_if_any(options, cw_expanded).unwrap_or_else(|| "No status content".to_string())
 	}
@@ -889,6 +1350,30 @@ pub struct Source {
 	pub privacy: Option<String>,
 	pub sensitive: Option<bool>,
 	pub language: Option<String>,
+	/// Plain-text bio, as the user typed it.
+	#[serde(default)]
+	pub note: Option<String>,
+	/// Default quote policy for new posts. Only sent by instances that support quote controls.
+	#[serde(default)]
+	pub quote_policy: Option<String>,
+}
+
+/// Filters emoji out of a display name per `mode`, falling back to the username and then the unfiltered name.
+/// Every place that shows an account's name should go through this so the emoji setting applies consistently.
+pub fn filtered_display_name(display_name: &str, username: &str, mode: DisplayNameEmojiMode) -> String {
+	let raw = if display_name.is_empty() { username } else { display_name };
+	if mode == DisplayNameEmojiMode::None {
+		return raw.to_string();
+	}
+	let filtered_display = strip_display_name_emojis(display_name, mode);
+	if !filtered_display.is_empty() {
+		return filtered_display;
+	}
+	let filtered_username = strip_display_name_emojis(username, mode);
+	if !filtered_username.is_empty() {
+		return filtered_username;
+	}
+	raw.to_string()
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -915,24 +1400,17 @@ impl Account {
 		}
 	}
 
+	pub fn is_self(&self, account: &crate::config::Account) -> bool {
+		is_self_reference(account, &self.id, &self.acct, &self.url)
+	}
+
 	pub fn timeline_display_name(&self, mode: DisplayNameEmojiMode) -> String {
-		if mode == DisplayNameEmojiMode::None {
-			return self.display_name_or_username().to_string();
-		}
-		let filtered_display = strip_display_name_emojis(&self.display_name, mode);
-		if !filtered_display.is_empty() {
-			return filtered_display;
-		}
-		let filtered_username = strip_display_name_emojis(&self.username, mode);
-		if !filtered_username.is_empty() {
-			return filtered_username;
-		}
-		self.display_name_or_username().to_string()
+		filtered_display_name(&self.display_name, &self.username, mode)
 	}
 
-	pub fn profile_display(&self) -> String {
+	pub fn profile_display(&self, mode: DisplayNameEmojiMode) -> String {
 		let mut lines = Vec::new();
-		let name = self.display_name_or_username();
+		let name = self.timeline_display_name(mode);
 		lines.push(format!("Name: {name}"));
 		lines.push(format!("Username: @{}", self.acct));
 		lines.push(format!("Direct Profile URL: {}", self.url));
@@ -948,7 +1426,7 @@ impl Account {
 			}
 		}
 		if !self.note.is_empty() {
-			let bio = strip_html(&self.note);
+			let bio = strip_html_with_emoji(&self.note, mode);
 			if !bio.trim().is_empty() {
 				lines.push(format!("Bio: {bio}"));
 			}
@@ -956,7 +1434,7 @@ impl Account {
 		if !self.fields.is_empty() {
 			lines.push("Fields:".to_string());
 			for field in &self.fields {
-				let value = strip_html(&field.value);
+				let value = strip_html_with_emoji(&field.value, mode);
 				lines.push(format!("\t{}: {}", field.name, value));
 			}
 		}
@@ -1002,7 +1480,11 @@ pub fn friendly_time_local(iso_time: &str) -> String {
 
 impl MastodonClient {
 	pub fn new(base_url: Url) -> Result<Self> {
-		let http = Client::builder().user_agent("Fedra/0.1").build().context("Failed to create HTTP client")?;
+		#[cfg(feature = "mock")]
+		let base_url = crate::mock::server_url().unwrap_or(base_url);
+		let http = proxy::configure(Client::builder().user_agent(user_agent()))?
+			.build()
+			.context("Failed to create HTTP client")?;
 		Ok(Self { base_url, http })
 	}
 
@@ -1011,7 +1493,32 @@ impl MastodonClient {
 		&self.base_url
 	}
 
-	pub fn register_app(&self, app_name: &str, redirect_uri: &str) -> Result<AppCredentials> {
+	/// Base URL for another instance's API. Reuses this client's own URL when `domain` is its host, so an
+	/// `http://` development server isn't upgraded to https.
+	fn instance_base_url(&self, domain: &str) -> Result<Url> {
+		if let Some(host) = self.base_url.host_str() {
+			let own = self.base_url.port().map_or_else(|| host.to_string(), |port| format!("{host}:{port}"));
+			if own.eq_ignore_ascii_case(domain) || host.eq_ignore_ascii_case(domain) {
+				return Ok(self.base_url.clone());
+			}
+		}
+		Ok(Url::parse(&format!("https://{domain}/"))?)
+	}
+
+	fn timeline_base_url(&self, timeline_type: &TimelineType) -> Result<Url> {
+		match timeline_type {
+			TimelineType::InstanceLocal { instance } => self.instance_base_url(instance),
+			_ => Ok(self.base_url.clone()),
+		}
+	}
+
+	pub fn register_app(
+		&self,
+		app_name: &str,
+		website: &str,
+		redirect_uri: &str,
+		scopes: &str,
+	) -> Result<AppCredentials> {
 		let url = self.base_url.join("api/v1/apps")?;
 		let response = self
 			.http
@@ -1019,48 +1526,95 @@ impl MastodonClient {
 			.form(&[
 				("client_name", app_name),
 				("redirect_uris", redirect_uri),
-				("scopes", DEFAULT_SCOPES),
-				("website", ""),
+				("scopes", scopes),
+				("website", website),
 			])
 			.send()
 			.context("Failed to register app with instance")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected app registration")?;
 		let payload: RegisterAppResponse = response.json().context("Invalid response from instance")?;
 		Ok(AppCredentials { client_id: payload.client_id, client_secret: payload.client_secret })
 	}
 
-	pub fn build_authorize_url(&self, credentials: &AppCredentials, redirect_uri: &str) -> Result<Url> {
+	/// Checks the instance's OAuth server metadata for S256 PKCE support (Mastodon 4.3+).
+	pub fn supports_pkce(&self) -> bool {
+		let Ok(url) = self.base_url.join(".well-known/oauth-authorization-server") else { return false };
+		let Ok(response) = self.http.get(url).send().and_then(reqwest::blocking::Response::error_for_status) else {
+			return false;
+		};
+		response
+			.json::<OAuthServerMetadata>()
+			.is_ok_and(|metadata| metadata.code_challenge_methods_supported.iter().any(|m| m == "S256"))
+	}
+
+	pub fn build_authorize_url(
+		&self,
+		credentials: &AppCredentials,
+		redirect_uri: &str,
+		scopes: &str,
+		pkce: Option<&Pkce>,
+	) -> Result<Url> {
 		let mut url = self.base_url.join("oauth/authorize")?;
-		url.query_pairs_mut()
-			.append_pair("client_id", &credentials.client_id)
-			.append_pair("redirect_uri", redirect_uri)
-			.append_pair("response_type", "code")
-			.append_pair("scope", DEFAULT_SCOPES);
+		{
+			let mut query = url.query_pairs_mut();
+			query
+				.append_pair("client_id", &credentials.client_id)
+				.append_pair("redirect_uri", redirect_uri)
+				.append_pair("response_type", "code")
+				.append_pair("scope", scopes);
+			if let Some(pkce) = pkce {
+				query.append_pair("code_challenge", &pkce.challenge).append_pair("code_challenge_method", "S256");
+			}
+		}
 		Ok(url)
 	}
 
-	pub fn exchange_token(&self, credentials: &AppCredentials, code: &str, redirect_uri: &str) -> Result<String> {
+	pub fn exchange_token(
+		&self,
+		credentials: &AppCredentials,
+		code: &str,
+		redirect_uri: &str,
+		scopes: &str,
+		pkce: Option<&Pkce>,
+	) -> Result<String> {
 		let url = self.base_url.join("oauth/token")?;
+		let mut params = vec![
+			("client_id", credentials.client_id.as_str()),
+			("client_secret", credentials.client_secret.as_str()),
+			("redirect_uri", redirect_uri),
+			("grant_type", "authorization_code"),
+			("code", code),
+			("scope", scopes),
+		];
+		if let Some(pkce) = pkce {
+			params.push(("code_verifier", pkce.verifier.as_str()));
+		}
 		let response = self
 			.http
 			.post(url)
-			.form(&[
-				("client_id", credentials.client_id.as_str()),
-				("client_secret", credentials.client_secret.as_str()),
-				("redirect_uri", redirect_uri),
-				("grant_type", "authorization_code"),
-				("code", code),
-				("scope", DEFAULT_SCOPES),
-			])
+			.form(&params)
 			.send()
 			.context("Failed to exchange token")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected token exchange")?;
 		let payload: TokenResponse = response.json().context("Invalid token response")?;
 		Ok(payload.access_token)
 	}
 
+	pub fn revoke_token(&self, client_id: &str, client_secret: &str, token: &str) -> Result<()> {
+		let url = self.base_url.join("oauth/revoke")?;
+		let _ = self
+			.http
+			.post(url)
+			.form(&[("client_id", client_id), ("client_secret", client_secret), ("token", token)])
+			.send()
+			.context("Failed to revoke token")?
+			.check_status()
+			.context("Instance rejected token revocation")?;
+		Ok(())
+	}
+
 	pub fn post_status_with_media(
 		&self,
 		access_token: &str,
@@ -1125,7 +1679,9 @@ impl MastodonClient {
 			self.http.post(url).bearer_auth(access_token).form(&params).send().context("Failed to post status")?;
 		let status = response.status();
 		if !status.is_success() {
+			let failed_url = response.url().clone();
 			let body = response.text().unwrap_or_default();
+			debug_log::log_failed_request(&failed_url, status, &body);
 			let detail = serde_json::from_str::<Value>(&body)
 				.ok()
 				.and_then(|json| {
@@ -1158,7 +1714,7 @@ impl MastodonClient {
 		let response =
 			self.http.post(url).bearer_auth(access_token).multipart(form).send().context("Failed to upload media")?;
 		let status = response.status();
-		let response = response.error_for_status().context("Instance rejected media upload")?;
+		let response = response.check_status().context("Instance rejected media upload")?;
 		let payload: MediaResponse = response.json().context("Invalid media upload response")?;
 		// v2/media returns 202 when the media is still processing asynchronously.
 		if status == reqwest::StatusCode::ACCEPTED {
@@ -1215,7 +1771,7 @@ impl MastodonClient {
 		limit: Option<u32>,
 		max_id: Option<&str>,
 	) -> Result<(Vec<Status>, Option<String>)> {
-		let mut url = self.base_url.join(&timeline_type.api_path())?;
+		let mut url = self.timeline_base_url(timeline_type)?.join(&timeline_type.api_path())?;
 		{
 			let mut query = url.query_pairs_mut();
 			for (key, value) in timeline_type.api_query_params() {
@@ -1235,13 +1791,13 @@ impl MastodonClient {
 		let response = req
 			.send()
 			.context("Failed to fetch timeline")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected timeline request")?;
 
 		let next_max_id =
 			response.headers().get("link").and_then(|h| h.to_str().ok()).and_then(Self::parse_link_header);
 
-		let statuses: Vec<Status> = response.json().context("Invalid timeline response")?;
+		let statuses = lenient_list(response.json().context("Invalid timeline response")?);
 		Ok((statuses, next_max_id))
 	}
 
@@ -1254,7 +1810,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch pinned statuses")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected pinned statuses request")?;
 
 		let statuses: Vec<Status> = response.json().context("Invalid pinned statuses response")?;
@@ -1268,7 +1824,7 @@ impl MastodonClient {
 		limit: Option<u32>,
 		max_id: Option<&str>,
 	) -> Result<(Vec<Notification>, Option<String>)> {
-		let mut url = self.base_url.join(&timeline_type.api_path())?;
+		let mut url = self.timeline_base_url(timeline_type)?.join(&timeline_type.api_path())?;
 		{
 			let mut query = url.query_pairs_mut();
 			for (key, value) in timeline_type.api_query_params() {
@@ -1287,13 +1843,13 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch notifications")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected notifications request")?;
 
 		let next_max_id =
 			response.headers().get("link").and_then(|h| h.to_str().ok()).and_then(Self::parse_link_header);
 
-		let notifications: Vec<Notification> = response.json().context("Invalid notifications response")?;
+		let notifications = lenient_list(response.json().context("Invalid notifications response")?);
 		Ok((notifications, next_max_id))
 	}
 
@@ -1319,13 +1875,13 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch conversations")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected conversations request")?;
 
 		let next_max_id =
 			response.headers().get("link").and_then(|h| h.to_str().ok()).and_then(Self::parse_link_header);
 
-		let conversations: Vec<Conversation> = response.json().context("Invalid conversations response")?;
+		let conversations = lenient_list(response.json().context("Invalid conversations response")?);
 		Ok((conversations, next_max_id))
 	}
 
@@ -1337,7 +1893,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to verify credentials")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected credential verification")?;
 		let account: Account = response.json().context("Invalid credentials response")?;
 		Ok(account)
@@ -1351,7 +1907,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch account")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected account request")?;
 		let account: Account = response.json().context("Invalid account response")?;
 		Ok(account)
@@ -1365,12 +1921,26 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected status request")?;
-		let status: Status = response.json().context("Invalid status response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid status response")?;
 		Ok(status)
 	}
 
+	pub fn get_poll(&self, access_token: &str, poll_id: &str) -> Result<Poll> {
+		let url = self.base_url.join(&format!("api/v1/polls/{poll_id}"))?;
+		let response = self
+			.http
+			.get(url)
+			.bearer_auth(access_token)
+			.send()
+			.context("Failed to fetch poll")?
+			.check_status()
+			.context("Instance rejected poll request")?;
+		let poll: Poll = response.json().context("Invalid poll response")?;
+		Ok(poll)
+	}
+
 	pub fn lookup_account(&self, access_token: &str, acct: &str) -> Result<Account> {
 		let mut url = self.base_url.join("api/v1/accounts/lookup")?;
 		url.query_pairs_mut().append_pair("acct", acct);
@@ -1380,7 +1950,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to lookup account")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected account lookup")?;
 		let account: Account = response.json().context("Invalid account response")?;
 		Ok(account)
@@ -1394,9 +1964,9 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to favorite status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected favorite request")?;
-		let status: Status = response.json().context("Invalid favorite response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid favorite response")?;
 		Ok(status)
 	}
 
@@ -1408,9 +1978,9 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to bookmark status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected bookmark request")?;
-		let status: Status = response.json().context("Invalid bookmark response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid bookmark response")?;
 		Ok(status)
 	}
 
@@ -1422,9 +1992,9 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to unfavorite status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected unfavorite request")?;
-		let status: Status = response.json().context("Invalid unfavorite response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid unfavorite response")?;
 		Ok(status)
 	}
 
@@ -1436,9 +2006,9 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to unbookmark status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected unbookmark request")?;
-		let status: Status = response.json().context("Invalid unbookmark response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid unbookmark response")?;
 		Ok(status)
 	}
 
@@ -1450,9 +2020,9 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to pin status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected pin request")?;
-		let status: Status = response.json().context("Invalid pin response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid pin response")?;
 		Ok(status)
 	}
 
@@ -1464,12 +2034,53 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to unpin status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected unpin request")?;
-		let status: Status = response.json().context("Invalid unpin response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid unpin response")?;
+		Ok(status)
+	}
+
+	pub fn mute_conversation(&self, access_token: &str, status_id: &str) -> Result<Status> {
+		let url = self.base_url.join(&format!("api/v1/statuses/{status_id}/mute"))?;
+		let response = self
+			.http
+			.post(url)
+			.bearer_auth(access_token)
+			.send()
+			.context("Failed to mute conversation")?
+			.check_status()
+			.context("Instance rejected mute conversation request")?;
+		let status: Status = json_keeping_raw(response).context("Invalid mute conversation response")?;
 		Ok(status)
 	}
 
+	pub fn unmute_conversation(&self, access_token: &str, status_id: &str) -> Result<Status> {
+		let url = self.base_url.join(&format!("api/v1/statuses/{status_id}/unmute"))?;
+		let response = self
+			.http
+			.post(url)
+			.bearer_auth(access_token)
+			.send()
+			.context("Failed to unmute conversation")?
+			.check_status()
+			.context("Instance rejected unmute conversation request")?;
+		let status: Status = json_keeping_raw(response).context("Invalid unmute conversation response")?;
+		Ok(status)
+	}
+
+	pub fn mark_conversation_read(&self, access_token: &str, conversation_id: &str) -> Result<()> {
+		let url = self.base_url.join(&format!("api/v1/conversations/{conversation_id}/read"))?;
+		let _ = self
+			.http
+			.post(url)
+			.bearer_auth(access_token)
+			.send()
+			.context("Failed to mark conversation as read")?
+			.check_status()
+			.context("Instance rejected marking conversation as read")?;
+		Ok(())
+	}
+
 	pub fn reblog(&self, access_token: &str, status_id: &str) -> Result<Status> {
 		let url = self.base_url.join(&format!("api/v1/statuses/{status_id}/reblog"))?;
 		let response = self
@@ -1478,9 +2089,9 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to boost status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected boost request")?;
-		let status: Status = response.json().context("Invalid boost response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid boost response")?;
 		Ok(status)
 	}
 
@@ -1492,9 +2103,9 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to unboost status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected unboost request")?;
-		let status: Status = response.json().context("Invalid unboost response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid unboost response")?;
 		Ok(status)
 	}
 
@@ -1505,7 +2116,7 @@ impl MastodonClient {
 			.get(url)
 			.send()
 			.context("Failed to fetch instance info")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected info request")?;
 		let info: InstanceResponse = response.json().context("Invalid instance response")?;
 		let max_chars =
@@ -1513,8 +2124,53 @@ impl MastodonClient {
 				as usize;
 		let poll_limits =
 			info.configuration.as_ref().and_then(|c| c.polls.as_ref()).map(PollLimits::from_config).unwrap_or_default();
+		let media_limits = info
+			.configuration
+			.as_ref()
+			.map(|c| MediaLimits::from_config(c.statuses.as_ref(), c.media_attachments.as_ref()))
+			.unwrap_or_default();
 		let streaming_url = info.urls.and_then(|u| u.streaming_api);
-		Ok(InstanceInfo { max_post_chars: max_chars, poll_limits, streaming_url })
+		let (software, software_version) = parse_instance_software(&info.version);
+		Ok(InstanceInfo {
+			title: info.title,
+			domain: info.uri,
+			software,
+			software_version,
+			reported_version: info.version,
+			max_post_chars: max_chars,
+			poll_limits,
+			media_limits,
+			streaming_url,
+		})
+	}
+
+	pub fn get_instance_overview(&self) -> Result<InstanceOverview> {
+		let url = self.base_url.join("api/v1/instance")?;
+		let response = self
+			.http
+			.get(url)
+			.send()
+			.context("Failed to fetch instance info")?
+			.check_status()
+			.context("Instance rejected info request")?;
+		let info: InstanceResponse = response.json().context("Invalid instance response")?;
+		// Older servers don't have the dedicated rules endpoint, so fall back to the rules embedded in the instance.
+		let rules = self.get_instance_rules().unwrap_or(info.rules);
+		let description = if info.description.trim().is_empty() { info.short_description } else { info.description };
+		Ok(InstanceOverview { title: info.title, description, rules })
+	}
+
+	pub fn get_instance_rules(&self) -> Result<Vec<InstanceRule>> {
+		let url = self.base_url.join("api/v1/instance/rules")?;
+		let response = self
+			.http
+			.get(url)
+			.send()
+			.context("Failed to fetch instance rules")?
+			.check_status()
+			.context("Instance rejected rules request")?;
+		let rules: Vec<InstanceRule> = response.json().context("Invalid instance rules response")?;
+		Ok(rules)
 	}
 
 	pub fn get_status_context(&self, access_token: &str, status_id: &str) -> Result<StatusContext> {
@@ -1525,7 +2181,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch status context")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected status context request")?;
 		let context: StatusContext = response.json().context("Invalid status context response")?;
 		Ok(context)
@@ -1539,7 +2195,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to follow tag")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected tag follow request")?;
 		let tag: Tag = response.json().context("Invalid tag response")?;
 		Ok(tag)
@@ -1553,7 +2209,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to unfollow tag")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected tag unfollow request")?;
 		let tag: Tag = response.json().context("Invalid tag response")?;
 		Ok(tag)
@@ -1567,7 +2223,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch tag info")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected tag info request")?;
 		let tag: Tag = response.json().context("Invalid tag response")?;
 		Ok(tag)
@@ -1581,7 +2237,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch boosts")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected boosts request")?;
 		let accounts: Vec<Account> = response.json().context("Invalid boosts response")?;
 		Ok(accounts)
@@ -1595,7 +2251,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch favorites")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected favorites request")?;
 		let accounts: Vec<Account> = response.json().context("Invalid favorites response")?;
 		Ok(accounts)
@@ -1619,7 +2275,7 @@ impl MastodonClient {
 		if let Some(token) = access_token {
 			req = req.bearer_auth(token);
 		}
-		let response = req.send()?.error_for_status()?;
+		let response = req.send()?.check_status()?;
 		let next_max_id =
 			response.headers().get("link").and_then(|h| h.to_str().ok()).and_then(Self::parse_link_header);
 		let accounts: Vec<Account> = response.json()?;
@@ -1678,7 +2334,7 @@ impl MastodonClient {
 		if domain.is_empty() {
 			return Err(anyhow::anyhow!("Invalid remote acct: {acct}"));
 		}
-		let base_url = Url::parse(&format!("https://{domain}/"))?;
+		let base_url = self.instance_base_url(domain)?;
 		let mut lookup_url = base_url.join("api/v1/accounts/lookup")?;
 		lookup_url.query_pairs_mut().append_pair("acct", acct);
 		let account: Account = self
@@ -1686,7 +2342,7 @@ impl MastodonClient {
 			.get(lookup_url)
 			.send()
 			.context("Failed to lookup account on remote instance")?
-			.error_for_status()
+			.check_status()
 			.context("Could not find this account on their home instance")?
 			.json()
 			.context("Invalid account response from remote instance")?;
@@ -1722,7 +2378,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to perform search")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected search request")?;
 		let results: SearchResults = response.json().context("Invalid search response")?;
 		Ok(results)
@@ -1742,7 +2398,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch relationships")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected relationships request")?;
 		let relationships: Vec<Relationship> = response.json().context("Invalid relationships response")?;
 		Ok(relationships)
@@ -1767,7 +2423,7 @@ impl MastodonClient {
 			.form(&[("reblogs", if reblogs { "true" } else { "false" })])
 			.send()
 			.context("Failed to follow account")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected follow request")?;
 		let relationship: Relationship = response.json().context("Invalid relationship response")?;
 		Ok(relationship)
@@ -1781,7 +2437,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to unfollow account")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected unfollow request")?;
 		let relationship: Relationship = response.json().context("Invalid relationship response")?;
 		Ok(relationship)
@@ -1795,7 +2451,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to authorize follow request")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected follow request authorization")?;
 		let relationship: Relationship = response.json().context("Invalid relationship response")?;
 		Ok(relationship)
@@ -1809,7 +2465,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to reject follow request")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected follow request rejection")?;
 		let relationship: Relationship = response.json().context("Invalid relationship response")?;
 		Ok(relationship)
@@ -1823,7 +2479,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to block account")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected block request")?;
 		let relationship: Relationship = response.json().context("Invalid relationship response")?;
 		Ok(relationship)
@@ -1837,7 +2493,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to unblock account")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected unblock request")?;
 		let relationship: Relationship = response.json().context("Invalid relationship response")?;
 		Ok(relationship)
@@ -1851,7 +2507,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to mute account")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected mute request")?;
 		let relationship: Relationship = response.json().context("Invalid relationship response")?;
 		Ok(relationship)
@@ -1865,7 +2521,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to unmute account")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected unmute request")?;
 		let relationship: Relationship = response.json().context("Invalid relationship response")?;
 		Ok(relationship)
@@ -1884,7 +2540,7 @@ impl MastodonClient {
 			.form(&params)
 			.send()
 			.context("Failed to vote on poll")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected vote request")?;
 		let poll: Poll = response.json().context("Invalid poll response")?;
 		Ok(poll)
@@ -1898,7 +2554,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to delete status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected delete request")?;
 		Ok(())
 	}
@@ -1917,6 +2573,7 @@ impl MastodonClient {
 		source_privacy: Option<&str>,
 		source_sensitive: Option<bool>,
 		source_language: Option<&str>,
+		source_quote_policy: Option<&str>,
 	) -> Result<Account> {
 		let url = self.base_url.join("api/v1/accounts/update_credentials")?;
 		let mut form = multipart::Form::new();
@@ -1959,6 +2616,9 @@ impl MastodonClient {
 		if let Some(v) = source_language {
 			form = form.text("source[language]", v.to_string());
 		}
+		if let Some(v) = source_quote_policy {
+			form = form.text("source[quote_policy]", v.to_string());
+		}
 
 		let response = self
 			.http
@@ -1967,7 +2627,7 @@ impl MastodonClient {
 			.multipart(form)
 			.send()
 			.context("Failed to update credentials")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected credentials update")?;
 		let account: Account = response.json().context("Invalid account response")?;
 		Ok(account)
@@ -1981,7 +2641,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch status source")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected request")?;
 		let source: StatusSource = response.json().context("Invalid source response")?;
 		Ok(source)
@@ -1996,6 +2656,7 @@ impl MastodonClient {
 		spoiler_text: Option<&str>,
 		language: Option<&str>,
 		media_ids: &[String],
+		media_descriptions: &[(String, String)],
 		poll: Option<&crate::network::PollData>,
 	) -> Result<Status> {
 		let url = self.base_url.join(&format!("api/v1/statuses/{status_id}"))?;
@@ -2012,6 +2673,11 @@ impl MastodonClient {
 		for media_id in media_ids {
 			params.push(("media_ids[]".to_string(), media_id.clone()));
 		}
+		// Attached media can't be updated through /api/v1/media any more, so edited descriptions go with the edit.
+		for (media_id, description) in media_descriptions {
+			params.push(("media_attributes[][id]".to_string(), media_id.clone()));
+			params.push(("media_attributes[][description]".to_string(), description.clone()));
+		}
 		if let Some(poll) = poll {
 			for option in &poll.options {
 				params.push(("poll[options][]".to_string(), option.clone()));
@@ -2027,9 +2693,9 @@ impl MastodonClient {
 			.form(&params)
 			.send()
 			.context("Failed to edit status")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected edit request")?;
-		let status: Status = response.json().context("Invalid edit response")?;
+		let status: Status = json_keeping_raw(response).context("Invalid edit response")?;
 		Ok(status)
 	}
 
@@ -2041,7 +2707,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch filters")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected filters request")?;
 		let filters: Vec<Filter> = response.json().context("Invalid filters response")?;
 		Ok(filters)
@@ -2055,12 +2721,26 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch lists")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected lists request")?;
 		let lists: Vec<List> = response.json().context("Invalid lists response")?;
 		Ok(lists)
 	}
 
+	pub fn get_account_lists(&self, access_token: &str, account_id: &str) -> Result<Vec<List>> {
+		let url = self.base_url.join(&format!("api/v1/accounts/{account_id}/lists"))?;
+		let response = self
+			.http
+			.get(url)
+			.bearer_auth(access_token)
+			.send()
+			.context("Failed to fetch account lists")?
+			.check_status()
+			.context("Instance rejected account lists request")?;
+		let lists: Vec<List> = response.json().context("Invalid account lists response")?;
+		Ok(lists)
+	}
+
 	pub fn create_list(&self, access_token: &str, title: &str, replies_policy: &str, exclusive: bool) -> Result<List> {
 		let url = self.base_url.join("api/v1/lists")?;
 		let response = self
@@ -2074,7 +2754,7 @@ impl MastodonClient {
 			])
 			.send()
 			.context("Failed to create list")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected list creation")?;
 		let list: List = response.json().context("Invalid list response")?;
 		Ok(list)
@@ -2100,7 +2780,7 @@ impl MastodonClient {
 			])
 			.send()
 			.context("Failed to update list")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected list update")?;
 		let list: List = response.json().context("Invalid list response")?;
 		Ok(list)
@@ -2114,7 +2794,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to delete list")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected list deletion")?;
 		Ok(())
 	}
@@ -2128,7 +2808,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to fetch list accounts")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected list accounts request")?;
 		let accounts: Vec<Account> = response.json().context("Invalid list accounts response")?;
 		Ok(accounts)
@@ -2147,7 +2827,7 @@ impl MastodonClient {
 			.form(&params)
 			.send()
 			.context("Failed to add accounts to list")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected adding accounts")?;
 		Ok(())
 	}
@@ -2166,7 +2846,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to remove accounts from list")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected removing accounts")?;
 		Ok(())
 	}
@@ -2203,7 +2883,7 @@ impl MastodonClient {
 			.form(&params)
 			.send()
 			.context("Failed to create filter")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected filter creation")?;
 		let filter: Filter = response.json().context("Invalid filter response")?;
 		Ok(filter)
@@ -2248,7 +2928,7 @@ impl MastodonClient {
 			.form(&params)
 			.send()
 			.context("Failed to update filter")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected filter update")?;
 		let filter: Filter = response.json().context("Invalid filter response")?;
 		Ok(filter)
@@ -2262,7 +2942,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to delete filter")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected filter deletion")?;
 		Ok(())
 	}
@@ -2282,7 +2962,7 @@ impl MastodonClient {
 			.form(&[("keyword", keyword), ("whole_word", if whole_word { "true" } else { "false" })])
 			.send()
 			.context("Failed to add filter keyword")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected adding filter keyword")?;
 		let kw: FilterKeyword = response.json().context("Invalid filter keyword response")?;
 		Ok(kw)
@@ -2296,7 +2976,7 @@ impl MastodonClient {
 			.bearer_auth(access_token)
 			.send()
 			.context("Failed to delete filter keyword")?
-			.error_for_status()
+			.check_status()
 			.context("Instance rejected deleting filter keyword")?;
 		Ok(())
 	}
@@ -2320,12 +3000,30 @@ struct MediaResponse {
 
 #[derive(Debug, Deserialize)]
 struct InstanceResponse {
+	#[serde(default)]
+	uri: String,
+	#[serde(default)]
+	title: String,
+	#[serde(default)]
+	version: String,
+	#[serde(default)]
+	description: String,
+	#[serde(default)]
+	short_description: String,
+	#[serde(default)]
+	rules: Vec<InstanceRule>,
 	#[serde(default)]
 	configuration: Option<InstanceConfiguration>,
 	#[serde(default)]
 	urls: Option<InstanceUrls>,
 }
 
+#[derive(Debug, Deserialize)]
+struct OAuthServerMetadata {
+	#[serde(default)]
+	code_challenge_methods_supported: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct InstanceUrls {
 	#[serde(default)]
@@ -2338,12 +3036,26 @@ struct InstanceConfiguration {
 	statuses: Option<StatusConfiguration>,
 	#[serde(default)]
 	polls: Option<PollConfiguration>,
+	#[serde(default)]
+	media_attachments: Option<MediaConfiguration>,
 }
 
 #[derive(Debug, Deserialize)]
 struct StatusConfiguration {
 	#[serde(default)]
 	max_characters: Option<u32>,
+	#[serde(default)]
+	max_media_attachments: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MediaConfiguration {
+	#[serde(default)]
+	image_size_limit: Option<u64>,
+	#[serde(default)]
+	video_size_limit: Option<u64>,
+	#[serde(default)]
+	supported_mime_types: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -2383,16 +3095,215 @@ impl Default for PollLimits {
 	}
 }
 
+/// Media file extensions and the MIME types servers list them under, in the order the file picker offers them.
+const MEDIA_TYPES: &[(&str, &[&str])] = &[
+	("png", &["image/png"]),
+	("jpg", &["image/jpeg"]),
+	("jpeg", &["image/jpeg"]),
+	("gif", &["image/gif"]),
+	("webp", &["image/webp"]),
+	("heic", &["image/heic"]),
+	("heif", &["image/heif"]),
+	("avif", &["image/avif"]),
+	("mp4", &["video/mp4"]),
+	("m4v", &["video/mp4", "video/x-m4v"]),
+	("webm", &["video/webm", "audio/webm"]),
+	("mov", &["video/quicktime"]),
+	("mp3", &["audio/mpeg", "audio/mp3"]),
+	("ogg", &["audio/ogg", "video/ogg"]),
+	("wav", &["audio/wave", "audio/wav", "audio/x-wav", "audio/vnd.wave"]),
+	("flac", &["audio/flac", "audio/x-flac"]),
+	("opus", &["audio/opus", "audio/ogg"]),
+	("aac", &["audio/aac"]),
+	("m4a", &["audio/mp4", "audio/x-m4a"]),
+	("3gp", &["video/3gpp", "audio/3gpp"]),
+];
+
+fn media_types_for(path: &str) -> Option<&'static [&'static str]> {
+	let ext = std::path::Path::new(path).extension()?.to_str()?;
+	MEDIA_TYPES.iter().find(|(known, _)| ext.eq_ignore_ascii_case(known)).map(|(_, types)| *types)
+}
+
+/// File extensions the server counts as images when choosing which size limit applies. Everything else, animated GIFs
+/// included, falls under the larger video limit.
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp", "heic", "heif", "avif", "bmp", "tif", "tiff"];
+
+#[derive(Debug, Clone)]
+pub struct MediaLimits {
+	pub max_attachments: usize,
+	/// Largest image upload, in bytes.
+	pub image_size_limit: u64,
+	/// Largest video, animated GIF or audio upload, in bytes.
+	pub video_size_limit: u64,
+	/// MIME types the server accepts. Empty when it didn't say, in which case any file is offered.
+	pub supported_mime_types: Vec<String>,
+}
+
+impl MediaLimits {
+	fn from_config(statuses: Option<&StatusConfiguration>, media: Option<&MediaConfiguration>) -> Self {
+		let defaults = Self::default();
+		Self {
+			max_attachments: statuses
+				.and_then(|s| s.max_media_attachments)
+				.map_or(defaults.max_attachments, |max| max as usize),
+			image_size_limit: media.and_then(|m| m.image_size_limit).unwrap_or(defaults.image_size_limit),
+			video_size_limit: media.and_then(|m| m.video_size_limit).unwrap_or(defaults.video_size_limit),
+			supported_mime_types: media.map(|m| m.supported_mime_types.clone()).unwrap_or_default(),
+		}
+	}
+
+	/// Whether the server accepts the file at `path`. Files are allowed when the server didn't list its types, or
+	/// when the extension isn't one Fedra knows, so the server gets the final say.
+	pub fn accepts(&self, path: &str) -> bool {
+		media_types_for(path).is_none_or(|types| self.accepts_types(types))
+	}
+
+	fn accepts_types(&self, types: &[&str]) -> bool {
+		self.supported_mime_types.is_empty()
+			|| types
+				.iter()
+				.any(|mime| self.supported_mime_types.iter().any(|supported| supported.eq_ignore_ascii_case(mime)))
+	}
+
+	/// Patterns for the file picker's media filter, e.g. "*.png;*.jpg", limited to what the server accepts. Falls back
+	/// to every known type when the server accepts none of them, so the filter never hides everything.
+	pub fn file_wildcard(&self) -> String {
+		let accepted: Vec<_> = MEDIA_TYPES.iter().filter(|(_, types)| self.accepts_types(types)).collect();
+		let shown = if accepted.is_empty() { MEDIA_TYPES.iter().collect() } else { accepted };
+		shown.iter().map(|(ext, _)| format!("*.{ext}")).collect::<Vec<_>>().join(";")
+	}
+
+	/// The size limit for the file at `path`, going by its extension.
+	pub fn size_limit_for(&self, path: &str) -> u64 {
+		let is_image = std::path::Path::new(path)
+			.extension()
+			.and_then(|ext| ext.to_str())
+			.is_some_and(|ext| IMAGE_EXTENSIONS.iter().any(|image| ext.eq_ignore_ascii_case(image)));
+		if is_image { self.image_size_limit } else { self.video_size_limit }
+	}
+}
+
+impl Default for MediaLimits {
+	fn default() -> Self {
+		Self {
+			max_attachments: 4,
+			image_size_limit: 16 * 1024 * 1024,
+			video_size_limit: 99 * 1024 * 1024,
+			supported_mime_types: Vec::new(),
+		}
+	}
+}
+
 #[derive(Debug, Clone)]
 pub struct InstanceInfo {
+	pub title: String,
+	pub domain: String,
+	pub software: String,
+	pub software_version: String,
+	/// The version string exactly as the server sent it.
+	pub reported_version: String,
 	pub max_post_chars: usize,
 	pub poll_limits: PollLimits,
+	pub media_limits: MediaLimits,
 	pub streaming_url: Option<String>,
 }
 
 impl Default for InstanceInfo {
 	fn default() -> Self {
-		Self { max_post_chars: 500, poll_limits: PollLimits::default(), streaming_url: None }
+		Self {
+			title: String::new(),
+			domain: String::new(),
+			software: String::new(),
+			software_version: String::new(),
+			reported_version: String::new(),
+			max_post_chars: 500,
+			poll_limits: PollLimits::default(),
+			media_limits: MediaLimits::default(),
+			streaming_url: None,
+		}
+	}
+}
+
+impl InstanceInfo {
+	/// Plain-text summary for bug reports, one detail per line.
+	pub fn display(&self) -> String {
+		let mut lines = Vec::new();
+		match (self.title.trim(), self.domain.trim()) {
+			("", "") => {}
+			(title, "") => lines.push(format!("Instance: {title}")),
+			("", domain) => lines.push(format!("Instance: {domain}")),
+			(title, domain) => lines.push(format!("Instance: {title} ({domain})")),
+		}
+		if !self.software.is_empty() {
+			lines.push(format!("Software: {} {}", self.software, self.software_version).trim_end().to_string());
+		}
+		if !self.reported_version.is_empty() && self.reported_version != self.software_version {
+			lines.push(format!("Reported version: {}", self.reported_version));
+		}
+		lines.push(format!("Maximum post length: {} characters", self.max_post_chars));
+		lines.push(format!("Fedra version: {}", env!("CARGO_PKG_VERSION")));
+		lines.join("\r\n")
+	}
+}
+
+/// Splits the `version` field of `/api/v1/instance` into software name and version. Servers other than Mastodon
+/// advertise themselves as "2.7.2 (compatible; Pleroma 2.5.0)".
+fn parse_instance_software(version: &str) -> (String, String) {
+	let version = version.trim();
+	if version.is_empty() {
+		return (String::new(), String::new());
+	}
+	if let Some((_, compatible)) = version.split_once("(compatible;")
+		&& let Some(product) = compatible.split(')').next().map(str::trim)
+		&& !product.is_empty()
+	{
+		return match product.rsplit_once(' ') {
+			Some((name, product_version)) => (name.trim().to_string(), product_version.trim().to_string()),
+			None => (product.to_string(), String::new()),
+		};
+	}
+	("Mastodon".to_string(), version.to_string())
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[allow(dead_code)]
+pub struct InstanceRule {
+	pub id: String,
+	pub text: String,
+	#[serde(default)]
+	pub hint: String,
+}
+
+/// Public description and server rules, shown before authorizing a new account.
+#[derive(Debug, Clone, Default)]
+pub struct InstanceOverview {
+	pub title: String,
+	pub description: String,
+	pub rules: Vec<InstanceRule>,
+}
+
+impl InstanceOverview {
+	pub fn display(&self) -> String {
+		let mut lines = Vec::new();
+		if !self.title.trim().is_empty() {
+			lines.push(self.title.trim().to_string());
+		}
+		let description = strip_html(&self.description);
+		if !description.trim().is_empty() {
+			lines.push(description.trim().to_string());
+		}
+		if self.rules.is_empty() {
+			lines.push("This instance has not published any rules.".to_string());
+		} else {
+			lines.push("Rules:".to_string());
+			for (index, rule) in self.rules.iter().enumerate() {
+				lines.push(format!("{}. {}", index + 1, rule.text.trim()));
+				if !rule.hint.trim().is_empty() {
+					lines.push(format!("\t{}", rule.hint.trim()));
+				}
+			}
+		}
+		lines.join("\r\n")
 	}
 }
 
@@ -2412,6 +3323,75 @@ where
 	}
 }
 
+fn default_visibility() -> String {
+	"public".to_string()
+}
+
+/// Types that can hold on to the JSON they were parsed from, so it can be copied for bug reports.
+pub trait KeepRawJson {
+	fn keep_raw_json(&mut self, raw: &Value);
+}
+
+impl KeepRawJson for Status {
+	fn keep_raw_json(&mut self, raw: &Value) {
+		self.raw_json = Some(Arc::new(raw.clone()));
+	}
+}
+
+impl KeepRawJson for Notification {
+	fn keep_raw_json(&mut self, raw: &Value) {
+		if let (Some(status), Some(raw_status)) = (self.status.as_mut(), raw.get("status")) {
+			status.keep_raw_json(raw_status);
+		}
+	}
+}
+
+impl KeepRawJson for Conversation {
+	fn keep_raw_json(&mut self, raw: &Value) {
+		if let (Some(status), Some(raw_status)) = (self.last_status.as_mut(), raw.get("last_status")) {
+			status.keep_raw_json(raw_status);
+		}
+	}
+}
+
+/// Parses `value`, keeping a copy of it on the result while debug logging is on.
+pub fn from_value_keeping_raw<T: serde::de::DeserializeOwned + KeepRawJson>(value: Value) -> serde_json::Result<T> {
+	let raw = debug_log::enabled().then(|| value.clone());
+	let mut item: T = serde_json::from_value(value)?;
+	if let Some(raw) = raw {
+		item.keep_raw_json(&raw);
+	}
+	Ok(item)
+}
+
+fn json_keeping_raw<T: serde::de::DeserializeOwned + KeepRawJson>(response: Response) -> Result<T> {
+	Ok(from_value_keeping_raw(response.json()?)?)
+}
+
+/// Parses each entry of a list on its own, dropping the ones that don't parse, so one post or notification in a shape
+/// we don't understand doesn't fail the whole timeline.
+fn lenient_list<T: serde::de::DeserializeOwned + KeepRawJson>(values: Vec<Value>) -> Vec<T> {
+	values.into_iter().filter_map(|value| from_value_keeping_raw(value).ok()).collect()
+}
+
+fn deserialize_lenient_list<'de, D, T>(deserializer: D) -> std::result::Result<Vec<T>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+	T: serde::de::DeserializeOwned + KeepRawJson,
+{
+	Ok(lenient_list(Vec::<Value>::deserialize(deserializer)?))
+}
+
+fn deserialize_string_or_empty<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	match Value::deserialize(deserializer)? {
+		Value::String(s) => Ok(s),
+		_ => Ok(String::new()),
+	}
+}
+
 fn deserialize_option_u64_or_zero<'de, D>(deserializer: D) -> std::result::Result<Option<u64>, D::Error>
 where
 	D: serde::Deserializer<'de>,
@@ -2427,3 +3407,321 @@ where
 		_ => Ok(None),
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use serde_json::{Value, json};
+
+	use super::{
+		Filter, MastodonClient, MediaAttachment, MediaLimits, Mention, Notification, Status, filtered_display_name,
+		is_self_reference, lenient_list, media_type_counts, next_filter_expiry, parse_instance_software,
+	};
+	use crate::{
+		config::{Account, ContentWarningDisplay, DisplayNameEmojiMode},
+		timeline::TimelineTextOptions,
+	};
+
+	fn signed_in(acct: &str) -> Account {
+		let mut account = Account::new("https://mastodon.example".to_string());
+		account.acct = Some(acct.to_string());
+		account.user_id = Some("100".to_string());
+		account
+	}
+
+	fn status_json(id: &str, visibility: &str) -> Value {
+		json!({
+			"id": id,
+			"content": "<p>Hello</p>",
+			"created_at": "2026-01-01T00:00:00.000Z",
+			"account": {"id": "1", "username": "alice", "acct": "alice", "display_name": "Alice", "url": "https://example.com/@alice"},
+			"visibility": visibility,
+			"reblogs_count": 0,
+			"favourites_count": 0,
+			"replies_count": 0,
+		})
+	}
+
+	fn mention(id: &str, acct: &str, url: &str) -> Mention {
+		Mention {
+			id: id.to_string(),
+			username: acct.split('@').next().unwrap_or(acct).to_string(),
+			acct: acct.to_string(),
+			url: url.to_string(),
+		}
+	}
+
+	#[test]
+	fn display_name_keeps_emoji_in_none_mode() {
+		let name = filtered_display_name("Alice 😄 :party_parrot:", "alice", DisplayNameEmojiMode::None);
+		assert_eq!(name, "Alice 😄 :party_parrot:");
+	}
+
+	#[test]
+	fn display_name_filters_by_mode() {
+		let name = "Alice 😄 :party_parrot:";
+		assert_eq!(filtered_display_name(name, "alice", DisplayNameEmojiMode::UnicodeOnly), "Alice :party_parrot:");
+		assert_eq!(filtered_display_name(name, "alice", DisplayNameEmojiMode::InstanceOnly), "Alice 😄");
+		assert_eq!(filtered_display_name(name, "alice", DisplayNameEmojiMode::All), "Alice");
+	}
+
+	#[test]
+	fn emoji_only_display_name_falls_back_to_username() {
+		assert_eq!(filtered_display_name(":blobcat: 😄", "alice", DisplayNameEmojiMode::All), "alice");
+		assert_eq!(filtered_display_name("", "alice", DisplayNameEmojiMode::None), "alice");
+	}
+
+	#[test]
+	fn self_mention_matches_local_and_federated_handles() {
+		let account = signed_in("alice");
+		assert!(mention("100", "alice", "https://mastodon.example/@alice").is_self(&account));
+		assert!(mention("9", "Alice", "https://mastodon.example/@Alice").is_self(&account));
+		assert!(mention("9", "alice@Mastodon.Example", "https://mastodon.example/@alice").is_self(&account));
+		assert!(mention("9", "@alice@mastodon.example", "https://mastodon.example/@alice").is_self(&account));
+	}
+
+	#[test]
+	fn self_mention_matches_web_domain_handles() {
+		let account = signed_in("alice");
+		assert!(mention("9", "alice@example", "https://mastodon.example/@alice").is_self(&account));
+		let account = signed_in("alice@example");
+		assert!(is_self_reference(&account, "9", "ALICE@example", "https://other.example/@alice"));
+	}
+
+	#[test]
+	fn self_mention_ignores_same_username_elsewhere() {
+		let account = signed_in("alice");
+		assert!(!mention("9", "alice@other.example", "https://other.example/@alice").is_self(&account));
+		assert!(!mention("9", "bob", "https://mastodon.example/@bob").is_self(&account));
+	}
+
+	#[test]
+	fn media_type_counts_group_by_type_in_order() {
+		assert_eq!(media_type_counts(["image", "video", "image"]).as_deref(), Some("2 images, 1 video"));
+		assert_eq!(media_type_counts(["gifv", "audio", "audio"]).as_deref(), Some("1 GIF, 2 audio files"));
+		assert_eq!(media_type_counts(["unknown"]).as_deref(), Some("1 attachment"));
+		assert_eq!(media_type_counts([]), None);
+	}
+
+	#[test]
+	fn media_attachment_accepts_gotosocial_meta_and_blurhash() {
+		let media: MediaAttachment = serde_json::from_str(
+			r#"{"id":"01HZ","type":"image","url":"https://gts.example/fileserver/a.jpeg","text_url":null,
+			"preview_url":"https://gts.example/fileserver/small/a.jpeg","remote_url":null,"preview_remote_url":null,
+			"meta":{"original":{"width":800,"height":600,"size":"800x600","aspect":1.3333334},
+			"small":{"width":512,"height":384,"size":"512x384","aspect":1.3333334},"focus":{"x":-0.5,"y":0.25}},
+			"description":"A cat","blurhash":"LKO2?U%2Tw=w]~RBVZRi};RPxuwH"}"#,
+		)
+		.unwrap();
+		assert_eq!(media.kind, "image");
+		assert_eq!(media.playable_url(), Some("https://gts.example/fileserver/a.jpeg"));
+		assert_eq!(media.description.as_deref(), Some("A cat"));
+	}
+
+	#[test]
+	fn media_attachment_accepts_pleroma_extensions_without_meta() {
+		let media: MediaAttachment = serde_json::from_str(
+			r#"{"id":"42","type":"video","url":"https://pl.example/media/b.mp4","remote_url":"https://pl.example/media/b.mp4",
+			"preview_url":"https://pl.example/media/b.mp4","text_url":"https://pl.example/media/b.mp4","description":null,
+			"pleroma":{"mime_type":"video/mp4"}}"#,
+		)
+		.unwrap();
+		assert_eq!(media.kind, "video");
+		assert_eq!(media.playable_url(), Some("https://pl.example/media/b.mp4"));
+		assert_eq!(media.description, None);
+	}
+
+	#[test]
+	fn media_attachment_falls_back_to_remote_url_when_url_is_null() {
+		let media: MediaAttachment = serde_json::from_str(
+			r#"{"id":"9","type":null,"url":null,"remote_url":"https://remote.example/c.png","preview_url":null,
+			"meta":null,"blurhash":null}"#,
+		)
+		.unwrap();
+		assert_eq!(media.kind, "");
+		assert_eq!(media.playable_url(), Some("https://remote.example/c.png"));
+	}
+
+	#[test]
+	fn status_keeps_unknown_visibility() {
+		let status: Status = serde_json::from_value(status_json("1", "local")).unwrap();
+		assert_eq!(status.visibility, "local");
+		assert_eq!(status.visibility_display(), "Local only");
+		let status: Status = serde_json::from_value(status_json("2", "mutuals_only")).unwrap();
+		assert_eq!(status.visibility_display(), "mutuals_only");
+	}
+
+	#[test]
+	fn reading_stats_count_words_and_minutes() {
+		let mut status: Status = serde_json::from_value(status_json("1", "public")).unwrap();
+		assert_eq!(status.reading_stats(), "1 word, 5 characters, under a minute to read");
+		status.content = format!("<p>{}</p>", "word ".repeat(450));
+		assert_eq!(status.reading_stats(), "450 words, 1800 characters, about 3 minutes to read");
+	}
+
+	#[test]
+	fn detail_text_lists_poll_and_keeps_warned_content_hidden() {
+		let mut options = TimelineTextOptions::from_config_default(&crate::config::Config::default());
+		options.cw_display = ContentWarningDisplay::WarningOnly;
+		let mut json = status_json("1", "public");
+		json["spoiler_text"] = json!("Food");
+		json["replies_count"] = json!(2);
+		json["poll"] = json!({
+			"id": "7", "expires_at": null, "expired": false, "multiple": false, "votes_count": 4, "voters_count": 4,
+			"options": [{"title": "Tea", "votes_count": 3}, {"title": "Coffee", "votes_count": 1}],
+			"voted": true, "own_votes": [0],
+		});
+		let status: Status = serde_json::from_value(json).unwrap();
+		let hidden = status.detail_text(&options, false);
+		assert!(hidden.starts_with("Alice (@alice)\nContent warning: Food\nExpand the post to read the rest.\n"));
+		assert!(!hidden.contains("Tea"));
+		let expanded = status.detail_text(&options, true);
+		assert!(expanded.contains("\nHello\nPoll:\nTea: 75% (your vote)\nCoffee: 25%\n4 people voted\nPosted "));
+		assert!(expanded.ends_with("\n2 replies"));
+	}
+
+	#[test]
+	fn poll_leader_summary_names_leader_or_tie() {
+		let poll = |counts: [u64; 3]| -> super::Poll {
+			serde_json::from_value(json!({
+				"id": "7", "expires_at": null, "expired": false, "multiple": false,
+				"votes_count": counts.iter().sum::<u64>(),
+				"options": [
+					{"title": "Tea", "votes_count": counts[0]},
+					{"title": "Coffee", "votes_count": counts[1]},
+					{"title": "Water", "votes_count": counts[2]},
+				],
+			}))
+			.unwrap()
+		};
+		let mode = DisplayNameEmojiMode::None;
+		assert_eq!(poll([3, 1, 0]).leader_summary(mode).as_deref(), Some("Tea leading with 75%"));
+		assert_eq!(poll([1, 1, 0]).leader_summary(mode).as_deref(), Some("Tea and Coffee tied at 50%"));
+		assert_eq!(poll([1, 1, 1]).leader_summary(mode).as_deref(), Some("Tea, Coffee and Water tied at 33%"));
+		assert_eq!(poll([0, 0, 0]).leader_summary(mode), None);
+	}
+
+	#[test]
+	fn media_limits_come_from_instance_configuration() {
+		let info: super::InstanceResponse = serde_json::from_value(json!({
+			"configuration": {
+				"statuses": {"max_characters": 500, "max_media_attachments": 6},
+				"media_attachments": {"image_size_limit": 10_485_760},
+			},
+		}))
+		.unwrap();
+		let config = info.configuration.unwrap();
+		let limits = MediaLimits::from_config(config.statuses.as_ref(), config.media_attachments.as_ref());
+		let video_limit = MediaLimits::default().video_size_limit;
+		assert_eq!(limits.max_attachments, 6);
+		assert_eq!(limits.size_limit_for("photos/cat.JPG"), 10_485_760);
+		assert_eq!(limits.size_limit_for("clip.mp4"), video_limit);
+		assert_eq!(limits.size_limit_for("dance.gif"), video_limit);
+	}
+
+	#[test]
+	fn media_limits_check_supported_types() {
+		let mut limits = MediaLimits::default();
+		assert!(limits.accepts("photo.avif"));
+		assert!(limits.file_wildcard().contains("*.avif"));
+		limits.supported_mime_types = vec!["image/jpeg".to_string(), "image/png".to_string(), "audio/wave".to_string()];
+		assert!(limits.accepts("photo.JPG"));
+		assert!(limits.accepts("sound.wav"));
+		assert!(!limits.accepts("photo.avif"));
+		assert!(limits.accepts("notes.xyz"));
+		assert_eq!(limits.file_wildcard(), "*.png;*.jpg;*.jpeg;*.wav");
+		limits.supported_mime_types = vec!["application/x-unknown".to_string()];
+		assert!(limits.file_wildcard().contains("*.png"));
+		assert!(limits.file_wildcard().contains("*.avif"));
+	}
+
+	#[test]
+	fn notification_keeps_unknown_type() {
+		let notification: Notification = serde_json::from_value(json!({
+			"id": "5",
+			"type": "pleroma:emoji_reaction",
+			"created_at": "2026-01-01T00:00:00.000Z",
+			"account": {"id": "2", "username": "bob", "acct": "bob@other.example", "display_name": "", "url": "https://other.example/@bob"},
+			"status": status_json("1", "public"),
+		}))
+		.unwrap();
+		assert_eq!(notification.kind, "pleroma:emoji_reaction");
+		assert_eq!(notification.simple_display(), "pleroma:emoji_reaction");
+	}
+
+	#[test]
+	fn hidden_boosts_match_the_booster_ignoring_case() {
+		let mut config = crate::config::Config::default();
+		config.hide_boosts_from.insert("alice@example.com".to_string());
+		let mut boost = status_json("1", "public");
+		boost["account"]["acct"] = json!("Alice");
+		boost["reblog"] = status_json("2", "public");
+		let boost: Status = serde_json::from_value(boost).unwrap();
+		let original: Status = serde_json::from_value(status_json("3", "public")).unwrap();
+		assert!(boost.is_hidden_boost(&config));
+		assert!(!original.is_hidden_boost(&config));
+		config.hide_boosts_from.clear();
+		assert!(!boost.is_hidden_boost(&config));
+	}
+
+	#[test]
+	fn lenient_list_skips_entries_that_do_not_parse() {
+		let statuses: Vec<Status> =
+			lenient_list(vec![status_json("1", "public"), json!({"id": "2"}), status_json("3", "local")]);
+		let ids: Vec<&str> = statuses.iter().map(|status| status.id.as_str()).collect();
+		assert_eq!(ids, ["1", "3"]);
+	}
+
+	#[test]
+	fn instance_base_url_keeps_scheme_for_own_host() {
+		let client = MastodonClient::new(reqwest::Url::parse("http://localhost:3000/").unwrap()).unwrap();
+		assert_eq!(client.instance_base_url("localhost:3000").unwrap().as_str(), "http://localhost:3000/");
+		assert_eq!(client.instance_base_url("mastodon.example").unwrap().as_str(), "https://mastodon.example/");
+	}
+
+	#[test]
+	fn mock_fixtures_parse() {
+		let home: Vec<Status> =
+			serde_json::from_str(include_str!("../fixtures/mock/api/v1/timelines/home.json")).unwrap();
+		assert!(home.iter().any(|status| status.reblog.as_ref().is_some_and(|boosted| boosted.poll.is_some())));
+		let public: Vec<Status> =
+			serde_json::from_str(include_str!("../fixtures/mock/api/v1/timelines/public.json")).unwrap();
+		assert!(!public.is_empty());
+		let notifications: Vec<Notification> =
+			serde_json::from_str(include_str!("../fixtures/mock/api/v1/notifications.json")).unwrap();
+		assert!(notifications.iter().any(|notification| notification.status.is_none()));
+		let me: super::Account =
+			serde_json::from_str(include_str!("../fixtures/mock/api/v1/accounts/verify_credentials.json")).unwrap();
+		assert_eq!(me.id, "100");
+	}
+
+	#[test]
+	fn parses_instance_software_from_version() {
+		let owned = |(name, version): (&str, &str)| (name.to_string(), version.to_string());
+		assert_eq!(parse_instance_software("4.2.1"), owned(("Mastodon", "4.2.1")));
+		assert_eq!(parse_instance_software("2.7.2 (compatible; Pleroma 2.5.0)"), owned(("Pleroma", "2.5.0")));
+		assert_eq!(
+			parse_instance_software("3.5.3 (compatible; Akkoma 3.9.3-0-gabc)"),
+			owned(("Akkoma", "3.9.3-0-gabc"))
+		);
+		assert_eq!(parse_instance_software(""), owned(("", "")));
+	}
+
+	#[test]
+	fn next_filter_expiry_skips_expired_and_permanent_filters() {
+		let filter = |id: &str, expires_at: Option<&str>| -> Filter {
+			serde_json::from_value(json!({
+				"id": id, "title": id, "context": ["home"], "filter_action": "hide", "keywords": [],
+				"expires_at": expires_at,
+			}))
+			.unwrap()
+		};
+		let filters = [
+			filter("past", Some("2001-01-01T00:00:00Z")),
+			filter("forever", None),
+			filter("later", Some("2999-06-01T00:00:00Z")),
+			filter("soon", Some("2999-01-01T00:00:00Z")),
+		];
+		assert_eq!(next_filter_expiry(&filters), filters[3].expires_at_time());
+		assert_eq!(next_filter_expiry(&filters[..2]), None);
+	}
+}
@@ -0,0 +1,117 @@
+use std::{
+	fs,
+	io::{BufRead, BufReader, Read, Write},
+	net::{TcpListener, TcpStream},
+	path::{Component, Path, PathBuf},
+	sync::OnceLock,
+	thread,
+};
+
+use url::Url;
+
+const MOCK_DIR_ENV: &str = "FEDRA_MOCK_DIR";
+
+static SERVER_URL: OnceLock<Option<Url>> = OnceLock::new();
+static CONFIG_PATH: OnceLock<Option<PathBuf>> = OnceLock::new();
+
+fn fixture_dir() -> Option<PathBuf> {
+	std::env::var_os(MOCK_DIR_ENV).filter(|dir| !dir.is_empty()).map(PathBuf::from)
+}
+
+/// Config file used in mock mode. Each run starts from a temporary copy of the fixtures' `config.json`, so neither the
+/// real config nor the fixture is ever rewritten.
+pub fn config_path() -> Option<PathBuf> {
+	CONFIG_PATH
+		.get_or_init(|| {
+			let fixture = fixture_dir()?.join("config.json");
+			let path = std::env::temp_dir().join("fedra-mock-config.json");
+			if fixture.exists() {
+				fs::copy(&fixture, &path).ok()?;
+			} else {
+				let _ = fs::remove_file(&path);
+			}
+			Some(path)
+		})
+		.clone()
+}
+
+/// Address of a local server answering each request with the matching JSON file from `FEDRA_MOCK_DIR`, starting it on
+/// first use. `None` when mock mode is off.
+pub fn server_url() -> Option<Url> {
+	SERVER_URL
+		.get_or_init(|| {
+			let dir = fixture_dir()?;
+			let listener = TcpListener::bind("127.0.0.1:0").ok()?;
+			let port = listener.local_addr().ok()?.port();
+			thread::spawn(move || {
+				for stream in listener.incoming().flatten() {
+					let dir = dir.clone();
+					thread::spawn(move || {
+						let _ = serve(stream, &dir);
+					});
+				}
+			});
+			Url::parse(&format!("http://127.0.0.1:{port}/")).ok()
+		})
+		.clone()
+}
+
+fn serve(stream: TcpStream, dir: &Path) -> std::io::Result<()> {
+	let mut reader = BufReader::new(stream);
+	let mut request_line = String::new();
+	reader.read_line(&mut request_line)?;
+	let mut content_length = 0;
+	loop {
+		let mut header = String::new();
+		if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+			break;
+		}
+		if let Some((name, value)) = header.split_once(':')
+			&& name.trim().eq_ignore_ascii_case("content-length")
+		{
+			content_length = value.trim().parse().unwrap_or(0);
+		}
+	}
+	// Drain the request body so the client isn't reset when the connection closes.
+	let mut body = vec![0; content_length];
+	reader.read_exact(&mut body)?;
+	let mut parts = request_line.split_whitespace();
+	let method = parts.next().unwrap_or("GET");
+	let target = parts.next().unwrap_or("/");
+	let (path, query) = target.split_once('?').unwrap_or((target, ""));
+	let (status, body) = respond(dir, method, path, query);
+	let mut stream = reader.into_inner();
+	write!(
+		stream,
+		"HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+		body.len()
+	)?;
+	stream.write_all(&body)?;
+	stream.flush()
+}
+
+/// Picks the answer to a request. `GET` reads the fixture at the request path, except that pages past the first are
+/// empty so Load More stops. Other methods read a fixture under a folder named for the method, such as
+/// `POST/api/v1/statuses.json`, and otherwise succeed with an empty object.
+fn respond(dir: &Path, method: &str, path: &str, query: &str) -> (&'static str, Vec<u8>) {
+	if method.eq_ignore_ascii_case("GET") {
+		if query.split('&').any(|pair| pair.split('=').next() == Some("max_id")) {
+			return ("200 OK", b"[]".to_vec());
+		}
+		return match fixture_path(dir, path).and_then(|file| fs::read(file).ok()) {
+			Some(contents) => ("200 OK", contents),
+			None => ("404 Not Found", format!(r#"{{"error":"No fixture for {path}"}}"#).into_bytes()),
+		};
+	}
+	let fixture = fixture_path(&dir.join(method.to_ascii_uppercase()), path).and_then(|file| fs::read(file).ok());
+	("200 OK", fixture.unwrap_or_else(|| b"{}".to_vec()))
+}
+
+/// Maps `/api/v1/timelines/home` to `<dir>/api/v1/timelines/home.json`, refusing paths that leave `dir`.
+fn fixture_path(dir: &Path, request_path: &str) -> Option<PathBuf> {
+	let relative = Path::new(request_path.trim_matches('/'));
+	if relative.as_os_str().is_empty() || !relative.components().all(|part| matches!(part, Component::Normal(_))) {
+		return None;
+	}
+	Some(dir.join(relative).with_extension("json"))
+}
@@ -21,6 +21,7 @@ use crate::{
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RelationshipAction {
 	Follow,
+	FollowBack,
 	Unfollow,
 	CancelFollowRequest,
 	AcceptFollowRequest,
@@ -75,12 +76,22 @@ pub enum NetworkCommand {
 		timeline_type: TimelineType,
 		status_id: String,
 	},
+	FetchThreadMore {
+		timeline_type: TimelineType,
+		status_id: String,
+	},
 	FetchStatusSource {
 		status: Box<Status>,
 	},
 	ResolveStatusForThread {
 		url: String,
 	},
+	FetchStatus {
+		id: String,
+	},
+	RefreshStatus {
+		status_id: String,
+	},
 	ResolveStatusForQuote {
 		url: String,
 	},
@@ -115,6 +126,15 @@ pub enum NetworkCommand {
 	Unpin {
 		status_id: String,
 	},
+	MuteConversation {
+		status_id: String,
+	},
+	UnmuteConversation {
+		status_id: String,
+	},
+	MarkConversationRead {
+		conversation_id: String,
+	},
 	Boost {
 		status_id: String,
 	},
@@ -150,6 +170,13 @@ pub enum NetworkCommand {
 		acct: String,
 		target_name: String,
 	},
+	FollowBack {
+		account_id: String,
+		target_name: String,
+	},
+	FetchRelationships {
+		account_ids: Vec<String>,
+	},
 	UnfollowAccount {
 		account_id: String,
 		target_name: String,
@@ -182,16 +209,13 @@ pub enum NetworkCommand {
 	FetchRelationship {
 		account_id: String,
 	},
-	FetchRelationshipsForList {
-		account_ids: Vec<String>,
-		for_followers: bool,
-	},
 	FetchAccount {
 		account_id: String,
 	},
 	FetchTagsInfo {
 		names: Vec<String>,
 	},
+	FetchFilters,
 	FetchRebloggedBy {
 		status_id: String,
 	},
@@ -233,6 +257,8 @@ pub enum NetworkCommand {
 		poll: Option<PollData>,
 	},
 	FetchCredentials,
+	/// Reaches the instance once, to confirm new connection settings work.
+	CheckConnection,
 	UpdateProfile {
 		update: ProfileUpdate,
 	},
@@ -264,16 +290,44 @@ pub enum NetworkCommand {
 		list_id: String,
 		account_id: String,
 	},
+	AddAccountToList {
+		list_id: String,
+		list_title: String,
+		account_id: String,
+		acct: String,
+	},
+	AddAccountsToNewList {
+		list_id: String,
+		list_title: String,
+		account_ids: Vec<String>,
+	},
 	RemoveListAccount {
 		list_id: String,
 		account_id: String,
 	},
+	FetchAccountLists {
+		account_id: String,
+		acct: String,
+	},
+	UpdateAccountLists {
+		account_id: String,
+		acct: String,
+		add: Vec<crate::mastodon::List>,
+		remove: Vec<crate::mastodon::List>,
+	},
 	MuteTag {
 		name: String,
 	},
 	UnmuteTag {
 		name: String,
 	},
+	RevokeToken {
+		instance: Url,
+		client_id: String,
+		client_secret: String,
+		token: String,
+		handle: String,
+	},
 	Shutdown,
 }
 
@@ -299,7 +353,11 @@ pub struct MediaUpload {
 #[derive(Debug, Clone)]
 pub enum EditMedia {
 	New(MediaUpload),
-	Existing(String),
+	/// An attachment already on the post, with a replacement description when it was edited.
+	Existing {
+		id: String,
+		description: Option<String>,
+	},
 }
 
 #[derive(Debug, Clone)]
@@ -320,6 +378,11 @@ pub enum NetworkResponse {
 	StatusResolvedForThread {
 		result: Result<Status>,
 	},
+	ThreadRepliesLoaded {
+		timeline_type: TimelineType,
+		status_id: String,
+		result: Result<Vec<Status>>,
+	},
 	StatusResolvedForQuote {
 		result: Result<Status>,
 	},
@@ -331,6 +394,10 @@ pub enum NetworkResponse {
 		handle: String,
 		result: Result<Account>,
 	},
+	AccountLookupAmbiguous {
+		handle: String,
+		accounts: Vec<Account>,
+	},
 	PostComplete(Result<PostSubmission>),
 	Favorited {
 		status_id: String,
@@ -356,6 +423,21 @@ pub enum NetworkResponse {
 		status_id: String,
 		result: Result<Status>,
 	},
+	ConversationMuted {
+		status_id: String,
+		result: Result<Status>,
+	},
+	ConversationUnmuted {
+		status_id: String,
+		result: Result<Status>,
+	},
+	ConversationRead {
+		result: Result<()>,
+	},
+	StatusRefreshed {
+		status_id: String,
+		result: Result<Status>,
+	},
 	Boosted {
 		status_id: String,
 		result: Result<Status>,
@@ -371,6 +453,7 @@ pub enum NetworkResponse {
 	},
 	StatusEdited {
 		_status_id: String,
+		descriptions_updated: bool,
 		result: Result<Status>,
 	},
 	TagFollowed {
@@ -391,9 +474,13 @@ pub enum NetworkResponse {
 		_account_id: String,
 		result: Result<crate::mastodon::Relationship>,
 	},
-	RelationshipsForListLoaded {
-		results: Vec<crate::mastodon::Relationship>,
-		for_followers: bool,
+	RelationshipsLoaded {
+		account_ids: Vec<String>,
+		result: Result<Vec<crate::mastodon::Relationship>>,
+	},
+	FollowBackSkipped {
+		target_name: String,
+		relationship: crate::mastodon::Relationship,
 	},
 	AccountFetched {
 		result: Result<Account>,
@@ -404,6 +491,9 @@ pub enum NetworkResponse {
 	TagsInfoFetched {
 		result: Result<Vec<crate::mastodon::Tag>>,
 	},
+	FiltersLoaded {
+		result: Result<Vec<crate::mastodon::Filter>>,
+	},
 	TagMuted {
 		name: String,
 		result: Result<()>,
@@ -434,6 +524,9 @@ pub enum NetworkResponse {
 	FollowingNextPageLoaded {
 		result: Result<(Vec<Account>, Option<String>)>,
 	},
+	ConnectionChecked {
+		result: Result<()>,
+	},
 	CredentialsFetched {
 		result: Result<Account>,
 	},
@@ -469,6 +562,18 @@ pub enum NetworkResponse {
 		list_id: String,
 		result: Result<()>,
 	},
+	AccountAddedToList {
+		list_title: String,
+		acct: String,
+		already_member: bool,
+		result: Result<()>,
+	},
+	AccountsAddedToNewList {
+		list_id: String,
+		list_title: String,
+		added: usize,
+		failed: usize,
+	},
 	ListAccountRemoved {
 		#[allow(dead_code)]
 		list_id: String,
@@ -476,6 +581,97 @@ pub enum NetworkResponse {
 		account_id: String,
 		result: Result<()>,
 	},
+	AccountListsFetched {
+		account_id: String,
+		acct: String,
+		/// All of the user's lists, then the ones containing the account.
+		result: Result<(Vec<crate::mastodon::List>, Vec<crate::mastodon::List>)>,
+	},
+	AccountListsUpdated {
+		acct: String,
+		added: Vec<String>,
+		removed: Vec<String>,
+		failed: Vec<String>,
+	},
+	TokenRevoked {
+		handle: String,
+		result: Result<()>,
+	},
+	AuthExpired,
+}
+
+impl NetworkResponse {
+	/// The error carried by a failed response. Token revocation is excluded since it targets another account.
+	pub fn error(&self) -> Option<&anyhow::Error> {
+		match self {
+			Self::PostComplete(Err(err))
+			| Self::Replied(Err(err))
+			| Self::TimelineLoaded { result: Err(err), .. }
+			| Self::StatusResolvedForThread { result: Err(err), .. }
+			| Self::ThreadRepliesLoaded { result: Err(err), .. }
+			| Self::StatusResolvedForQuote { result: Err(err), .. }
+			| Self::StatusSourceFetched { result: Err(err), .. }
+			| Self::AccountLookupResult { result: Err(err), .. }
+			| Self::Favorited { result: Err(err), .. }
+			| Self::Bookmarked { result: Err(err), .. }
+			| Self::Unfavorited { result: Err(err), .. }
+			| Self::Unbookmarked { result: Err(err), .. }
+			| Self::Pinned { result: Err(err), .. }
+			| Self::Unpinned { result: Err(err), .. }
+			| Self::ConversationMuted { result: Err(err), .. }
+			| Self::ConversationUnmuted { result: Err(err), .. }
+			| Self::ConversationRead { result: Err(err) }
+			| Self::StatusRefreshed { result: Err(err), .. }
+			| Self::Boosted { result: Err(err), .. }
+			| Self::Unboosted { result: Err(err), .. }
+			| Self::StatusDeleted { result: Err(err), .. }
+			| Self::StatusEdited { result: Err(err), .. }
+			| Self::TagFollowed { result: Err(err), .. }
+			| Self::TagUnfollowed { result: Err(err), .. }
+			| Self::RelationshipUpdated { result: Err(err), .. }
+			| Self::RelationshipLoaded { result: Err(err), .. }
+			| Self::RelationshipsLoaded { result: Err(err), .. }
+			| Self::AccountFetched { result: Err(err), .. }
+			| Self::PollVoted { result: Err(err), .. }
+			| Self::TagsInfoFetched { result: Err(err), .. }
+			| Self::TagMuted { result: Err(err), .. }
+			| Self::TagUnmuted { result: Err(err), .. }
+			| Self::RebloggedByLoaded { result: Err(err), .. }
+			| Self::FavoritedByLoaded { result: Err(err), .. }
+			| Self::FollowersLoaded { result: Err(err), .. }
+			| Self::FollowingLoaded { result: Err(err), .. }
+			| Self::FollowersNextPageLoaded { result: Err(err), .. }
+			| Self::FollowingNextPageLoaded { result: Err(err), .. }
+			| Self::CredentialsFetched { result: Err(err), .. }
+			| Self::ConnectionChecked { result: Err(err) }
+			| Self::ProfileUpdated { result: Err(err), .. }
+			| Self::SearchLoaded { result: Err(err), .. }
+			| Self::ListsFetched { result: Err(err), .. }
+			| Self::ListCreated { result: Err(err), .. }
+			| Self::ListUpdated { result: Err(err), .. }
+			| Self::ListDeleted { result: Err(err), .. }
+			| Self::ListAccountsFetched { result: Err(err), .. }
+			| Self::ListAccountAdded { result: Err(err), .. }
+			| Self::AccountAddedToList { result: Err(err), .. }
+			| Self::ListAccountRemoved { result: Err(err), .. }
+			| Self::AccountListsFetched { result: Err(err), .. } => Some(err),
+			_ => None,
+		}
+	}
+
+	/// Whether this answers a request Fedra made on its own, such as a timeline poll or a follow state lookup, rather
+	/// than one the user made.
+	pub const fn is_background(&self) -> bool {
+		matches!(
+			self,
+			Self::TimelineLoaded { .. }
+				| Self::RelationshipsLoaded { .. }
+				| Self::ConversationRead { .. }
+				| Self::FiltersLoaded { .. }
+				| Self::AuthExpired
+				| Self::TokenRevoked { .. }
+		)
+	}
 }
 
 #[derive(Debug)]
@@ -542,6 +738,7 @@ fn edit_with_media(
 	poll: Option<&PollData>,
 ) -> Result<Status> {
 	let mut media_ids = Vec::new();
+	let mut media_descriptions = Vec::new();
 	let mut upload_failed = None;
 	for item in media {
 		match item {
@@ -554,13 +751,28 @@ fn edit_with_media(
 					}
 				}
 			}
-			EditMedia::Existing(id) => media_ids.push(id),
+			EditMedia::Existing { id, description } => {
+				if let Some(description) = description {
+					media_descriptions.push((id.clone(), description));
+				}
+				media_ids.push(id);
+			}
 		}
 	}
 	if let Some(err) = upload_failed {
 		return Err(err);
 	}
-	client.edit_status(access_token, status_id, content, sensitive, spoiler_text, language, &media_ids, poll)
+	client.edit_status(
+		access_token,
+		status_id,
+		content,
+		sensitive,
+		spoiler_text,
+		language,
+		&media_ids,
+		&media_descriptions,
+		poll,
+	)
 }
 
 pub struct NetworkHandle {
@@ -608,15 +820,45 @@ pub fn start_network(base_url: Url, access_token: String, ui_waker: UiWaker) ->
 }
 
 fn send_response(responses: &Sender<NetworkResponse>, ui_waker: &UiWaker, response: NetworkResponse) {
+	let unauthorized = response.error().is_some_and(is_unauthorized);
 	let _ = responses.send(response);
+	if unauthorized {
+		let _ = responses.send(NetworkResponse::AuthExpired);
+	}
 	ui_waker.wake();
 }
 
+fn has_status(err: &anyhow::Error, statuses: &[reqwest::StatusCode]) -> bool {
+	err.chain().any(|cause| {
+		cause
+			.downcast_ref::<reqwest::Error>()
+			.and_then(reqwest::Error::status)
+			.or_else(|| {
+				cause.downcast_ref::<crate::mastodon::HttpStatusError>().map(|status_error| status_error.status)
+			})
+			.is_some_and(|status| statuses.contains(&status))
+	})
+}
+
+fn is_unauthorized(err: &anyhow::Error) -> bool {
+	has_status(err, &[reqwest::StatusCode::UNAUTHORIZED])
+}
+
+/// Whether the server says the requested thing doesn't exist or can't be seen anymore.
+pub fn is_gone(err: &anyhow::Error) -> bool {
+	has_status(err, &[reqwest::StatusCode::NOT_FOUND, reqwest::StatusCode::GONE, reqwest::StatusCode::FORBIDDEN])
+}
+
 fn prepare_thread_timeline(focus: Status, context: StatusContext) -> TimelineData {
 	let mut statuses = context.ancestors;
 	statuses.push(focus);
 	statuses.extend(context.descendants);
+	TimelineData::Statuses(order_thread(statuses), None)
+}
 
+/// Orders a thread's posts depth first, each reply after its parent and siblings oldest first, then reverses the
+/// result to match how timeline entries are stored.
+pub fn order_thread(statuses: Vec<Status>) -> Vec<Status> {
 	let mut status_map: HashMap<String, Status> = HashMap::new();
 	let mut children_map: HashMap<String, Vec<String>> = HashMap::new();
 	for status in statuses {
@@ -666,7 +908,7 @@ fn prepare_thread_timeline(focus: Status, context: StatusContext) -> TimelineDat
 		}
 	}
 	sorted_statuses.reverse();
-	TimelineData::Statuses(sorted_statuses, None)
+	sorted_statuses
 }
 
 fn first_relationship_result(relationships: Vec<Relationship>) -> Result<Relationship> {
@@ -762,8 +1004,23 @@ fn network_loop(
 					NetworkResponse::TimelineLoaded { timeline_type, result, max_id: None },
 				);
 			}
-			Ok(NetworkCommand::FetchStatusSource { status }) => {
+			Ok(NetworkCommand::FetchThreadMore { timeline_type, status_id }) => {
+				let result = client.get_status_context(access_token, &status_id).map(|context| context.descendants);
+				send_response(
+					responses,
+					ui_waker,
+					NetworkResponse::ThreadRepliesLoaded { timeline_type, status_id, result },
+				);
+			}
+			Ok(NetworkCommand::FetchStatusSource { mut status }) => {
 				let result = client.fetch_status_source(access_token, &status.id);
+				// The status doesn't carry the poll's remaining time reliably, so refresh it; on failure the
+				// cached poll is kept.
+				if let Some(poll_id) = status.poll.as_ref().map(|poll| poll.id.clone())
+					&& let Ok(poll) = client.get_poll(access_token, &poll_id)
+				{
+					status.poll = Some(poll);
+				}
 				send_response(responses, ui_waker, NetworkResponse::StatusSourceFetched { status, result });
 			}
 			Ok(NetworkCommand::ResolveStatusForThread { url }) => {
@@ -772,6 +1029,14 @@ fn network_loop(
 					.and_then(|mut res| res.statuses.pop().context("Status not found on local instance"));
 				send_response(responses, ui_waker, NetworkResponse::StatusResolvedForThread { result });
 			}
+			Ok(NetworkCommand::FetchStatus { id }) => {
+				let result = client.get_status(access_token, &id);
+				send_response(responses, ui_waker, NetworkResponse::StatusResolvedForThread { result });
+			}
+			Ok(NetworkCommand::RefreshStatus { status_id }) => {
+				let result = client.get_status(access_token, &status_id);
+				send_response(responses, ui_waker, NetworkResponse::StatusRefreshed { status_id, result });
+			}
 			Ok(NetworkCommand::ResolveStatusForQuote { url }) => {
 				let result = client
 					.search(access_token, &url, SearchType::Statuses, Some(1), None)
@@ -882,8 +1147,29 @@ fn network_loop(
 				send_response(responses, ui_waker, NetworkResponse::AccountLookupResult { handle: url, result });
 			}
 			Ok(NetworkCommand::LookupAccount { handle }) => {
-				let result = client.lookup_account(access_token, &handle);
-				send_response(responses, ui_waker, NetworkResponse::AccountLookupResult { handle, result });
+				// A bare username may exist on several instances, and the lookup endpoint would only ever return the
+				// local one, so search for it first.
+				let username = handle.trim_start_matches('@');
+				let mut matches: Vec<Account> = if username.contains('@') {
+					Vec::new()
+				} else {
+					client
+						.search(access_token, username, SearchType::Accounts, Some(40), None)
+						.map(|results| {
+							results.accounts.into_iter().filter(|a| a.username.eq_ignore_ascii_case(username)).collect()
+						})
+						.unwrap_or_default()
+				};
+				if matches.len() > 1 {
+					send_response(
+						responses,
+						ui_waker,
+						NetworkResponse::AccountLookupAmbiguous { handle, accounts: matches },
+					);
+				} else {
+					let result = matches.pop().map_or_else(|| client.lookup_account(access_token, &handle), Ok);
+					send_response(responses, ui_waker, NetworkResponse::AccountLookupResult { handle, result });
+				}
 			}
 			Ok(NetworkCommand::PostStatus { post }) => {
 				let result = post_with_media(
@@ -904,6 +1190,8 @@ fn network_loop(
 				send_response(responses, ui_waker, NetworkResponse::PostComplete(result));
 			}
 			Ok(NetworkCommand::EditStatus { status_id, content, sensitive, spoiler_text, language, media, poll }) => {
+				let descriptions_updated =
+					media.iter().any(|item| matches!(item, EditMedia::Existing { description: Some(_), .. }));
 				let result = edit_with_media(
 					client,
 					access_token,
@@ -915,7 +1203,11 @@ fn network_loop(
 					media,
 					poll.as_ref(),
 				);
-				send_response(responses, ui_waker, NetworkResponse::StatusEdited { _status_id: status_id, result });
+				send_response(
+					responses,
+					ui_waker,
+					NetworkResponse::StatusEdited { _status_id: status_id, descriptions_updated, result },
+				);
 			}
 			Ok(NetworkCommand::DeleteStatus { status_id }) => {
 				let result = client.delete_status(access_token, &status_id);
@@ -945,6 +1237,18 @@ fn network_loop(
 				let result = client.unpin_status(access_token, &status_id);
 				send_response(responses, ui_waker, NetworkResponse::Unpinned { status_id, result });
 			}
+			Ok(NetworkCommand::MuteConversation { status_id }) => {
+				let result = client.mute_conversation(access_token, &status_id);
+				send_response(responses, ui_waker, NetworkResponse::ConversationMuted { status_id, result });
+			}
+			Ok(NetworkCommand::UnmuteConversation { status_id }) => {
+				let result = client.unmute_conversation(access_token, &status_id);
+				send_response(responses, ui_waker, NetworkResponse::ConversationUnmuted { status_id, result });
+			}
+			Ok(NetworkCommand::MarkConversationRead { conversation_id }) => {
+				let result = client.mark_conversation_read(access_token, &conversation_id);
+				send_response(responses, ui_waker, NetworkResponse::ConversationRead { result });
+			}
 			Ok(NetworkCommand::Boost { status_id }) => {
 				let result = client.reblog(access_token, &status_id);
 				send_response(responses, ui_waker, NetworkResponse::Boosted { status_id, result });
@@ -1012,6 +1316,10 @@ fn network_loop(
 				let result = Ok(tags);
 				send_response(responses, ui_waker, NetworkResponse::TagsInfoFetched { result });
 			}
+			Ok(NetworkCommand::FetchFilters) => {
+				let result = client.get_filters(access_token);
+				send_response(responses, ui_waker, NetworkResponse::FiltersLoaded { result });
+			}
 			Ok(NetworkCommand::MuteTag { name }) => {
 				let result = (|| -> Result<()> {
 					let filters = client.get_filters(access_token)?;
@@ -1137,6 +1445,33 @@ fn network_loop(
 					}
 				}
 			}
+			Ok(NetworkCommand::FollowBack { account_id, target_name }) => {
+				let existing = client
+					.get_relationships(access_token, slice::from_ref(&account_id))
+					.ok()
+					.and_then(|mut rels| rels.pop());
+				if let Some(rel) = existing
+					&& (rel.following || rel.requested)
+				{
+					send_response(
+						responses,
+						ui_waker,
+						NetworkResponse::FollowBackSkipped { target_name, relationship: rel },
+					);
+				} else {
+					let result = client.follow_account_with_options(access_token, &account_id, true);
+					send_response(
+						responses,
+						ui_waker,
+						NetworkResponse::RelationshipUpdated {
+							_account_id: account_id,
+							target_name,
+							action: RelationshipAction::FollowBack,
+							result,
+						},
+					);
+				}
+			}
 			Ok(NetworkCommand::UnfollowAccount { account_id, target_name, action }) => {
 				let result = client.unfollow_account(access_token, &account_id);
 				send_response(
@@ -1233,13 +1568,9 @@ fn network_loop(
 					NetworkResponse::RelationshipLoaded { _account_id: account_id, result },
 				);
 			}
-			Ok(NetworkCommand::FetchRelationshipsForList { account_ids, for_followers }) => {
-				let results = client.get_relationships(access_token, &account_ids).unwrap_or_default();
-				send_response(
-					responses,
-					ui_waker,
-					NetworkResponse::RelationshipsForListLoaded { results, for_followers },
-				);
+			Ok(NetworkCommand::FetchRelationships { account_ids }) => {
+				let result = client.get_relationships(access_token, &account_ids);
+				send_response(responses, ui_waker, NetworkResponse::RelationshipsLoaded { account_ids, result });
 			}
 			Ok(NetworkCommand::FetchAccount { account_id }) => {
 				let result = client.get_account(access_token, &account_id);
@@ -1253,6 +1584,10 @@ fn network_loop(
 				let result = client.verify_credentials(access_token);
 				send_response(responses, ui_waker, NetworkResponse::CredentialsFetched { result });
 			}
+			Ok(NetworkCommand::CheckConnection) => {
+				let result = client.get_instance_info().map(|_| ());
+				send_response(responses, ui_waker, NetworkResponse::ConnectionChecked { result });
+			}
 			Ok(NetworkCommand::UpdateProfile { update }) => {
 				let result = client.update_credentials(
 					access_token,
@@ -1267,6 +1602,7 @@ fn network_loop(
 					update.source.as_ref().and_then(|s| s.privacy.as_deref()),
 					update.source.as_ref().and_then(|s| s.sensitive),
 					update.source.as_ref().and_then(|s| s.language.as_deref()),
+					update.source.as_ref().and_then(|s| s.quote_policy.as_deref()),
 				);
 				send_response(responses, ui_waker, NetworkResponse::ProfileUpdated { result });
 			}
@@ -1302,10 +1638,73 @@ fn network_loop(
 				let result = client.add_list_accounts(access_token, &list_id, slice::from_ref(&account_id));
 				send_response(responses, ui_waker, NetworkResponse::ListAccountAdded { list_id, result });
 			}
+			Ok(NetworkCommand::AddAccountToList { list_id, list_title, account_id, acct }) => {
+				let already_member = client
+					.get_account_lists(access_token, &account_id)
+					.is_ok_and(|lists| lists.iter().any(|list| list.id == list_id));
+				let result = if already_member {
+					Ok(())
+				} else {
+					client.add_list_accounts(access_token, &list_id, slice::from_ref(&account_id))
+				};
+				send_response(
+					responses,
+					ui_waker,
+					NetworkResponse::AccountAddedToList { list_title, acct, already_member, result },
+				);
+			}
+			Ok(NetworkCommand::AddAccountsToNewList { list_id, list_title, account_ids }) => {
+				// One request per account, since the instance rejects the whole batch if any account can't be added.
+				let added = account_ids
+					.iter()
+					.filter(|id| client.add_list_accounts(access_token, &list_id, slice::from_ref(*id)).is_ok())
+					.count();
+				let failed = account_ids.len() - added;
+				send_response(
+					responses,
+					ui_waker,
+					NetworkResponse::AccountsAddedToNewList { list_id, list_title, added, failed },
+				);
+			}
 			Ok(NetworkCommand::RemoveListAccount { list_id, account_id }) => {
 				let result = client.remove_list_accounts(access_token, &list_id, slice::from_ref(&account_id));
 				send_response(responses, ui_waker, NetworkResponse::ListAccountRemoved { list_id, account_id, result });
 			}
+			Ok(NetworkCommand::FetchAccountLists { account_id, acct }) => {
+				let result = client.get_lists(access_token).and_then(|lists| {
+					client.get_account_lists(access_token, &account_id).map(|containing| (lists, containing))
+				});
+				send_response(responses, ui_waker, NetworkResponse::AccountListsFetched { account_id, acct, result });
+			}
+			Ok(NetworkCommand::UpdateAccountLists { account_id, acct, add, remove }) => {
+				let ids = slice::from_ref(&account_id);
+				let mut added = Vec::new();
+				let mut removed = Vec::new();
+				let mut failed = Vec::new();
+				for list in add {
+					match client.add_list_accounts(access_token, &list.id, ids) {
+						Ok(()) => added.push(list.title),
+						Err(_) => failed.push(list.title),
+					}
+				}
+				for list in remove {
+					match client.remove_list_accounts(access_token, &list.id, ids) {
+						Ok(()) => removed.push(list.title),
+						Err(_) => failed.push(list.title),
+					}
+				}
+				send_response(
+					responses,
+					ui_waker,
+					NetworkResponse::AccountListsUpdated { acct, added, removed, failed },
+				);
+			}
+			Ok(NetworkCommand::RevokeToken { instance, client_id, client_secret, token, handle }) => {
+				// The account being removed may live on a different instance than the active one.
+				let result = MastodonClient::new(instance)
+					.and_then(|other| other.revoke_token(&client_id, &client_secret, &token));
+				send_response(responses, ui_waker, NetworkResponse::TokenRevoked { handle, result });
+			}
 			Ok(NetworkCommand::Shutdown) | Err(_) => {
 				break;
 			}
@@ -1,11 +1,17 @@
-use std::cell::Cell;
+use std::{
+	cell::Cell,
+	collections::HashMap,
+	slice,
+	time::{Duration, Instant},
+};
 
 use wxdragon::prelude::*;
 
 use crate::{
 	AppState, UiCommand,
+	accounts::start_reauth_flow,
 	config::{AutoloadMode, ConfigStore, SortOrder},
-	mastodon::{Poll, Status},
+	mastodon::{Account, Poll, Relationship, Status, next_filter_expiry},
 	network::{NetworkCommand, NetworkResponse, TimelineData},
 	streaming,
 	timeline::{TimelineEntry, TimelineType},
@@ -17,6 +23,9 @@ use crate::{
 	ui_wake::UiCommandSender,
 };
 
+/// How long a stream must stay down before its outage is announced, so brief reconnects stay quiet.
+const STREAM_STATUS_DEBOUNCE: Duration = Duration::from_secs(10);
+
 fn summarize_api_error(err: &anyhow::Error) -> String {
 	for cause in err.chain().skip(1) {
 		let mut message = cause.to_string();
@@ -47,11 +56,58 @@ fn refresh_own_user_timelines(state: &AppState) {
 		if let crate::timeline::TimelineType::User { ref id, .. } = tt
 			&& id == current_user_id
 		{
-			handle.send(NetworkCommand::FetchTimeline { timeline_type: tt, limit: Some(40), max_id: None });
+			handle.send(NetworkCommand::FetchTimeline {
+				timeline_type: tt,
+				limit: state.config.page_limit(),
+				max_id: None,
+			});
 		}
 	}
 }
 
+/// Caches relationships for follow markers, redrawing the active timeline when markers are shown.
+fn remember_relationships(
+	state: &mut AppState,
+	relationships: &[Relationship],
+	timeline_list: &crate::ui::timeline_list::TimelineList,
+	suppress_selection: &Cell<bool>,
+) {
+	for rel in relationships {
+		state.pending_relationships.remove(&rel.id);
+		state.relationships.insert(rel.id.clone(), rel.clone());
+	}
+	if !state.config.show_follow_markers || relationships.is_empty() {
+		return;
+	}
+	let view_options = state.timeline_manager.active().map(|a| state.timeline_view_options_for(&a.timeline_type));
+	let active_index = state.timeline_manager.active_index();
+	if let Some(view_options) = view_options
+		&& let Some(active) = state.timeline_manager.active_mut()
+	{
+		update_active_timeline_ui(
+			timeline_list,
+			active,
+			suppress_selection,
+			&view_options,
+			&state.cw_expanded,
+			active_index,
+		);
+	}
+}
+
+/// Shows cached relationships in a follow list dialog right away and batch-fetches the rest.
+fn load_list_relationships(state: &mut AppState, accounts: &[Account], for_followers: bool) {
+	let cached: Vec<Relationship> =
+		accounts.iter().filter_map(|account| state.relationships.get(&account.id).cloned()).collect();
+	let dialog = if for_followers { &state.followers_dialog } else { &state.following_dialog };
+	if let Some(dlg) = dialog
+		&& !cached.is_empty()
+	{
+		dlg.update_relationships(&cached);
+	}
+	crate::commands::request_relationships(state, accounts.iter().map(|account| account.id.clone()).collect());
+}
+
 fn merge_status_snapshot_by_id(state: &mut AppState, status_id: &str, snapshot: &Status) -> bool {
 	let mut updated = false;
 	for timeline in state.timeline_manager.iter_mut() {
@@ -81,9 +137,56 @@ fn merge_status_snapshot(state: &mut AppState, snapshot: &Status) -> bool {
 	updated
 }
 
+/// Shows the profile of an account found by user lookup, or returns the timeline to open for it.
+fn open_looked_up_account(
+	state: &mut AppState,
+	frame: &Frame,
+	ui_tx: &UiCommandSender,
+	live_region: &crate::ui::timeline_list::TimelineList,
+	account: Account,
+	action: UserLookupAction,
+) -> Option<TimelineType> {
+	match action {
+		UserLookupAction::Profile => {
+			if let Some(net) = &state.network_handle {
+				net.send(NetworkCommand::FetchRelationship { account_id: account.id.clone() });
+				let net_tx = net.command_tx.clone();
+				let ui_tx_timeline = ui_tx.clone();
+				let timeline_type =
+					TimelineType::User { id: account.id.clone(), name: account.display_name_or_username().to_string() };
+				let ui_tx_close = ui_tx.clone();
+
+				let dlg = dialogs::ProfileDialog::new(
+					frame,
+					account,
+					state.current_user_id.as_deref(),
+					&state.config,
+					net_tx,
+					ui_tx.clone(),
+					move || {
+						let _ = ui_tx_timeline.send(UiCommand::OpenTimeline(timeline_type.clone()));
+					},
+					move || {
+						let _ = ui_tx_close.send(UiCommand::ProfileDialogClosed);
+					},
+				);
+				dlg.show();
+				state.profile_dialog = Some(dlg);
+			} else {
+				live_region.announce("Network not available");
+			}
+			None
+		}
+		UserLookupAction::Timeline => {
+			Some(TimelineType::User { id: account.id, name: account.display_name_or_username().to_string() })
+		}
+	}
+}
+
 /// Processes streaming events from WebSocket connections.
 pub fn process_stream_events(
 	state: &mut AppState,
+	timelines_selector: ListBox,
 	timeline_list: &crate::ui::timeline_list::TimelineList,
 	suppress_selection: &Cell<bool>,
 	frame: &Frame,
@@ -93,6 +196,10 @@ pub fn process_stream_events(
 	let mut processed_notification_ids = std::collections::HashSet::new();
 	let mut status_snapshots: Vec<Status> = Vec::new();
 	let mut mention_forwards: Vec<Box<crate::mastodon::Notification>> = Vec::new();
+	let mut stream_status_messages: Vec<String> = Vec::new();
+	let mention_sound_account = state.config.mention_sound.then(|| state.config.active_account().cloned()).flatten();
+	let mut mention_arrived = false;
+	let mut direct_message_senders: Vec<String> = Vec::new();
 
 	for timeline in state.timeline_manager.iter_mut() {
 		let Some(handle) = &timeline.stream_handle else { continue };
@@ -111,15 +218,21 @@ pub fn process_stream_events(
 			let effective_sort_order = timeline.effective_sort_order(&state.config);
 			sync_timeline_selection_from_list(timeline, timeline_list, effective_sort_order);
 		}
+		let newest_before = timeline.entries.first().map(|entry| entry.id().to_string());
 		for event in events {
 			match event {
 				streaming::StreamEvent::Update { timeline_type, status } => {
 					status_snapshots.push((*status).clone());
 					if timeline.timeline_type == timeline_type
-						&& !status.should_hide(&filter_context)
+						&& !status.should_hide(&filter_context, state.config.hide_warned_posts)
 						&& status.matches_filter(&timeline_filter, current_user_id)
+						&& !status.is_hidden_boost(&state.config)
 					{
-						timeline.entries.insert(0, TimelineEntry::Status(Box::new(*status)));
+						let entry = TimelineEntry::Status(Box::new(*status));
+						if mention_sound_account.as_ref().is_some_and(|account| entry.mentions(account)) {
+							mention_arrived = true;
+						}
+						timeline.entries.insert(0, entry);
 						if is_active {
 							active_needs_update = true;
 						}
@@ -159,7 +272,10 @@ pub fn process_stream_events(
 							}
 							processed_notification_ids.insert(notification.id.clone());
 						}
-						if notification.status.as_ref().is_none_or(|s| !s.should_hide(&filter_context))
+						if notification
+							.status
+							.as_ref()
+							.is_none_or(|s| !s.should_hide(&filter_context, state.config.hide_warned_posts))
 							&& notification.matches_filter(&timeline_filter, current_user_id)
 						{
 							if notification.kind == "mention" {
@@ -175,9 +291,16 @@ pub fn process_stream_events(
 				streaming::StreamEvent::Conversation { timeline_type, conversation } => {
 					if timeline.timeline_type == timeline_type
 						&& let Some(mut status) = conversation.last_status
-						&& !status.should_hide(&filter_context)
+						&& !status.should_hide(&filter_context, state.config.hide_warned_posts)
 						&& status.matches_filter(&timeline_filter, current_user_id)
 					{
+						if conversation.unread
+							&& Some(status.account.id.as_str()) != current_user_id
+							&& !timeline.unread_conversations.contains(&conversation.id)
+						{
+							timeline.unread_conversations.push(conversation.id.clone());
+							direct_message_senders.push(status.account.display_name_or_username().to_string());
+						}
 						status.conversation_id = Some(conversation.id);
 						status_snapshots.push(status.clone());
 						if let Some(conv_id) = &status.conversation_id {
@@ -195,12 +318,41 @@ pub fn process_stream_events(
 						}
 					}
 				}
-				streaming::StreamEvent::Connected(timeline_type)
-				| streaming::StreamEvent::Disconnected(timeline_type) => {
-					let _ = timeline_type;
+				streaming::StreamEvent::Connected(_) => {
+					timeline.stream_down_since = None;
+					if std::mem::take(&mut timeline.stream_outage_announced) && state.config.announce_stream_status {
+						stream_status_messages
+							.push(format!("Live updates reconnected for {}", timeline.timeline_type.display_name()));
+					}
+				}
+				streaming::StreamEvent::Disconnected(_) => {
+					let down_since = *timeline.stream_down_since.get_or_insert_with(Instant::now);
+					if !timeline.stream_outage_announced && down_since.elapsed() >= STREAM_STATUS_DEBOUNCE {
+						timeline.stream_outage_announced = true;
+						if state.config.announce_stream_status {
+							stream_status_messages
+								.push(format!("Live updates lost for {}", timeline.timeline_type.display_name()));
+						}
+					}
 				}
 			}
 		}
+		if timeline.follow_live && timeline.entries.first().map(TimelineEntry::id) != newest_before.as_deref() {
+			timeline.select_newest();
+		}
+	}
+	for message in &stream_status_messages {
+		timeline_list.announce(message);
+	}
+	if !direct_message_senders.is_empty() {
+		crate::commands::refresh_timeline_names(state, timelines_selector, suppress_selection, frame);
+		for sender in &direct_message_senders {
+			timeline_list.announce(&format!("New direct message from {sender}"));
+		}
+	}
+	if mention_arrived && let Some(mc) = &state.media_ctrl {
+		mc.stop();
+		mc.play();
 	}
 	if !mention_forwards.is_empty() {
 		if let Some(mentions_tl) = state.timeline_manager.get_mut(&TimelineType::Mentions) {
@@ -243,9 +395,34 @@ pub fn process_stream_events(
 		if let Some(mb) = frame.get_menu_bar() {
 			update_menu_labels(&mb, state);
 		}
+		crate::commands::request_missing_relationships(state);
 	}
 }
 
+/// Counts the posts in `entries` that reply to `focus_id`, directly or further down the chain.
+fn thread_reply_count(entries: &[TimelineEntry], focus_id: &str) -> usize {
+	let parents: HashMap<&str, &str> = entries
+		.iter()
+		.filter_map(TimelineEntry::as_status)
+		.filter_map(|s| s.in_reply_to_id.as_deref().map(|parent| (s.id.as_str(), parent)))
+		.collect();
+	entries
+		.iter()
+		.filter(|entry| {
+			let mut current = entry.id();
+			// Bounded by the entry count so a malformed reply chain can't loop forever.
+			for _ in 0..entries.len() {
+				match parents.get(current) {
+					Some(&parent) if parent == focus_id => return true,
+					Some(&parent) => current = parent,
+					None => return false,
+				}
+			}
+			false
+		})
+		.count()
+}
+
 /// Processes network responses from the background network thread.
 pub struct NetworkResponseContext<'a> {
 	pub frame: &'a Frame,
@@ -296,10 +473,17 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 		}};
 	}
 	for response in handle.drain() {
+		if let Some(err) = response.error() {
+			state.last_error = Some(format!("{err:?}"));
+		} else if !response.is_background() {
+			// Only something the user did counts as things working again; a quiet poll shouldn't hide the error.
+			state.last_error = None;
+		}
 		match response {
 			NetworkResponse::TimelineLoaded { timeline_type, result: Ok(data), max_id } => {
 				let mut should_find_next = false;
 				let mut should_find_prev = false;
+				let mut unread_changed = false;
 				let is_active = active_type.as_ref() == Some(&timeline_type);
 				let mut status_snapshots: Vec<Status> = Vec::new();
 				let view_options = state.timeline_view_options_for(&timeline_type);
@@ -326,24 +510,38 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 						state.config.accounts.iter().find(|a| a.id == id).and_then(|a| a.user_id.clone())
 					});
 					let current_user_id = current_user_id_string.as_deref();
+					let bookmark_tag = timeline.bookmark_tag_filter.clone();
+					let thread_focus_id = match &timeline_type {
+						TimelineType::Thread { id, .. } => Some(id.as_str()),
+						_ => None,
+					};
+					let first_load = !timeline.loaded;
+					let mut unread_conversations = Vec::new();
+					let mut page_conversations = Vec::new();
+					let mut filtered_out = Vec::new();
 
 					let (new_entries, next_max_id): (Vec<TimelineEntry>, Option<String>) = match data {
-						TimelineData::Statuses(statuses, next) => (
-							statuses
-								.into_iter()
-								.filter(|s| {
-									!s.should_hide(&filter_context)
-										&& s.matches_filter(&timeline_filter, current_user_id)
-								})
-								.map(|s| TimelineEntry::Status(Box::new(s)))
-								.collect(),
-							next,
-						),
+						TimelineData::Statuses(statuses, next) => {
+							let (shown, hidden): (Vec<Status>, Vec<Status>) =
+								statuses.into_iter().filter(|s| !s.is_hidden_boost(&state.config)).partition(|s| {
+									// The post a thread was opened from is always shown, even if a filter matches it.
+									thread_focus_id == Some(s.id.as_str())
+										|| (!s.should_hide(&filter_context, state.config.hide_warned_posts)
+											&& s.matches_filter(&timeline_filter, current_user_id)
+											&& bookmark_tag
+												.as_deref()
+												.is_none_or(|tag| state.config.bookmark_has_tag(&s.id, tag)))
+								});
+							filtered_out = hidden;
+							(shown.into_iter().map(|s| TimelineEntry::Status(Box::new(s))).collect(), next)
+						}
 						TimelineData::Notifications(notifications, next) => (
 							notifications
 								.into_iter()
 								.filter(|n| {
-									n.status.as_ref().is_none_or(|s| !s.should_hide(&filter_context))
+									n.status
+										.as_ref()
+										.is_none_or(|s| !s.should_hide(&filter_context, state.config.hide_warned_posts))
 										&& n.matches_filter(&timeline_filter, current_user_id)
 								})
 								.map(|n| TimelineEntry::Notification(Box::new(n)))
@@ -354,12 +552,16 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 							conversations
 								.into_iter()
 								.filter_map(|c| {
+									page_conversations.push(c.id.clone());
 									c.last_status
 										.filter(|s| {
-											!s.should_hide(&filter_context)
+											!s.should_hide(&filter_context, state.config.hide_warned_posts)
 												&& s.matches_filter(&timeline_filter, current_user_id)
 										})
 										.map(|mut s| {
+											if c.unread && Some(s.account.id.as_str()) != current_user_id {
+												unread_conversations.push(c.id.clone());
+											}
 											s.conversation_id = Some(c.id);
 											TimelineEntry::Status(Box::new(s))
 										})
@@ -380,6 +582,10 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 						let filtered: Vec<TimelineEntry> =
 							new_entries.into_iter().filter(|entry| !existing_ids.contains(entry.id())).collect();
 						if filtered.is_empty() {
+							// A page of nothing but duplicates or filtered posts isn't the end if the cursor moved on.
+							if next_max_id.is_none() || next_max_id == max_id {
+								timeline.end_reached = true;
+							}
 							live_region.announce("No more posts");
 						} else {
 							timeline.entries.extend(filtered.clone());
@@ -399,12 +605,42 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 						}
 					} else {
 						timeline.entries = new_entries;
+						if thread_focus_id.is_some() {
+							// A full reload truncates branches again, so every post may have replies to load.
+							timeline.fetched_replies.clear();
+							timeline.filtered_replies.clear();
+							timeline.record_filtered_replies(&filtered_out);
+							timeline.entries = crate::timeline::with_more_replies_rows(
+								std::mem::take(&mut timeline.entries),
+								&[],
+								&timeline.filtered_reply_counts(),
+							);
+						}
+						timeline.end_reached = false;
+						timeline.loaded = true;
+						if is_active && timeline.entries.is_empty() {
+							live_region.announce("This timeline is empty");
+						}
 						// Restore selected post if it exists in the freshly loaded entries.
 						if let Some(ref id) = restore_id {
 							if timeline.entries.iter().any(|e| e.id() == id.as_str()) {
 								timeline.selected_id = Some(id.clone());
 							}
 						}
+						if let Some(focus_id) = thread_focus_id {
+							if !timeline
+								.selected_id
+								.as_deref()
+								.is_some_and(|id| timeline.entries.iter().any(|e| e.id() == id))
+							{
+								timeline.selected_id = Some(focus_id.to_string());
+							}
+							if is_active && first_load {
+								let replies = thread_reply_count(&timeline.entries, focus_id);
+								let noun = if replies == 1 { "reply" } else { "replies" };
+								live_region.announce(&format!("Showing thread, {replies} {noun}"));
+							}
+						}
 						if is_active {
 							if let Some(idx) = timeline_index_opt {
 								update_active_timeline_ui(
@@ -418,6 +654,15 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 							}
 						}
 					}
+					// A page only says which of its own conversations are unread, so ones further down keep theirs.
+					let previous_unread = timeline.unread_conversations.clone();
+					timeline.unread_conversations.retain(|id| !page_conversations.contains(id));
+					for id in unread_conversations {
+						if !timeline.unread_conversations.contains(&id) {
+							timeline.unread_conversations.push(id);
+						}
+					}
+					unread_changed = timeline.unread_conversations != previous_unread;
 					timeline.next_max_id = next_max_id;
 					timeline.loading_more = false;
 					timeline.loading_more_in_background = false;
@@ -434,6 +679,9 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				if restore_id.is_some() {
 					state.pending_restore_post_id = None;
 				}
+				if unread_changed {
+					crate::commands::refresh_timeline_names(state, timelines_selector, suppress_selection, frame);
+				}
 				if !status_snapshots.is_empty() {
 					let mut merged_any = false;
 					for snapshot in &status_snapshots {
@@ -459,6 +707,9 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 						}
 					}
 				}
+				if is_active {
+					crate::commands::request_missing_relationships(state);
+				}
 				if should_find_next {
 					dispatch_ui_command!(crate::commands::UiCommand::FindNext);
 				}
@@ -467,10 +718,23 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				}
 			}
 			NetworkResponse::TimelineLoaded { timeline_type, result: Err(ref err), max_id } => {
+				let mut gone = false;
 				if let Some(timeline) = state.timeline_manager.get_mut(&timeline_type) {
 					timeline.loading_more = false;
-				}
-				if max_id.is_some() {
+					gone = timeline.restored && !timeline.loaded && crate::network::is_gone(err);
+				}
+				if gone && state.timeline_manager.len() > 1 {
+					let name = timeline_type.display_name_with_lists(&state.lists);
+					state.timeline_manager.close(&timeline_type, false);
+					crate::commands::refresh_timeline_selector(
+						state,
+						timelines_selector,
+						timeline_list,
+						suppress_selection,
+						frame,
+					);
+					live_region.announce(&format!("Closed {name}, which no longer exists"));
+				} else if max_id.is_some() {
 					live_region.announce("Failed to load more posts");
 				} else {
 					live_region.announce(&spoken_failure("Failed to load timeline", err));
@@ -482,6 +746,76 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 			NetworkResponse::StatusResolvedForThread { result: Err(err) } => {
 				live_region.announce(&format!("Failed to resolve thread: {}", summarize_api_error(&err)));
 			}
+			NetworkResponse::ThreadRepliesLoaded { timeline_type, status_id, result: Ok(replies) } => {
+				let view_options = state.timeline_view_options_for(&timeline_type);
+				let timeline_index = state.timeline_manager.index_of(&timeline_type);
+				let filter_context = timeline_type.filter_context();
+				let timeline_filter = state.config.filters.resolve(timeline_type.template_key());
+				let current_user_id = state.current_user_id.as_deref();
+				let is_active = active_type.as_ref() == Some(&timeline_type);
+				let Some(timeline) = state.timeline_manager.get_mut(&timeline_type) else { continue };
+				if is_active {
+					let effective_sort_order = timeline.effective_sort_order(&state.config);
+					sync_timeline_selection_from_list(timeline, timeline_list, effective_sort_order);
+				}
+				let existing_ids: std::collections::HashSet<String> =
+					timeline.entries.iter().map(|entry| entry.id().to_string()).collect();
+				let (new_replies, filtered_out): (Vec<Status>, Vec<Status>) =
+					replies.into_iter().filter(|s| !existing_ids.contains(&s.id)).partition(|s| {
+						!s.should_hide(&filter_context, state.config.hide_warned_posts)
+							&& s.matches_filter(&timeline_filter, current_user_id)
+					});
+				timeline.record_filtered_replies(&filtered_out);
+				// The context endpoint returns every reply it will give, so whatever is still missing was filtered out
+				// and loading more wouldn't bring it back.
+				if !timeline.fetched_replies.contains(&status_id) {
+					timeline.fetched_replies.push(status_id.clone());
+				}
+				let count = new_replies.len();
+				// The row that was just used goes away, so the selection moves to the first reply it brought in.
+				if timeline.selected_id.as_deref() == Some(crate::timeline::more_replies_id(&status_id).as_str()) {
+					let first_reply = new_replies.iter().min_by(|a, b| a.created_at.cmp(&b.created_at));
+					timeline.selected_id =
+						Some(first_reply.map_or_else(|| status_id.clone(), |reply| reply.id.clone()));
+				}
+				let mut statuses: Vec<Status> = timeline
+					.entries
+					.drain(..)
+					.filter_map(|entry| match entry {
+						TimelineEntry::Status(status) => Some(*status),
+						_ => None,
+					})
+					.collect();
+				statuses.extend(new_replies);
+				let entries = crate::network::order_thread(statuses)
+					.into_iter()
+					.map(|status| TimelineEntry::Status(Box::new(status)))
+					.collect();
+				timeline.entries = crate::timeline::with_more_replies_rows(
+					entries,
+					&timeline.fetched_replies,
+					&timeline.filtered_reply_counts(),
+				);
+				if is_active && let Some(idx) = timeline_index {
+					update_active_timeline_ui(
+						timeline_list,
+						timeline,
+						suppress_selection,
+						&view_options,
+						&state.cw_expanded,
+						idx,
+					);
+				}
+				if count == 0 {
+					live_region.announce("No more replies");
+				} else {
+					let noun = if count == 1 { "reply" } else { "replies" };
+					live_region.announce(&format!("Loaded {count} more {noun}"));
+				}
+			}
+			NetworkResponse::ThreadRepliesLoaded { result: Err(err), .. } => {
+				live_region.announce(&format!("Failed to load more replies: {}", summarize_api_error(&err)));
+			}
 			NetworkResponse::StatusResolvedForQuote { result: Ok(focus) } => {
 				ui_tx.send(crate::commands::UiCommand::PromptForQuote(Box::new(focus))).unwrap();
 			}
@@ -501,51 +835,42 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 						None
 					}
 				};
-				crate::commands::run_edit_post_dialog(frame, state, &status, source_text.as_deref());
+				crate::commands::run_edit_post_dialog(frame, state, live_region, &status, source_text.as_deref());
+				crate::commands::restore_focus(None, state, frame, timelines_selector, timeline_list, tray_hidden);
 			}
 			NetworkResponse::StatusResolvedForQuote { result: Err(err) } => {
 				live_region.announce(&format!("Failed to resolve post for quote: {}", summarize_api_error(&err)));
 			}
 			NetworkResponse::AccountLookupResult { handle: _, result: Ok(account) } => {
 				let action = state.pending_user_lookup_action.take().unwrap_or(UserLookupAction::Timeline);
-				match action {
-					UserLookupAction::Profile => {
-						if let Some(net) = &state.network_handle {
-							net.send(NetworkCommand::FetchRelationship { account_id: account.id.clone() });
-							let net_tx = net.command_tx.clone();
-							let ui_tx_timeline = ui_tx.clone();
-							let timeline_type = TimelineType::User {
-								id: account.id.clone(),
-								name: account.display_name_or_username().to_string(),
-							};
-							let ui_tx_close = ui_tx.clone();
-
-							let dlg = dialogs::ProfileDialog::new(
-								frame,
-								account.clone(),
-								state.current_user_id.as_deref(),
-								net_tx,
-								ui_tx.clone(),
-								move || {
-									let _ = ui_tx_timeline.send(UiCommand::OpenTimeline(timeline_type.clone()));
-								},
-								move || {
-									let _ = ui_tx_close.send(UiCommand::ProfileDialogClosed);
-								},
-							);
-							dlg.show();
-							state.profile_dialog = Some(dlg);
-						} else {
-							live_region.announce("Network not available");
-						}
-					}
-					UserLookupAction::Timeline => {
-						let timeline_type = TimelineType::User {
-							id: account.id.clone(),
-							name: account.display_name_or_username().to_string(),
-						};
-						dispatch_ui_command!(UiCommand::OpenTimeline(timeline_type));
-					}
+				if let Some(timeline_type) = open_looked_up_account(state, frame, ui_tx, live_region, account, action) {
+					dispatch_ui_command!(UiCommand::OpenTimeline(timeline_type));
+				}
+			}
+			NetworkResponse::AccountLookupAmbiguous { handle, accounts } => {
+				state.pending_user_lookup_action = None;
+				live_region.announce(&format!("{} users named {handle}", accounts.len()));
+				let labels: Vec<String> = accounts
+					.iter()
+					.map(|a| {
+						format!(
+							"{} (@{})",
+							a.timeline_display_name(state.config.display_name_emoji_mode),
+							a.full_acct()
+						)
+					})
+					.collect();
+				let label_refs: Vec<&str> = labels.iter().map(String::as_str).collect();
+				let account_refs: Vec<&Account> = accounts.iter().collect();
+				if let Some((account, action)) = dialogs::prompt_for_account_selection(
+					frame,
+					&format!("Users Named {handle}"),
+					&account_refs,
+					&label_refs,
+				) && let Some(timeline_type) =
+					open_looked_up_account(state, frame, ui_tx, live_region, account, action)
+				{
+					dispatch_ui_command!(UiCommand::OpenTimeline(timeline_type));
 				}
 			}
 			NetworkResponse::AccountLookupResult { handle, result: Err(err) } => {
@@ -615,6 +940,10 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				update_status_in_timelines(state, &status_id, |s| {
 					s.bookmarked = status.bookmarked;
 				});
+				if state.active_account_mut().and_then(|account| account.bookmark_tags.remove(&status_id)).is_some() {
+					let _ = ConfigStore::new().save(&state.config);
+					crate::commands::refetch_tagged_bookmarks(state);
+				}
 				if let Some(mb) = frame.get_menu_bar() {
 					update_menu_labels(&mb, state);
 				}
@@ -649,6 +978,30 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 			NetworkResponse::Unpinned { result: Err(ref err), .. } => {
 				live_region.announce(&spoken_failure("Failed to unpin post", err));
 			}
+			NetworkResponse::ConversationMuted { status_id, result: Ok(status) } => {
+				update_status_in_timelines(state, &status_id, |s| {
+					s.muted = status.muted;
+				});
+				if let Some(mb) = frame.get_menu_bar() {
+					update_menu_labels(&mb, state);
+				}
+				live_region.announce("Conversation muted");
+			}
+			NetworkResponse::ConversationMuted { result: Err(ref err), .. } => {
+				live_region.announce(&spoken_failure("Failed to mute conversation", err));
+			}
+			NetworkResponse::ConversationUnmuted { status_id, result: Ok(status) } => {
+				update_status_in_timelines(state, &status_id, |s| {
+					s.muted = status.muted;
+				});
+				if let Some(mb) = frame.get_menu_bar() {
+					update_menu_labels(&mb, state);
+				}
+				live_region.announce("Conversation unmuted");
+			}
+			NetworkResponse::ConversationUnmuted { result: Err(ref err), .. } => {
+				live_region.announce(&spoken_failure("Failed to unmute conversation", err));
+			}
 			NetworkResponse::Boosted { status_id, result: Ok(status) } => {
 				// The returned status is the reblog wrapper, get the inner status
 				if let Some(inner) = &status.reblog {
@@ -720,7 +1073,7 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 			NetworkResponse::StatusDeleted { result: Err(ref err), .. } => {
 				live_region.announce(&spoken_failure("Failed to delete", err));
 			}
-			NetworkResponse::StatusEdited { _status_id: _, result: Ok(status) } => {
+			NetworkResponse::StatusEdited { _status_id: _, descriptions_updated, result: Ok(status) } => {
 				let status_clone = status.clone();
 				update_status_in_timelines(state, &status.id, move |s| *s = status_clone.clone());
 				{
@@ -740,11 +1093,48 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 						);
 					}
 				}
-				live_region.announce("Edited");
+				live_region.announce(if descriptions_updated { "Edited. Descriptions updated" } else { "Edited" });
 			}
 			NetworkResponse::StatusEdited { result: Err(ref err), .. } => {
 				live_region.announce(&spoken_failure("Failed to edit", err));
 			}
+			NetworkResponse::StatusRefreshed { status_id, result: Ok(status) } => {
+				let summary = status.interaction_summary();
+				update_status_in_timelines(state, &status_id, |s| {
+					// Pinned and conversation markers are set locally, not sent with the status.
+					let (pinned, conversation_id) = (s.pinned, s.conversation_id.take());
+					*s = status.clone();
+					s.pinned = pinned;
+					s.conversation_id = conversation_id;
+				});
+				{
+					let view_options =
+						state.timeline_manager.active().map(|a| state.timeline_view_options_for(&a.timeline_type));
+					let active_index = state.timeline_manager.active_index();
+					if let Some(view_options) = view_options
+						&& let Some(active) = state.timeline_manager.active_mut()
+					{
+						update_active_timeline_ui(
+							timeline_list,
+							active,
+							suppress_selection,
+							&view_options,
+							&state.cw_expanded,
+							active_index,
+						);
+					}
+				}
+				if let Some(mb) = frame.get_menu_bar() {
+					update_menu_labels(&mb, state);
+				}
+				live_region.announce(&format!(
+					"Post refreshed, {}",
+					summary.as_deref().unwrap_or("no boosts, favorites, or replies")
+				));
+			}
+			NetworkResponse::StatusRefreshed { result: Err(ref err), .. } => {
+				live_region.announce(&spoken_failure("Failed to refresh post", err));
+			}
 			NetworkResponse::TagFollowed { name, result: Ok(_) } => {
 				update_tag_in_timelines(state, &name, true);
 				if let Some(dlg) = &state.hashtag_dialog {
@@ -765,6 +1155,12 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 			NetworkResponse::TagUnfollowed { name, result: Err(err) } => {
 				live_region.announce(&format!("Failed to unfollow #{name}: {}", summarize_api_error(&err)));
 			}
+			NetworkResponse::FiltersLoaded { result } => {
+				// Filters are only fetched to time the next expiry; the filter manager fetches its own copy.
+				if let Ok(filters) = result {
+					state.next_filter_expiry = next_filter_expiry(&filters);
+				}
+			}
 			NetworkResponse::TagsInfoFetched { result: Ok(tags) } => {
 				if let Some(handle) = &state.network_handle {
 					let net_tx = handle.command_tx.clone();
@@ -798,9 +1194,13 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				live_region.announce(&format!("Failed to unmute #{name}: {}", summarize_api_error(&err)));
 			}
 			NetworkResponse::RebloggedByLoaded { result: Ok(accounts), .. } => {
-				if let Some((account, action)) =
-					dialogs::prompt_for_account_list(frame, "Boosts", "Users who boosted this post", &accounts)
-				{
+				if let Some((account, action)) = dialogs::prompt_for_account_list(
+					frame,
+					"Boosts",
+					"Users who boosted this post",
+					&accounts,
+					state.config.display_name_emoji_mode,
+				) {
 					match action {
 						UserLookupAction::Profile => {
 							if let Some(net) = &state.network_handle {
@@ -816,6 +1216,7 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 									frame,
 									account.clone(),
 									state.current_user_id.as_deref(),
+									&state.config,
 									net_tx,
 									ui_tx.clone(),
 									move || {
@@ -845,9 +1246,13 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				live_region.announce(&spoken_failure("Failed to load boosts", &err));
 			}
 			NetworkResponse::FavoritedByLoaded { result: Ok(accounts), .. } => {
-				if let Some((account, action)) =
-					dialogs::prompt_for_account_list(frame, "Favorites", "Users who favorited this post", &accounts)
-				{
+				if let Some((account, action)) = dialogs::prompt_for_account_list(
+					frame,
+					"Favorites",
+					"Users who favorited this post",
+					&accounts,
+					state.config.display_name_emoji_mode,
+				) {
 					match action {
 						UserLookupAction::Profile => {
 							if let Some(net) = &state.network_handle {
@@ -863,6 +1268,7 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 									frame,
 									account.clone(),
 									state.current_user_id.as_deref(),
+									&state.config,
 									net_tx,
 									ui_tx.clone(),
 									move || {
@@ -914,6 +1320,7 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 					&accounts,
 					total_count,
 					account_id_opt,
+					state.config.display_name_emoji_mode,
 					net_tx_dlg,
 					ui_tx_dlg,
 					move |account| {
@@ -932,9 +1339,8 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 					dlg.mark_loaded();
 				}
 				state.followers_dialog = Some(dlg);
+				load_list_relationships(state, &accounts, true);
 				if let Some(h) = &state.network_handle {
-					let account_ids = accounts.iter().map(|a| a.id.clone()).collect();
-					let _ = h.send(NetworkCommand::FetchRelationshipsForList { account_ids, for_followers: true });
 					if let Some(max_id) = next_max_id {
 						let _ = h.send(NetworkCommand::FetchNextFollowersPage { account_id, max_id });
 					}
@@ -957,14 +1363,11 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				} else {
 					None
 				};
-				if let Some(h) = &state.network_handle {
-					if !accounts.is_empty() {
-						let account_ids = accounts.iter().map(|a| a.id.clone()).collect();
-						let _ = h.send(NetworkCommand::FetchRelationshipsForList { account_ids, for_followers: true });
-					}
-					if let Some((account_id, max_id)) = should_fetch {
-						let _ = h.send(NetworkCommand::FetchNextFollowersPage { account_id, max_id });
-					}
+				load_list_relationships(state, &accounts, true);
+				if let Some(h) = &state.network_handle
+					&& let Some((account_id, max_id)) = should_fetch
+				{
+					let _ = h.send(NetworkCommand::FetchNextFollowersPage { account_id, max_id });
 				}
 			}
 			NetworkResponse::FollowersNextPageLoaded { result: Err(err) } => {
@@ -996,6 +1399,7 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 					&accounts,
 					total_count,
 					account_id_opt,
+					state.config.display_name_emoji_mode,
 					net_tx_dlg,
 					ui_tx_dlg,
 					move |account| {
@@ -1014,9 +1418,8 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 					dlg.mark_loaded();
 				}
 				state.following_dialog = Some(dlg);
+				load_list_relationships(state, &accounts, false);
 				if let Some(h) = &state.network_handle {
-					let account_ids = accounts.iter().map(|a| a.id.clone()).collect();
-					let _ = h.send(NetworkCommand::FetchRelationshipsForList { account_ids, for_followers: false });
 					if let Some(max_id) = next_max_id {
 						let _ = h.send(NetworkCommand::FetchNextFollowingPage { account_id, max_id });
 					}
@@ -1039,14 +1442,11 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				} else {
 					None
 				};
-				if let Some(h) = &state.network_handle {
-					if !accounts.is_empty() {
-						let account_ids = accounts.iter().map(|a| a.id.clone()).collect();
-						let _ = h.send(NetworkCommand::FetchRelationshipsForList { account_ids, for_followers: false });
-					}
-					if let Some((account_id, max_id)) = should_fetch {
-						let _ = h.send(NetworkCommand::FetchNextFollowingPage { account_id, max_id });
-					}
+				load_list_relationships(state, &accounts, false);
+				if let Some(h) = &state.network_handle
+					&& let Some((account_id, max_id)) = should_fetch
+				{
+					let _ = h.send(NetworkCommand::FetchNextFollowingPage { account_id, max_id });
 				}
 			}
 			NetworkResponse::FollowingNextPageLoaded { result: Err(err) } => {
@@ -1055,14 +1455,20 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				}
 				live_region.announce(&spoken_failure("Failed to load more following", &err));
 			}
-			NetworkResponse::RelationshipsForListLoaded { results, for_followers } => {
-				let dialog = if for_followers { &state.followers_dialog } else { &state.following_dialog };
-				if let Some(dlg) = dialog {
-					dlg.update_relationships(&results);
+			NetworkResponse::RelationshipsLoaded { account_ids, result } => {
+				for id in &account_ids {
+					state.pending_relationships.remove(id);
+				}
+				if let Ok(relationships) = result {
+					for dlg in [&state.followers_dialog, &state.following_dialog].into_iter().flatten() {
+						dlg.update_relationships(&relationships);
+					}
+					remember_relationships(state, &relationships, timeline_list, suppress_selection);
 				}
 			}
 			NetworkResponse::RelationshipUpdated { _account_id: _, target_name, action, result } => match result {
 				Ok(rel) => {
+					remember_relationships(state, slice::from_ref(&rel), timeline_list, suppress_selection);
 					if let Some(dlg) = &state.profile_dialog {
 						dlg.update_relationship(&rel);
 					}
@@ -1074,6 +1480,7 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 					}
 					let msg = match action {
 						crate::network::RelationshipAction::Follow => format!("Followed {target_name}"),
+						crate::network::RelationshipAction::FollowBack => format!("Followed back {target_name}"),
 						crate::network::RelationshipAction::Unfollow => format!("Unfollowed {target_name}"),
 						crate::network::RelationshipAction::CancelFollowRequest => {
 							format!("Canceled follow request to {target_name}")
@@ -1099,11 +1506,23 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 					live_region.announce(&spoken_failure("Failed to update relationship", &err));
 				}
 			},
+			NetworkResponse::FollowBackSkipped { target_name, relationship } => {
+				remember_relationships(state, slice::from_ref(&relationship), timeline_list, suppress_selection);
+				if let Some(dlg) = &state.profile_dialog {
+					dlg.update_relationship(&relationship);
+				}
+				if relationship.following {
+					live_region.announce(&format!("Already following {target_name}"));
+				} else {
+					live_region.announce(&format!("Follow request to {target_name} already pending"));
+				}
+			}
 			NetworkResponse::RelationshipLoaded { _account_id: _, result } => {
-				if let Ok(rel) = result
-					&& let Some(dlg) = &state.profile_dialog
-				{
-					dlg.update_relationship(&rel);
+				if let Ok(rel) = result {
+					if let Some(dlg) = &state.profile_dialog {
+						dlg.update_relationship(&rel);
+					}
+					remember_relationships(state, slice::from_ref(&rel), timeline_list, suppress_selection);
 				}
 			}
 			NetworkResponse::AccountFetched { result } => {
@@ -1131,18 +1550,30 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 							active_index,
 						);
 					}
-					live_region.announce("Vote submitted");
+					match poll.leader_summary(state.config.display_name_emoji_mode) {
+						Some(leader) => live_region.announce(&format!("Vote recorded, {leader}")),
+						None => live_region.announce("Vote recorded"),
+					}
 				}
 				Err(err) => {
 					live_region.announce(&spoken_failure("Failed to vote", &err));
 				}
 			},
+			NetworkResponse::ConnectionChecked { result: Ok(()) } => {}
+			NetworkResponse::ConnectionChecked { result: Err(err) } => {
+				live_region.announce("Could not reach your instance with the new connection settings");
+				dialogs::show_error(
+					frame,
+					&anyhow::anyhow!("Could not reach your instance with the new connection settings.\n\n{err:#}"),
+				);
+			}
 			NetworkResponse::CredentialsFetched { result: Ok(account) } => {
 				if let Some(update) = dialogs::show_profile_edit_dialog(frame, &account)
 					&& let Some(handle) = &state.network_handle
 				{
 					handle.send(NetworkCommand::UpdateProfile { update });
 				}
+				crate::commands::restore_focus(None, state, frame, timelines_selector, timeline_list, tray_hidden);
 			}
 			NetworkResponse::CredentialsFetched { result: Err(err) } => {
 				live_region.announce(&spoken_failure("Failed to fetch profile", &err));
@@ -1171,7 +1602,7 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 					let accounts_ref: Vec<&crate::mastodon::Account> = results.accounts.iter().collect();
 
 					if let Some(account) =
-						dialogs::prompt_for_account_choice(dlg.get_dialog(), &accounts_ref, &label_refs)
+						dialogs::prompt_for_account_choice(dlg.get_dialog(), "Add Member", &accounts_ref, &label_refs)
 						&& let Some(handle) = &state.network_handle
 					{
 						handle.send(NetworkCommand::AddListAccount {
@@ -1211,6 +1642,7 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 					let is_load_more = offset.is_some() && offset.unwrap_or(0) > 0;
 					if is_load_more {
 						if new_entries.is_empty() {
+							timeline.end_reached = true;
 							live_region.announce("No more results");
 						} else {
 							timeline.entries.extend(new_entries.clone());
@@ -1229,6 +1661,11 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 						}
 					} else {
 						timeline.entries = new_entries;
+						timeline.end_reached = false;
+						timeline.loaded = true;
+						if is_active && timeline.entries.is_empty() {
+							live_region.announce("No results");
+						}
 						if is_active {
 							if let Some(idx) = timeline_index_opt {
 								update_active_timeline_ui(
@@ -1278,13 +1715,20 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				live_region.announce(&format!("Search for '{query}' failed: {}", summarize_api_error(err)));
 			}
 			NetworkResponse::ListsFetched { result: Ok(lists) } => {
-				if let Some(account_id) = state.pending_add_to_list_user.take() {
+				state.lists.clone_from(&lists);
+				crate::commands::refresh_timeline_names(state, timelines_selector, suppress_selection, frame);
+				if let Some((account_id, acct)) = state.pending_add_to_list_user.take() {
 					if lists.is_empty() {
 						live_region.announce("No lists found to add user to");
 					} else if let Some(list) = dialogs::show_list_selection_dialog(frame, &lists, "Add to List", "Add")
 					{
 						if let Some(handle) = &state.network_handle {
-							handle.send(NetworkCommand::AddListAccount { list_id: list.id, account_id });
+							handle.send(NetworkCommand::AddAccountToList {
+								list_id: list.id,
+								list_title: list.title,
+								account_id,
+								acct,
+							});
 						}
 					}
 				} else if let Some(dlg) = &state.manage_lists_dialog {
@@ -1300,11 +1744,41 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				live_region.announce(&spoken_failure("Failed to fetch lists", &err));
 			}
 			NetworkResponse::ListCreated { result: Ok(list) } => {
+				if let Some((_, account_ids)) =
+					state.pending_new_list_members.take_if(|(title, _)| *title == list.title)
+				{
+					live_region.announce(&format!(
+						"List '{}' created, adding {} {}",
+						list.title,
+						account_ids.len(),
+						if account_ids.len() == 1 { "author" } else { "authors" }
+					));
+					if let Some(handle) = &state.network_handle {
+						handle.send(NetworkCommand::AddAccountsToNewList {
+							list_id: list.id.clone(),
+							list_title: list.title.clone(),
+							account_ids,
+						});
+					}
+					state.lists.push(list);
+					continue;
+				}
 				live_region.announce(&format!("List '{}' created", list.title));
 				if let Some(handle) = &state.network_handle {
 					handle.send(NetworkCommand::FetchLists);
 				}
 			}
+			NetworkResponse::AccountsAddedToNewList { list_id, list_title, added, failed } => {
+				let mut summary = format!("Added {added} of {} authors to {list_title}", added + failed);
+				if failed > 0 {
+					summary.push_str(". Some instances only allow adding accounts you follow");
+				}
+				live_region.announce(&summary);
+				if added > 0 {
+					let timeline_type = TimelineType::List { id: list_id, title: list_title };
+					dispatch_ui_command!(UiCommand::OpenTimeline(timeline_type));
+				}
+			}
 			NetworkResponse::ListCreated { result: Err(err) }
 			| NetworkResponse::ListUpdated { result: Err(err) }
 			| NetworkResponse::ListDeleted { result: Err(err), .. }
@@ -1312,6 +1786,7 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 			| NetworkResponse::ListAccountAdded { result: Err(err), .. }
 			| NetworkResponse::ListAccountRemoved { result: Err(err), .. } => {
 				state.pending_add_to_list_user = None;
+				state.pending_new_list_members = None;
 				let parent: &dyn WxWidget = if let Some(dlg) = &state.manage_list_members_dialog {
 					dlg.get_dialog()
 				} else if let Some(dlg) = &state.manage_lists_dialog {
@@ -1323,6 +1798,10 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 			}
 			NetworkResponse::ListUpdated { result: Ok(list) } => {
 				live_region.announce(&format!("List '{}' updated", list.title));
+				if let Some(cached) = state.lists.iter_mut().find(|cached| cached.id == list.id) {
+					*cached = list;
+					crate::commands::refresh_timeline_names(state, timelines_selector, suppress_selection, frame);
+				}
 				if let Some(handle) = &state.network_handle {
 					handle.send(NetworkCommand::FetchLists);
 				}
@@ -1342,24 +1821,42 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 						return;
 					}
 				}
-				if let Some(dlg) = &state.manage_lists_dialog {
-					let list_title = dlg.get_list_title(&list_id).unwrap_or_default();
-					if let Some(handle) = &state.network_handle {
-						let net_tx = handle.command_tx.clone();
-						let ui_tx_dlg = ui_tx.clone();
-						let members_dlg = dialogs::ManageListMembersDialog::new(
-							dlg.get_dialog(),
-							list_id,
-							&list_title,
-							members,
-							net_tx,
-							move || {
-								let _ = ui_tx_dlg.send(UiCommand::ManageListMembersDialogClosed);
-							},
-						);
-						members_dlg.show();
-						state.manage_list_members_dialog = Some(members_dlg);
-					}
+				let from_current_list = state.pending_manage_list_id.take_if(|id| *id == list_id).is_some();
+				if !from_current_list && state.manage_lists_dialog.is_none() {
+					continue;
+				}
+				let list_title = state
+					.lists
+					.iter()
+					.find(|list| list.id == list_id)
+					.map(|list| list.title.clone())
+					.or_else(|| state.manage_lists_dialog.as_ref().and_then(|dlg| dlg.get_list_title(&list_id)))
+					.or_else(|| {
+						state.timeline_manager.timelines().iter().find_map(|timeline| match &timeline.timeline_type {
+							TimelineType::List { id, title } if *id == list_id => Some(title.clone()),
+							_ => None,
+						})
+					})
+					.unwrap_or_default();
+				let parent: &dyn WxWidget = match &state.manage_lists_dialog {
+					Some(dlg) if !from_current_list => dlg.get_dialog(),
+					_ => frame,
+				};
+				if let Some(handle) = &state.network_handle {
+					let net_tx = handle.command_tx.clone();
+					let ui_tx_dlg = ui_tx.clone();
+					let members_dlg = dialogs::ManageListMembersDialog::new(
+						parent,
+						list_id,
+						&list_title,
+						members,
+						net_tx,
+						move || {
+							let _ = ui_tx_dlg.send(UiCommand::ManageListMembersDialogClosed);
+						},
+					);
+					members_dlg.show();
+					state.manage_list_members_dialog = Some(members_dlg);
 				}
 			}
 
@@ -1372,6 +1869,79 @@ pub fn process_network_responses(ctx: &mut NetworkResponseContext<'_>) {
 				}
 			}
 
+			NetworkResponse::AccountAddedToList { list_title, acct, already_member, result } => match result {
+				Ok(()) if already_member => live_region.announce(&format!("@{acct} is already in {list_title}")),
+				Ok(()) => live_region.announce(&format!("Added @{acct} to {list_title}")),
+				Err(err) => live_region.announce(&spoken_failure("Failed to add to list", &err)),
+			},
+			NetworkResponse::AccountListsFetched { account_id, acct, result: Ok((lists, containing)) } => {
+				state.lists.clone_from(&lists);
+				crate::commands::refresh_timeline_names(state, timelines_selector, suppress_selection, frame);
+				if lists.is_empty() {
+					live_region.announce("No lists found");
+					continue;
+				}
+				if containing.is_empty() {
+					live_region.announce(&format!("@{acct} is not in any of your lists"));
+				} else {
+					let titles: Vec<&str> = containing.iter().map(|l| l.title.as_str()).collect();
+					live_region.announce(&format!("@{acct} is in {}", titles.join(", ")));
+				}
+				if let Some((add, remove)) = dialogs::prompt_for_list_memberships(frame, &acct, &lists, &containing)
+					&& (!add.is_empty() || !remove.is_empty())
+					&& let Some(handle) = &state.network_handle
+				{
+					handle.send(NetworkCommand::UpdateAccountLists { account_id, acct, add, remove });
+				}
+			}
+			NetworkResponse::AccountListsFetched { result: Err(err), .. } => {
+				live_region.announce(&spoken_failure("Failed to fetch lists", &err));
+			}
+			NetworkResponse::AccountListsUpdated { acct, added, removed, failed } => {
+				let mut parts = Vec::new();
+				if !added.is_empty() {
+					parts.push(format!("Added @{acct} to {}", added.join(", ")));
+				}
+				if !removed.is_empty() {
+					parts.push(format!("Removed @{acct} from {}", removed.join(", ")));
+				}
+				if !failed.is_empty() {
+					parts.push(format!("Failed to update {}", failed.join(", ")));
+				}
+				live_region.announce(&parts.join(". "));
+			}
+			NetworkResponse::AuthExpired => {
+				if state.pending_reauth_account_id.is_some() {
+					continue;
+				}
+				let Some(account) = state.active_account() else { continue };
+				if state.declined_reauth_account_id.as_deref() == Some(account.id.as_str()) {
+					continue;
+				}
+				let account_id = account.id.clone();
+				let prompt = format!(
+					"Your session for {} has expired or was revoked. Sign in again now? Your open timelines will be kept.",
+					account.full_handle()
+				);
+				state.pending_reauth_account_id = Some(account_id.clone());
+				live_region.announce("Session expired, please re-authenticate");
+				let dialog = MessageDialog::builder(frame, &prompt, "Session Expired")
+					.with_style(MessageDialogStyle::YesNo | MessageDialogStyle::IconWarning)
+					.build();
+				if dialog.show_modal() == ID_YES {
+					start_reauth_flow(frame, ui_tx, state, &account_id);
+				} else {
+					state.pending_reauth_account_id = None;
+					state.declined_reauth_account_id = Some(account_id);
+					live_region.announce("Use File, Sign In Again when you're ready to sign back in");
+				}
+			}
+			NetworkResponse::TokenRevoked { handle, result: Ok(()) } => {
+				live_region.announce(&format!("Revoked Fedra's access to {handle}"));
+			}
+			NetworkResponse::TokenRevoked { result: Err(_), .. } => {}
+			// Unread state is only shown locally; a failure shows up again as unread on the next refresh.
+			NetworkResponse::ConversationRead { .. } => {}
 			NetworkResponse::ListAccountRemoved { result: Ok(()), .. } => {
 				live_region.announce("Member removed");
 				if let Some(dlg) = &state.manage_list_members_dialog
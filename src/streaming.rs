@@ -6,11 +6,16 @@ use std::{
 };
 
 use serde::Deserialize;
-use tungstenite::{Message, client::IntoClientRequest, client_tls_with_config, http::Uri};
+use tungstenite::{
+	Message,
+	client::IntoClientRequest,
+	client_tls_with_config,
+	http::{HeaderValue, Uri, header},
+};
 use url::Url;
 
 use crate::{
-	mastodon::{Conversation, Notification, Status},
+	mastodon::{Conversation, KeepRawJson, Notification, Status, from_value_keeping_raw},
 	timeline::TimelineType,
 	ui_wake::UiWaker,
 };
@@ -56,6 +61,10 @@ pub fn start_streaming(
 	timeline_type: TimelineType,
 	ui_waker: UiWaker,
 ) -> Option<StreamHandle> {
+	#[cfg(feature = "mock")]
+	if crate::mock::server_url().is_some() {
+		return None;
+	}
 	let stream_params = timeline_type.stream_params()?;
 	let mut streaming_url = base_url.join("api/v1/streaming").ok()?;
 	let scheme = if base_url.scheme() == "https" || base_url.scheme() == "wss" { "wss" } else { "ws" };
@@ -108,7 +117,10 @@ fn connect_and_stream(
 	sender: &Sender<StreamEvent>,
 	ui_waker: &UiWaker,
 ) -> Result<(), String> {
-	let request = url.as_str().into_client_request().map_err(|e| format!("WebSocket request failed: {e}"))?;
+	let mut request = url.as_str().into_client_request().map_err(|e| format!("WebSocket request failed: {e}"))?;
+	let user_agent =
+		HeaderValue::from_str(&crate::mastodon::user_agent()).map_err(|e| format!("Invalid User-Agent: {e}"))?;
+	request.headers_mut().insert(header::USER_AGENT, user_agent);
 	let uri = request.uri().clone();
 	let tcp = connect_tcp_stream(&uri)?;
 	let (mut socket, _response) =
@@ -167,6 +179,13 @@ fn connect_tcp_stream(uri: &Uri) -> Result<TcpStream, String> {
 	let host = uri.host().ok_or_else(|| "WebSocket URL missing host".to_string())?;
 	let host = if host.starts_with('[') { &host[1..host.len() - 1] } else { host };
 	let port = uri.port_u16().unwrap_or_else(|| if uri.scheme_str() == Some("wss") { 443 } else { 80 });
+	let proxy =
+		crate::proxy::proxy_url().or_else(|| crate::proxy::system_proxy_for(host, uri.scheme_str() == Some("wss")));
+	if let Some(proxy) = proxy {
+		let stream = crate::proxy::connect_through_proxy(&proxy, host, port, CONNECT_TIMEOUT)?;
+		configure_tcp_timeouts(&stream).map_err(|e| format!("Failed to configure WebSocket timeouts: {e}"))?;
+		return Ok(stream);
+	}
 	let addrs = (host, port).to_socket_addrs().map_err(|e| format!("Failed to resolve WebSocket host: {e}"))?;
 	let mut last_err = None;
 	for addr in addrs {
@@ -196,6 +215,10 @@ struct StreamMessage {
 	payload: Option<String>,
 }
 
+fn parse_payload<T: serde::de::DeserializeOwned + KeepRawJson>(payload: &str) -> Option<T> {
+	serde_json::from_str(payload).and_then(from_value_keeping_raw).ok()
+}
+
 fn parse_stream_message(text: &str, timeline_type: &TimelineType) -> Option<StreamEvent> {
 	let msg: StreamMessage = serde_json::from_str(text).ok()?;
 	match msg.event.as_str() {
@@ -204,12 +227,12 @@ fn parse_stream_message(text: &str, timeline_type: &TimelineType) -> Option<Stre
 				return None;
 			}
 			let payload = msg.payload?;
-			let status: Status = serde_json::from_str(&payload).ok()?;
+			let status: Status = parse_payload(&payload)?;
 			Some(StreamEvent::Update { timeline_type: timeline_type.clone(), status: Box::new(status) })
 		}
 		"status.update" => {
 			let payload = msg.payload?;
-			let status: Status = serde_json::from_str(&payload).ok()?;
+			let status: Status = parse_payload(&payload)?;
 			Some(StreamEvent::StatusUpdate { status: Box::new(status) })
 		}
 		"delete" => {
@@ -224,7 +247,7 @@ fn parse_stream_message(text: &str, timeline_type: &TimelineType) -> Option<Stre
 				return None;
 			}
 			let payload = msg.payload?;
-			let notification: Notification = serde_json::from_str(&payload).ok()?;
+			let notification: Notification = parse_payload(&payload)?;
 			Some(StreamEvent::Notification {
 				timeline_type: timeline_type.clone(),
 				notification: Box::new(notification),
@@ -235,7 +258,7 @@ fn parse_stream_message(text: &str, timeline_type: &TimelineType) -> Option<Stre
 				return None;
 			}
 			let payload = msg.payload?;
-			let conversation: Conversation = serde_json::from_str(&payload).ok()?;
+			let conversation: Conversation = parse_payload(&payload)?;
 			Some(StreamEvent::Conversation {
 				timeline_type: timeline_type.clone(),
 				conversation: Box::new(conversation),
@@ -1,9 +1,13 @@
-use minijinja::{Environment, context};
+use minijinja::{Environment, Value, context};
 
 pub const DEFAULT_POST_TEMPLATE: &str = "{{ author }}: {{ content }}{% if media or poll %} - {{ media }}{{ poll }}{% endif %} - {{ relative_time }}, {{ visibility }}{% if reply_count %}, {{ reply_count }}{% endif %}{% if boost_count %}, {{ boost_count }}{% endif %}{% if favorite_count %}, {{ favorite_count }}{% endif %}{% if client %}, via {{ client }}{% endif %}";
 pub const DEFAULT_BOOST_TEMPLATE: &str = "{{ booster }} boosted {{ author }}: {{ content }}{% if media or poll %} - {{ media }}{{ poll }}{% endif %}{% if quote_author %} - Quoting {{ quote_author }} ({{ quote_username }}): {{ quote_content }}{% if quote_media or quote_poll %} - {{ quote_media }}{{ quote_poll }}{% endif %}{% endif %} - {{ relative_time }}, {{ visibility }}{% if reply_count %}, {{ reply_count }}{% endif %}{% if boost_count %}, {{ boost_count }}{% endif %}{% if favorite_count %}, {{ favorite_count }}{% endif %}{% if client %}, via {{ client }}{% endif %}";
 pub const DEFAULT_QUOTE_TEMPLATE: &str = "{{ author }}: {{ content }}{% if media or poll %} - {{ media }}{{ poll }}{% endif %} - Quoting {{ quote_author }} ({{ quote_username }}): {{ quote_content }}{% if quote_media or quote_poll %} - {{ quote_media }}{{ quote_poll }}{% endif %} - {{ relative_time }}, {{ visibility }}{% if reply_count %}, {{ reply_count }}{% endif %}{% if boost_count %}, {{ boost_count }}{% endif %}{% if favorite_count %}, {{ favorite_count }}{% endif %}{% if client %}, via {{ client }}{% endif %}";
 pub const DEFAULT_WINDOW_TITLE_TEMPLATE: &str = "Fedra - {{ account }}";
+pub const DEFAULT_MENTION_NOTIFICATION_TEMPLATE: &str = "{{ post }}";
+pub const DEFAULT_FAVORITE_NOTIFICATION_TEMPLATE: &str = "{{ actor }} ({{ actor_username }}) favorited {{ post }}";
+pub const DEFAULT_FOLLOW_NOTIFICATION_TEMPLATE: &str = "{{ actor }} followed you";
+pub const DEFAULT_POLL_NOTIFICATION_TEMPLATE: &str = "Poll ended: {{ post }}";
 
 pub struct WindowTitleTemplateVars {
 	pub app: String,
@@ -21,6 +25,7 @@ pub fn render_window_title(template: &str, vars: &WindowTitleTemplateVars) -> St
 	env.render_str(template, ctx).unwrap_or_else(|_| format!("{} - {}", vars.app, vars.account))
 }
 
+#[derive(Default)]
 pub struct PostTemplateVars {
 	pub author: String,
 	pub username: String,
@@ -46,7 +51,11 @@ pub struct PostTemplateVars {
 
 pub fn render_template(template: &str, vars: &PostTemplateVars) -> String {
 	let env = Environment::new();
-	let ctx = context! {
+	env.render_str(template, post_context(vars)).unwrap_or_else(|_| format!("{}: {}", vars.author, vars.content))
+}
+
+fn post_context(vars: &PostTemplateVars) -> Value {
+	context! {
 		author => vars.author,
 		username => vars.username,
 		content => vars.content,
@@ -67,6 +76,43 @@ pub fn render_template(template: &str, vars: &PostTemplateVars) -> String {
 		quote_content => vars.quote_content,
 		quote_media => vars.quote_media,
 		quote_poll => vars.quote_poll,
+	}
+}
+
+/// Variables for saved compose templates: the current local date and time.
+pub struct ComposeTemplateVars {
+	pub date: String,
+	pub time: String,
+}
+
+/// Fills in a saved compose template. `{date}` and `{time}` work as well as `{{ date }}` and `{{ time }}`, and a
+/// template that doesn't parse keeps everything else as written.
+pub fn render_compose_template(template: &str, vars: &ComposeTemplateVars) -> String {
+	let env = Environment::new();
+	let ctx = context! {
+		date => vars.date,
+		time => vars.time,
+	};
+	let rendered = env.render_str(template, ctx).unwrap_or_else(|_| template.to_string());
+	rendered.replace("{date}", &vars.date).replace("{time}", &vars.time)
+}
+
+/// Variables for notification templates: who acted, the post as the timeline would show it, and every post variable
+/// of the notification's status (empty when there is none).
+pub struct NotificationTemplateVars {
+	pub actor: String,
+	pub actor_username: String,
+	pub post: String,
+	pub status: PostTemplateVars,
+}
+
+pub fn render_notification_template(template: &str, vars: &NotificationTemplateVars) -> String {
+	let env = Environment::new();
+	let ctx = context! {
+		actor => vars.actor,
+		actor_username => vars.actor_username,
+		post => vars.post,
+		..post_context(&vars.status)
 	};
-	env.render_str(template, ctx).unwrap_or_else(|_| format!("{}: {}", vars.author, vars.content))
+	env.render_str(template, ctx).unwrap_or_else(|_| format!("{} {}", vars.actor, vars.post))
 }
@@ -14,6 +14,69 @@ pub fn strip_display_name_emojis(name: &str, mode: DisplayNameEmojiMode) -> Stri
 	normalize_spaces(&with_unicode_filtered)
 }
 
+/// Filters emoji out of a poll option title like a display name, keeping the original when nothing would be left.
+pub fn poll_option_title(title: &str, mode: DisplayNameEmojiMode) -> String {
+	let filtered = strip_display_name_emojis(title, mode);
+	if filtered.is_empty() { normalize_spaces(title) } else { filtered }
+}
+
+/// Every link counts as this many characters toward the post limit, however long it is.
+const URL_LENGTH: usize = 23;
+
+/// Length of post text as Mastodon counts it: links count as 23 characters and mentions only count the username, not
+/// the domain.
+pub fn post_length(text: &str) -> usize {
+	let mut length = text.chars().count();
+	for word in text.split_whitespace() {
+		if word.starts_with("http://") || word.starts_with("https://") {
+			let url = word.trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '\'', '"']);
+			length = length - url.chars().count() + URL_LENGTH;
+		} else if let Some(handle) = word.strip_prefix('@')
+			&& let Some((_, rest)) = handle.split_once('@')
+		{
+			let domain_len = rest.find(|c: char| !c.is_alphanumeric() && c != '.' && c != '-').unwrap_or(rest.len());
+			let domain = rest[..domain_len].trim_end_matches('.');
+			if !domain.is_empty() {
+				length -= 1 + domain.chars().count();
+			}
+		}
+	}
+	length
+}
+
+/// Whether the text @mentions anyone. A direct post without a mention reaches nobody.
+pub fn mentions_someone(text: &str) -> bool {
+	text.split_whitespace().any(|word| {
+		word.trim_start_matches(['(', '[', '"', '\''])
+			.strip_prefix('@')
+			.and_then(|handle| handle.chars().next())
+			.is_some_and(|first| first.is_alphanumeric() || first == '_')
+	})
+}
+
+/// Every `@user@domain` handle written in the text, as `user@domain`, in order and without duplicates. Email addresses
+/// aren't handles, so the leading @ is required.
+pub fn find_handles(text: &str) -> Vec<String> {
+	let mut handles: Vec<String> = Vec::new();
+	for word in text.split_whitespace() {
+		let Some(handle) = word.trim_start_matches(['(', '[', '"', '\'']).strip_prefix('@') else { continue };
+		let Some((user, rest)) = handle.split_once('@') else { continue };
+		let domain_len = rest.find(|c: char| !c.is_alphanumeric() && c != '.' && c != '-').unwrap_or(rest.len());
+		let domain = rest[..domain_len].trim_end_matches(['.', '-']);
+		if user.is_empty()
+			|| !user.chars().all(|c| c.is_alphanumeric() || matches!(c, '_' | '.' | '-'))
+			|| !domain.contains('.')
+		{
+			continue;
+		}
+		let found = format!("{user}@{domain}");
+		if !handles.iter().any(|h| h.eq_ignore_ascii_case(&found)) {
+			handles.push(found);
+		}
+	}
+	handles
+}
+
 fn strip_instance_shortcodes(input: &str) -> String {
 	let chars: Vec<char> = input.chars().collect();
 	let mut output = String::with_capacity(input.len());
@@ -65,7 +128,7 @@ fn normalize_spaces(input: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-	use super::strip_display_name_emojis;
+	use super::{find_handles, mentions_someone, poll_option_title, post_length, strip_display_name_emojis};
 	use crate::config::DisplayNameEmojiMode;
 
 	#[test]
@@ -91,4 +154,44 @@ mod tests {
 		let output = strip_display_name_emojis("Time 10:30 and A:B", DisplayNameEmojiMode::InstanceOnly);
 		assert_eq!(output, "Time 10:30 and A:B");
 	}
+
+	#[test]
+	fn poll_option_titles_follow_emoji_mode() {
+		let title = "Yes :blobcat_yes: 👍";
+		assert_eq!(poll_option_title(title, DisplayNameEmojiMode::None), "Yes :blobcat_yes: 👍");
+		assert_eq!(poll_option_title(title, DisplayNameEmojiMode::UnicodeOnly), "Yes :blobcat_yes:");
+		assert_eq!(poll_option_title(title, DisplayNameEmojiMode::InstanceOnly), "Yes 👍");
+		assert_eq!(poll_option_title(title, DisplayNameEmojiMode::All), "Yes");
+	}
+
+	#[test]
+	fn emoji_only_poll_option_keeps_its_title() {
+		assert_eq!(poll_option_title(":thumbsup:  👍", DisplayNameEmojiMode::All), ":thumbsup: 👍");
+		assert_eq!(poll_option_title(":thumbsup:", DisplayNameEmojiMode::InstanceOnly), ":thumbsup:");
+	}
+
+	#[test]
+	fn post_length_counts_links_and_mentions_like_the_server() {
+		assert_eq!(post_length("héllo"), 5);
+		assert_eq!(post_length("see https://example.com/a/very/long/path/that/goes/on."), 4 + 23 + 1);
+		assert_eq!(post_length("@alice@example.social hi"), "@alice hi".len());
+		assert_eq!(post_length("@bob@example.com."), "@bob.".len());
+		assert_eq!(post_length("@carol hi"), 9);
+	}
+
+	#[test]
+	fn detects_mentions_of_recipients() {
+		assert!(mentions_someone("@alice@example.social hello"));
+		assert!(mentions_someone("hi (@bob)"));
+		assert!(!mentions_someone("email me at bob@example.com"));
+		assert!(!mentions_someone("just an @ sign"));
+	}
+
+	#[test]
+	fn finds_handles_in_plain_text() {
+		let text =
+			"Follow @alice@example.social, (@Bob@mastodon.online) and @alice@Example.social. Mail carol@example.com";
+		assert_eq!(find_handles(text), vec!["alice@example.social", "Bob@mastodon.online"]);
+		assert!(find_handles("@dave and @erin@localhost").is_empty());
+	}
 }
@@ -1,14 +1,40 @@
-use std::time::Instant;
+use std::{
+	borrow::Cow,
+	collections::{BTreeMap, HashMap},
+	time::Instant,
+};
 
 use serde::{Deserialize, Serialize};
 
 use crate::{
-	config::{Config, ContentWarningDisplay, DisplayNameEmojiMode, SortOrder},
-	mastodon::{Account, FilterContext, Notification, SearchType, Status, Tag},
+	config::{Config, ContentWarningDisplay, DisplayNameEmojiMode, NotificationTemplates, SortOrder},
+	mastodon::{Account, FilterContext, List, Notification, SearchType, Status, Tag},
 	streaming::StreamHandle,
 	template::{DEFAULT_BOOST_TEMPLATE, DEFAULT_POST_TEMPLATE, DEFAULT_QUOTE_TEMPLATE},
 };
 
+/// Which slice of the public timeline a media-only timeline shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PublicScope {
+	Local,
+	Federated,
+	Remote,
+}
+
+impl PublicScope {
+	pub const fn all() -> &'static [Self] {
+		&[Self::Local, Self::Federated, Self::Remote]
+	}
+
+	pub const fn display_name(self) -> &'static str {
+		match self {
+			Self::Local => "Local",
+			Self::Federated => "Federated",
+			Self::Remote => "Remote",
+		}
+	}
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TimelineType {
 	Home,
@@ -18,6 +44,8 @@ pub enum TimelineType {
 	Local,
 	InstanceLocal { instance: String },
 	Federated,
+	Remote,
+	Media { scope: PublicScope },
 	Bookmarks,
 	Favorites,
 	User { id: String, name: String },
@@ -37,6 +65,8 @@ impl TimelineType {
 			Self::Local => "Local".to_string(),
 			Self::InstanceLocal { instance } => format!("Local ({instance})"),
 			Self::Federated => "Federated".to_string(),
+			Self::Remote => "Remote".to_string(),
+			Self::Media { scope } => format!("{} Media", scope.display_name()),
 			Self::Bookmarks => "Bookmarks".to_string(),
 			Self::Favorites => "Favorites".to_string(),
 			Self::User { name, .. } | Self::Thread { name, .. } => name.clone(),
@@ -46,13 +76,37 @@ impl TimelineType {
 		}
 	}
 
+	/// Like `display_name`, but list timelines use the cached list's current title and note its configuration.
+	pub fn display_name_with_lists(&self, lists: &[List]) -> String {
+		let Self::List { id, .. } = self else { return self.display_name() };
+		let Some(list) = lists.iter().find(|list| list.id == *id) else { return self.display_name() };
+		let mut flags = Vec::new();
+		if list.exclusive {
+			flags.push("excl");
+		}
+		match list.replies_policy.as_deref() {
+			Some("list") => flags.push("list replies"),
+			Some("none") => flags.push("no replies"),
+			_ => {}
+		}
+		if flags.is_empty() {
+			format!("List: {}", list.title)
+		} else {
+			format!("List: {} [{}]", list.title, flags.join(", "))
+		}
+	}
+
 	pub const fn filter_context(&self) -> FilterContext {
 		match self {
 			Self::Home | Self::List { .. } => FilterContext::Home,
 			Self::Notifications | Self::Mentions => FilterContext::Notifications,
-			Self::Local | Self::Federated | Self::Search { .. } | Self::Hashtag { .. } | Self::InstanceLocal { .. } => {
-				FilterContext::Public
-			}
+			Self::Local
+			| Self::Federated
+			| Self::Remote
+			| Self::Media { .. }
+			| Self::Search { .. }
+			| Self::Hashtag { .. }
+			| Self::InstanceLocal { .. } => FilterContext::Public,
 			Self::Thread { .. } => FilterContext::Thread,
 			Self::User { .. } => FilterContext::Account,
 			Self::Direct | Self::Bookmarks | Self::Favorites => FilterContext::Unknown,
@@ -64,8 +118,9 @@ impl TimelineType {
 			Self::Home => "api/v1/timelines/home".to_string(),
 			Self::Notifications | Self::Mentions => "api/v1/notifications".to_string(),
 			Self::Direct => "api/v1/conversations".to_string(),
-			Self::Local | Self::Federated => "api/v1/timelines/public".to_string(),
-			Self::InstanceLocal { instance } => format!("https://{instance}/api/v1/timelines/public"),
+			Self::Local | Self::Federated | Self::Remote | Self::Media { .. } | Self::InstanceLocal { .. } => {
+				"api/v1/timelines/public".to_string()
+			}
 			Self::Bookmarks => "api/v1/bookmarks".to_string(),
 			Self::Favorites => "api/v1/favourites".to_string(),
 			Self::User { id, .. } => format!("api/v1/accounts/{id}/statuses"),
@@ -79,6 +134,10 @@ impl TimelineType {
 	pub fn api_query_params(&self) -> Vec<(&str, &str)> {
 		match self {
 			Self::Local | Self::InstanceLocal { .. } => vec![("local", "true")],
+			Self::Remote => vec![("remote", "true")],
+			Self::Media { scope: PublicScope::Local } => vec![("local", "true"), ("only_media", "true")],
+			Self::Media { scope: PublicScope::Federated } => vec![("only_media", "true")],
+			Self::Media { scope: PublicScope::Remote } => vec![("remote", "true"), ("only_media", "true")],
 			Self::Mentions => vec![("types[]", "mention")],
 			_ => vec![],
 		}
@@ -89,6 +148,15 @@ impl TimelineType {
 			Self::Direct => Some(vec![("stream", "direct".to_string())]),
 			Self::Local => Some(vec![("stream", "public:local".to_string())]),
 			Self::Federated => Some(vec![("stream", "public".to_string())]),
+			Self::Remote => Some(vec![("stream", "public:remote".to_string())]),
+			Self::Media { scope } => {
+				let stream = match scope {
+					PublicScope::Local => "public:local:media",
+					PublicScope::Federated => "public:media",
+					PublicScope::Remote => "public:remote:media",
+				};
+				Some(vec![("stream", stream.to_string())])
+			}
 			Self::List { id, .. } => Some(vec![("stream", "list".to_string()), ("list", id.clone())]),
 			Self::Mentions
 			| Self::Bookmarks
@@ -108,6 +176,21 @@ impl TimelineType {
 		}
 	}
 
+	/// Timelines opened to look at one thing, such as a user, thread, list, or search, as opposed to the built-in ones.
+	pub const fn is_secondary(&self) -> bool {
+		matches!(
+			self,
+			Self::User { .. }
+				| Self::Thread { .. }
+				| Self::List { .. }
+				| Self::Search { .. }
+				| Self::Hashtag { .. }
+				| Self::InstanceLocal { .. }
+				| Self::Remote
+				| Self::Media { .. }
+		)
+	}
+
 	pub const fn supports_paging(&self) -> bool {
 		!matches!(self, Self::Thread { .. })
 	}
@@ -118,8 +201,8 @@ impl TimelineType {
 			Self::List { .. } => "List Timelines",
 			Self::Notifications | Self::Mentions => "Notifications",
 			Self::Direct => "Direct Messages",
-			Self::Local | Self::InstanceLocal { .. } => "Local",
-			Self::Federated => "Federated",
+			Self::Local | Self::InstanceLocal { .. } | Self::Media { scope: PublicScope::Local } => "Local",
+			Self::Federated | Self::Remote | Self::Media { .. } => "Federated",
 			Self::Bookmarks => "Bookmarks",
 			Self::Favorites => "Favorites",
 			Self::User { .. } => "User Timelines",
@@ -136,6 +219,17 @@ pub enum TimelineEntry {
 	Notification(Box<Notification>),
 	Account(Account),
 	Hashtag(Tag),
+	MoreReplies(MoreReplies),
+}
+
+/// A row in a thread standing in for replies to a post that the thread hasn't loaded, after the ones it has.
+#[derive(Debug, Clone)]
+pub struct MoreReplies {
+	/// Unique among the thread's entries, so the row can be selected like a post.
+	pub id: String,
+	pub parent_id: String,
+	pub parent_acct: String,
+	pub count: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -146,10 +240,42 @@ pub struct TimelineTextOptions {
 	pub post_template: String,
 	pub boost_template: String,
 	pub quote_template: String,
+	pub notification_templates: NotificationTemplates,
 	pub filter_context: FilterContext,
+	/// Whether the active account follows each author, keyed by account ID. `None` hides follow markers.
+	pub follow_states: Option<HashMap<String, bool>>,
+	/// Leads each post with its attachments and their descriptions, for media-only timelines.
+	pub media_first: bool,
+	/// Starts each row that has attachments with their types and counts, e.g. "[2 images, 1 video]".
+	pub media_indicator: bool,
+	/// The post a thread was opened from, marked so it can be told apart from its ancestors and replies.
+	pub thread_focus_id: Option<String>,
+	/// Keeps descriptions of sensitive or blur-filtered media behind the expand toggle.
+	pub hide_sensitive_media: bool,
+	/// Rows mentioning this account are marked. `None` when marking is off, and in notification timelines where every
+	/// mention already stands out.
+	pub mention_account: Option<crate::config::Account>,
+	/// Direct replies each post in a thread has loaded, keyed by post ID, so reply counts match what is shown. `None`
+	/// outside threads, where the server's counts are used.
+	pub loaded_replies: Option<HashMap<String, u64>>,
+	/// Replies each post in a thread has that filters left out, keyed by post ID, so they aren't reported as unloaded.
+	pub filtered_replies: HashMap<String, u64>,
+	/// Longest row the list shows before cutting it short. `None` shows rows in full. The detail pane ignores it.
+	pub row_length: Option<usize>,
 }
 
 impl TimelineTextOptions {
+	/// These options with reply counts taken from `timeline` when it is a thread, or unchanged otherwise.
+	pub fn with_thread_replies(&self, timeline: &Timeline) -> Cow<'_, Self> {
+		if self.thread_focus_id.is_none() {
+			return Cow::Borrowed(self);
+		}
+		let mut options = self.clone();
+		options.loaded_replies = Some(loaded_reply_counts(&timeline.entries));
+		options.filtered_replies = timeline.filtered_reply_counts();
+		Cow::Owned(options)
+	}
+
 	pub fn from_config(config: &Config, timeline_type: &TimelineType) -> Self {
 		let key = timeline_type.template_key();
 		Self {
@@ -159,7 +285,26 @@ impl TimelineTextOptions {
 			post_template: config.templates.resolve_post_template(key).to_string(),
 			boost_template: config.templates.resolve_boost_template(key).to_string(),
 			quote_template: config.templates.resolve_quote_template(key).to_string(),
+			notification_templates: config.notification_templates.clone(),
 			filter_context: timeline_type.filter_context(),
+			follow_states: None,
+			media_first: matches!(timeline_type, TimelineType::Media { .. }),
+			media_indicator: config.show_media_indicator,
+			thread_focus_id: match timeline_type {
+				TimelineType::Thread { id, .. } => Some(id.clone()),
+				_ => None,
+			},
+			hide_sensitive_media: config.hide_sensitive_media,
+			mention_account: if config.mark_mentions_of_me
+				&& !matches!(timeline_type, TimelineType::Notifications | TimelineType::Mentions)
+			{
+				config.active_account().cloned()
+			} else {
+				None
+			},
+			loaded_replies: None,
+			filtered_replies: HashMap::new(),
+			row_length: config.row_length(),
 		}
 	}
 
@@ -171,11 +316,84 @@ impl TimelineTextOptions {
 			post_template: DEFAULT_POST_TEMPLATE.to_string(),
 			boost_template: DEFAULT_BOOST_TEMPLATE.to_string(),
 			quote_template: DEFAULT_QUOTE_TEMPLATE.to_string(),
+			notification_templates: config.notification_templates.clone(),
 			filter_context: FilterContext::Unknown,
+			follow_states: None,
+			media_first: false,
+			media_indicator: config.show_media_indicator,
+			thread_focus_id: None,
+			hide_sensitive_media: config.hide_sensitive_media,
+			mention_account: None,
+			loaded_replies: None,
+			filtered_replies: HashMap::new(),
+			row_length: config.row_length(),
 		}
 	}
 }
 
+/// A thread's `entries`, stored newest first as `order_thread` leaves them, with a `MoreReplies` row after the loaded
+/// replies of each post that has more on the server. Posts in `fetched` have had all their replies fetched already, and
+/// replies counted in `filtered` were left out by filters, so loading them again wouldn't show them.
+pub fn with_more_replies_rows(
+	entries: Vec<TimelineEntry>,
+	fetched: &[String],
+	filtered: &HashMap<String, u64>,
+) -> Vec<TimelineEntry> {
+	let mut display: Vec<TimelineEntry> =
+		entries.into_iter().rev().filter(|entry| !matches!(entry, TimelineEntry::MoreReplies(_))).collect();
+	let loaded = loaded_reply_counts(&display);
+	let mut depths: HashMap<String, usize> = HashMap::new();
+	let mut rows: Vec<(usize, MoreReplies)> = Vec::new();
+	let mut open: Vec<(usize, MoreReplies)> = Vec::new();
+	for (index, status) in display.iter().enumerate().filter_map(|(i, e)| e.as_status().map(|s| (i, s))) {
+		let depth = status.in_reply_to_id.as_ref().and_then(|parent| depths.get(parent)).map_or(0, |depth| depth + 1);
+		depths.insert(status.id.clone(), depth);
+		// Replies come right after their parent, so a row is due once the order returns to the parent's depth.
+		while open.last().is_some_and(|(open_depth, _)| *open_depth >= depth) {
+			if let Some((_, row)) = open.pop() {
+				rows.push((index, row));
+			}
+		}
+		let count = unloaded_reply_count(status, &loaded, filtered);
+		if count > 0 && !fetched.contains(&status.id) {
+			let row = MoreReplies {
+				id: more_replies_id(&status.id),
+				parent_id: status.id.clone(),
+				parent_acct: status.account.acct.clone(),
+				count,
+			};
+			open.push((depth, row));
+		}
+	}
+	let end = display.len();
+	rows.extend(open.into_iter().rev().map(|(_, row)| (end, row)));
+	for (index, row) in rows.into_iter().rev() {
+		display.insert(index, TimelineEntry::MoreReplies(row));
+	}
+	display.reverse();
+	display
+}
+
+/// The ID of the `MoreReplies` row for replies to `parent_id`.
+pub fn more_replies_id(parent_id: &str) -> String {
+	format!("more-replies-{parent_id}")
+}
+
+/// How many direct replies each post has among `entries`, keyed by the ID of the post replied to.
+pub fn loaded_reply_counts(entries: &[TimelineEntry]) -> HashMap<String, u64> {
+	let mut counts = HashMap::new();
+	for parent in entries.iter().filter_map(TimelineEntry::as_status).filter_map(|s| s.in_reply_to_id.as_deref()) {
+		*counts.entry(parent.to_string()).or_insert(0) += 1;
+	}
+	counts
+}
+
+/// How many of `status`'s replies on the server are neither among the `loaded` ones nor `filtered` out.
+fn unloaded_reply_count(status: &Status, loaded: &HashMap<String, u64>, filtered: &HashMap<String, u64>) -> u64 {
+	let known = loaded.get(&status.id).copied().unwrap_or(0) + filtered.get(&status.id).copied().unwrap_or(0);
+	status.replies_count.saturating_sub(known)
+}
+
 impl TimelineEntry {
 	pub const fn id(&self) -> &str {
 		match self {
@@ -183,10 +401,82 @@ impl TimelineEntry {
 			Self::Notification(notification) => notification.id.as_str(),
 			Self::Account(account) => account.id.as_str(),
 			Self::Hashtag(tag) => tag.name.as_str(),
+			Self::MoreReplies(more) => more.id.as_str(),
+		}
+	}
+
+	/// The account whose follow state is shown for this entry: a post's original author or a notification's actor.
+	pub fn author_id(&self) -> Option<&str> {
+		match self {
+			Self::Status(status) => Some(status.reblog.as_deref().unwrap_or(status).account.id.as_str()),
+			Self::Notification(notification) => Some(notification.account.id.as_str()),
+			Self::Account(_) | Self::Hashtag(_) | Self::MoreReplies(_) => None,
+		}
+	}
+
+	/// The account an entry is about: a post's original author, a notification's actor, or an account result.
+	pub fn author(&self) -> Option<&Account> {
+		match self {
+			Self::Status(status) => Some(&status.reblog.as_deref().unwrap_or(status).account),
+			Self::Notification(notification) => Some(&notification.account),
+			Self::Account(account) => Some(account),
+			Self::Hashtag(_) | Self::MoreReplies(_) => None,
+		}
+	}
+
+	/// The post whose attachments a row describes: a boost's original post, or a notification's post.
+	fn media_status(&self) -> Option<&Status> {
+		match self {
+			Self::Status(status) => Some(status.reblog.as_deref().unwrap_or(status)),
+			Self::Notification(notification) => notification.status.as_deref(),
+			Self::Account(_) | Self::Hashtag(_) | Self::MoreReplies(_) => None,
 		}
 	}
 
 	pub fn display_text(&self, options: &TimelineTextOptions, cw_expanded: bool) -> String {
+		let mut text = self.entry_text(options, cw_expanded);
+		if options.media_indicator
+			&& !options.media_first
+			&& let Some(indicator) = self.media_status().and_then(Status::media_indicator)
+		{
+			text = format!("{indicator} {text}");
+		}
+		if options.mention_account.as_ref().is_some_and(|account| self.mentions(account)) {
+			text = format!("[mentions you] {text}");
+		}
+		if options.thread_focus_id.as_deref() == Some(self.id()) {
+			text = format!("[focused] {text}");
+		}
+		let following =
+			options.follow_states.as_ref().and_then(|states| self.author_id().and_then(|id| states.get(id)).copied());
+		match following {
+			Some(true) => format!("[following] {text}"),
+			Some(false) => format!("[not following] {text}"),
+			None => text,
+		}
+	}
+
+	/// The entry laid out in full for the detail pane. Posts, and notifications about them, show everything about the
+	/// post; other entries show their row text.
+	pub fn detail_text(&self, options: &TimelineTextOptions, cw_expanded: bool) -> String {
+		match self {
+			Self::Status(status) => status.detail_text(options, cw_expanded),
+			Self::Notification(notification) => match notification.status.as_deref() {
+				Some(status) if matches!(notification.kind.as_str(), "mention" | "status") => {
+					status.detail_text(options, cw_expanded)
+				}
+				Some(status) => format!(
+					"{}\n\n{}",
+					self.display_text(options, cw_expanded),
+					status.detail_text(options, cw_expanded)
+				),
+				None => self.display_text(options, cw_expanded),
+			},
+			Self::Account(_) | Self::Hashtag(_) | Self::MoreReplies(_) => self.display_text(options, cw_expanded),
+		}
+	}
+
+	fn entry_text(&self, options: &TimelineTextOptions, cw_expanded: bool) -> String {
 		match self {
 			Self::Status(status) => status.timeline_display(
 				options,
@@ -205,6 +495,10 @@ impl TimelineEntry {
 				let following_str = if tag.following { "following" } else { "not following" };
 				format!("[Hashtag] #{} ({})", tag.name, following_str)
 			}
+			Self::MoreReplies(more) => {
+				let noun = if more.count == 1 { "reply" } else { "replies" };
+				format!("[Load {} more {noun} to @{}]", more.count, more.parent_acct)
+			}
 		}
 	}
 
@@ -212,7 +506,7 @@ impl TimelineEntry {
 		match self {
 			Self::Status(status) => Some(status),
 			Self::Notification(notification) => notification.status.as_deref(),
-			Self::Account(_) | Self::Hashtag(_) => None,
+			Self::Account(_) | Self::Hashtag(_) | Self::MoreReplies(_) => None,
 		}
 	}
 
@@ -220,10 +514,17 @@ impl TimelineEntry {
 		match self {
 			Self::Status(status) => Some(status),
 			Self::Notification(notification) => notification.status.as_deref_mut(),
-			Self::Account(_) | Self::Hashtag(_) => None,
+			Self::Account(_) | Self::Hashtag(_) | Self::MoreReplies(_) => None,
 		}
 	}
 
+	/// Whether the post, or the post it boosts, mentions `account`.
+	pub fn mentions(&self, account: &crate::config::Account) -> bool {
+		self.as_status().is_some_and(|status| {
+			status.reblog.as_deref().unwrap_or(status).mentions.iter().any(|mention| mention.is_self(account))
+		})
+	}
+
 	pub fn matches_query(&self, query: &str) -> bool {
 		let query = query.to_lowercase();
 		match self {
@@ -243,6 +544,7 @@ impl TimelineEntry {
 					|| account.acct.to_lowercase().contains(&query)
 			}
 			Self::Hashtag(tag) => tag.name.to_lowercase().contains(&query),
+			Self::MoreReplies(_) => false,
 		}
 	}
 
@@ -281,9 +583,28 @@ pub struct Timeline {
 	pub loading_more_in_background: bool,
 	pub last_load_attempt: Option<Instant>,
 	pub next_max_id: Option<String>,
+	/// Set once loading more returned nothing new, so further attempts don't hit the network until a refresh.
+	pub end_reached: bool,
+	/// Set once a first page has arrived, so an empty list can be told apart from one that is still loading or failed.
+	pub loaded: bool,
 	pub find_query: Option<String>,
 	pub pending_find_next: bool,
 	pub pending_find_prev: bool,
+	pub bookmark_tag_filter: Option<String>,
+	pub stream_down_since: Option<Instant>,
+	pub stream_outage_announced: bool,
+	/// Reopened from the previous session, so it is closed quietly if what it shows no longer exists.
+	pub restored: bool,
+	/// IDs of direct conversations with messages not yet viewed. Only used by the Direct Messages timeline.
+	pub unread_conversations: Vec<String>,
+	/// Keeps the selection on the newest post as live updates arrive, until the user moves away from it.
+	pub follow_live: bool,
+	/// IDs of thread posts whose replies have all been fetched, so loading more isn't offered for them again. Only used
+	/// by thread timelines.
+	pub fetched_replies: Vec<String>,
+	/// Thread replies that filters left out, keyed by reply ID, with the ID of the post each one replies to. Only used
+	/// by thread timelines.
+	pub filtered_replies: BTreeMap<String, String>,
 }
 
 impl Timeline {
@@ -306,18 +627,84 @@ impl Timeline {
 			loading_more_in_background: false,
 			last_load_attempt: None,
 			next_max_id: None,
+			end_reached: false,
+			loaded: false,
 			find_query: None,
 			pending_find_next: false,
 			pending_find_prev: false,
+			bookmark_tag_filter: None,
+			stream_down_since: None,
+			stream_outage_announced: false,
+			restored: false,
+			unread_conversations: Vec::new(),
+			follow_live: false,
+			fetched_replies: Vec::new(),
+			filtered_replies: BTreeMap::new(),
+		}
+	}
+
+	/// Whether a post in a thread has more direct replies on the server than the thread has loaded or filtered out.
+	pub fn has_unloaded_replies(&self, status: &Status) -> bool {
+		if !matches!(self.timeline_type, TimelineType::Thread { .. }) || self.fetched_replies.contains(&status.id) {
+			return false;
+		}
+		unloaded_reply_count(status, &loaded_reply_counts(&self.entries), &self.filtered_reply_counts()) > 0
+	}
+
+	/// Remembers `replies` as left out of this thread by filters.
+	pub fn record_filtered_replies<'a>(&mut self, replies: impl IntoIterator<Item = &'a Status>) {
+		for reply in replies {
+			if let Some(parent) = &reply.in_reply_to_id {
+				self.filtered_replies.insert(reply.id.clone(), parent.clone());
+			}
 		}
 	}
 
+	/// How many replies filters left out of this thread, keyed by the ID of the post replied to.
+	pub fn filtered_reply_counts(&self) -> HashMap<String, u64> {
+		let mut counts = HashMap::new();
+		for parent in self.filtered_replies.values() {
+			*counts.entry(parent.clone()).or_insert(0) += 1;
+		}
+		counts
+	}
+
 	pub fn find_next(&self, start_index: usize, config: &Config) -> Option<usize> {
 		let query = self.find_query.as_ref()?;
-		if self.entries.is_empty() {
-			return None;
+		self.next_matching(start_index, config, |entry| entry.matches_query(query))
+	}
+
+	/// Moves the selection to the newest entry, wherever the sort order displays it.
+	pub fn select_newest(&mut self) {
+		if let Some(newest) = self.entries.first() {
+			self.selected_id = Some(newest.id().to_string());
+			self.selected_index = None;
 		}
+	}
 
+	/// Marks a conversation as viewed, returning false if it wasn't unread.
+	pub fn mark_conversation_read(&mut self, conversation_id: &str) -> bool {
+		let before = self.unread_conversations.len();
+		self.unread_conversations.retain(|id| id != conversation_id);
+		self.unread_conversations.len() != before
+	}
+
+	/// The next entry at or after `start_index` in display order that mentions `account`.
+	pub fn next_mention_of(
+		&self,
+		start_index: usize,
+		config: &Config,
+		account: &crate::config::Account,
+	) -> Option<usize> {
+		self.next_matching(start_index, config, |entry| entry.mentions(account))
+	}
+
+	fn next_matching(
+		&self,
+		start_index: usize,
+		config: &Config,
+		matches: impl Fn(&TimelineEntry) -> bool,
+	) -> Option<usize> {
 		let effective_sort_order = self.effective_sort_order(config);
 		let len = self.entries.len();
 		for visual_index in start_index..len {
@@ -325,9 +712,7 @@ impl Timeline {
 				SortOrder::NewestToOldest => visual_index,
 				SortOrder::OldestToNewest => len - 1 - visual_index,
 			};
-			if let Some(entry) = self.entries.get(entry_index)
-				&& entry.matches_query(query)
-			{
+			if self.entries.get(entry_index).is_some_and(&matches) {
 				return Some(entry_index);
 			}
 		}
@@ -433,6 +818,43 @@ impl TimelineManager {
 		false
 	}
 
+	/// Closes every secondary timeline that isn't pinned and focuses Home, returning how many were closed. If only
+	/// secondary timelines are open, the active one is kept so at least one remains.
+	pub fn close_secondary(&mut self, pinned: &[TimelineType]) -> usize {
+		let before = self.timelines.len();
+		let keep = self
+			.timelines
+			.iter()
+			.all(|t| t.timeline_type.is_secondary())
+			.then(|| self.active().map(|t| t.timeline_type.clone()))
+			.flatten();
+		self.timelines.retain(|t| {
+			!t.timeline_type.is_secondary()
+				|| pinned.contains(&t.timeline_type)
+				|| keep.as_ref() == Some(&t.timeline_type)
+		});
+		let closed = before - self.timelines.len();
+		if closed > 0 {
+			let timelines = &self.timelines;
+			let is_open = |timeline_type: &TimelineType| timelines.iter().any(|t| t.timeline_type == *timeline_type);
+			self.history.retain(is_open);
+			if self.last_focused.as_ref().is_some_and(|t| !is_open(t)) {
+				self.last_focused = None;
+			}
+			self.active_index = self.timelines.iter().position(|t| t.timeline_type == TimelineType::Home).unwrap_or(0);
+		}
+		closed
+	}
+
+	/// Moves pinned timelines to the front in the order given, keeping the rest in their current order.
+	pub fn apply_pins(&mut self, pinned: &[TimelineType]) {
+		let active = self.active().map(|t| t.timeline_type.clone());
+		self.timelines.sort_by_key(|t| pinned.iter().position(|p| *p == t.timeline_type).unwrap_or(pinned.len()));
+		if let Some(index) = active.and_then(|active| self.index_of(&active)) {
+			self.active_index = index;
+		}
+	}
+
 	pub fn active(&self) -> Option<&Timeline> {
 		self.timelines.get(self.active_index)
 	}
@@ -472,8 +894,17 @@ impl TimelineManager {
 		self.timelines.iter_mut()
 	}
 
-	pub fn display_names(&self) -> Vec<String> {
-		self.timelines.iter().map(|t| t.timeline_type.display_name()).collect()
+	pub fn display_names(&self, lists: &[List]) -> Vec<String> {
+		self.timelines
+			.iter()
+			.map(|t| {
+				let name = t.timeline_type.display_name_with_lists(lists);
+				match t.unread_conversations.len() {
+					0 => name,
+					unread => format!("{name} ({unread} unread)"),
+				}
+			})
+			.collect()
 	}
 
 	pub fn open_timeline_types(&self) -> Vec<TimelineType> {
@@ -534,3 +965,124 @@ impl Default for TimelineManager {
 		Self::new()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashMap;
+
+	use serde_json::json;
+
+	use super::{
+		Timeline, TimelineEntry, TimelineTextOptions, TimelineType, loaded_reply_counts, with_more_replies_rows,
+	};
+	use crate::{config::Config, mastodon::Status};
+
+	fn thread_status(id: &str, in_reply_to_id: Option<&str>, replies_count: u64) -> Status {
+		serde_json::from_value(json!({
+			"id": id,
+			"content": "<p>Hello</p>",
+			"created_at": "2026-01-01T00:00:00.000Z",
+			"account": {"id": "1", "username": "alice", "acct": "alice", "display_name": "Alice", "url": "https://example.com/@alice"},
+			"visibility": "public",
+			"reblogs_count": 0,
+			"favourites_count": 0,
+			"replies_count": replies_count,
+			"in_reply_to_id": in_reply_to_id,
+		}))
+		.unwrap()
+	}
+
+	/// A root with two branches: B has loaded a reply chain, C's replies were never fetched, and A's server count
+	/// includes a reply that isn't in the thread.
+	fn branching_thread() -> Vec<TimelineEntry> {
+		[
+			thread_status("A", None, 3),
+			thread_status("B", Some("A"), 1),
+			thread_status("D", Some("B"), 1),
+			thread_status("E", Some("D"), 0),
+			thread_status("C", Some("A"), 2),
+		]
+		.into_iter()
+		.map(|status| TimelineEntry::Status(Box::new(status)))
+		.collect()
+	}
+
+	fn thread_timeline(entries: Vec<TimelineEntry>) -> Timeline {
+		let mut timeline = Timeline::new(TimelineType::Thread { id: "A".to_string(), name: "Thread".to_string() });
+		timeline.entries = entries;
+		timeline
+	}
+
+	fn rows(timeline: &Timeline) -> Vec<String> {
+		let options = TimelineTextOptions::from_config(&Config::default(), &timeline.timeline_type);
+		let options = options.with_thread_replies(timeline);
+		timeline.entries.iter().map(|entry| entry.display_text(&options, false)).collect()
+	}
+
+	fn status<'a>(timeline: &'a Timeline, id: &str) -> &'a Status {
+		timeline.entries.iter().find_map(|entry| entry.as_status().filter(|status| status.id == id)).unwrap()
+	}
+
+	#[test]
+	fn loaded_reply_counts_follow_each_branch() {
+		let counts = loaded_reply_counts(&branching_thread());
+		assert_eq!(counts.get("A"), Some(&2));
+		assert_eq!(counts.get("B"), Some(&1));
+		assert_eq!(counts.get("D"), Some(&1));
+		assert_eq!(counts.get("C"), None);
+		assert_eq!(counts.get("E"), None);
+	}
+
+	#[test]
+	fn thread_rows_mark_unloaded_replies() {
+		let timeline = thread_timeline(branching_thread());
+		let rows = rows(&timeline);
+		assert!(rows[0].contains("2 replies, 1 more not loaded"));
+		assert!(rows[1].contains("1 reply") && !rows[1].contains("not loaded"));
+		assert!(rows[2].contains("1 reply") && !rows[2].contains("not loaded"));
+		assert!(!rows[3].contains("repl"));
+		assert!(rows[4].contains("2 replies not loaded"));
+		assert!(timeline.has_unloaded_replies(status(&timeline, "A")));
+		assert!(!timeline.has_unloaded_replies(status(&timeline, "B")));
+		assert!(timeline.has_unloaded_replies(status(&timeline, "C")));
+	}
+
+	#[test]
+	fn filtered_replies_are_not_offered_for_loading() {
+		let mut timeline = thread_timeline(branching_thread());
+		let hidden = [thread_status("F", Some("A"), 0), thread_status("G", Some("C"), 0)];
+		timeline.record_filtered_replies(&hidden);
+		timeline.record_filtered_replies(&hidden);
+		let rows = rows(&timeline);
+		assert!(rows[0].contains("2 replies, 1 hidden by filters") && !rows[0].contains("not loaded"));
+		assert!(rows[4].contains("1 reply hidden by filters, 1 more not loaded"));
+		assert!(!timeline.has_unloaded_replies(status(&timeline, "A")));
+		assert!(timeline.has_unloaded_replies(status(&timeline, "C")));
+		let stored: Vec<_> = timeline.entries.iter().rev().cloned().collect();
+		let with_rows = with_more_replies_rows(stored, &[], &timeline.filtered_reply_counts());
+		let ids: Vec<&str> = with_rows.iter().rev().map(TimelineEntry::id).collect();
+		assert_eq!(ids, ["A", "B", "D", "E", "C", "more-replies-C"]);
+	}
+
+	#[test]
+	fn more_replies_rows_follow_each_branch() {
+		let stored: Vec<_> = branching_thread().into_iter().rev().collect();
+		let ids = |entries: Vec<TimelineEntry>| -> Vec<String> {
+			entries.iter().rev().map(|entry| entry.id().to_string()).collect()
+		};
+		let rows = with_more_replies_rows(stored, &[], &HashMap::new());
+		assert_eq!(ids(rows.clone()), ["A", "B", "D", "E", "C", "more-replies-C", "more-replies-A"]);
+		let again = with_more_replies_rows(rows, &["C".to_string()], &HashMap::new());
+		assert_eq!(ids(again), ["A", "B", "D", "E", "C", "more-replies-A"]);
+	}
+
+	#[test]
+	fn reply_counts_outside_threads_come_from_server() {
+		let timeline = thread_timeline(branching_thread());
+		let options = TimelineTextOptions::from_config_default(&Config::default());
+		let options = options.with_thread_replies(&timeline);
+		assert!(options.loaded_replies.is_none());
+		let row = timeline.entries[0].display_text(&options, false);
+		assert!(row.contains("3 replies") && !row.contains("not loaded"));
+	}
+}
@@ -1,6 +1,7 @@
 pub mod app_shell;
 pub mod dialogs;
 pub mod ids;
+pub mod live_region;
 pub mod menu;
 pub mod timeline_list;
 pub mod timeline_view;
@@ -15,11 +15,19 @@ pub struct HotkeyHandle {
 	pub(crate) join_handle: JoinHandle<()>,
 }
 
+#[cfg(target_os = "windows")]
+pub struct PowerSubclass {
+	hwnd: *mut std::ffi::c_void,
+	ui_tx_ptr: usize,
+}
+
 pub struct AppShell {
 	pub(crate) tray_menu: RefCell<Option<Menu>>,
 	pub(crate) taskbar: TaskBarIcon,
 	#[cfg(target_os = "windows")]
 	pub(crate) hotkey_handle: Rc<RefCell<Option<HotkeyHandle>>>,
+	#[cfg(target_os = "windows")]
+	pub(crate) power_subclass: RefCell<Option<PowerSubclass>>,
 }
 
 impl AppShell {
@@ -64,10 +72,14 @@ impl AppShell {
 			// Blocking here during close can hang the UI thread.
 			drop(handle.join_handle);
 		}
+		#[cfg(target_os = "windows")]
+		if let Some(subclass) = self.power_subclass.borrow_mut().take() {
+			remove_power_listener(subclass);
+		}
 	}
 }
 
-pub fn install_app_shell(_frame: &Frame, ui_tx: UiCommandSender, hotkey: &HotkeyConfig) -> AppShell {
+pub fn install_app_shell(frame: &Frame, ui_tx: UiCommandSender, hotkey: &HotkeyConfig) -> AppShell {
 	let mut tray_menu = Menu::builder()
 		.append_item(ID_TRAY_TOGGLE, "Show/Hide", "Show or hide Fedra")
 		.append_separator()
@@ -92,12 +104,16 @@ pub fn install_app_shell(_frame: &Frame, ui_tx: UiCommandSender, hotkey: &Hotkey
 		_ => {}
 	});
 	#[cfg(target_os = "windows")]
+	let power_subclass = RefCell::new(install_power_listener(frame, ui_tx.clone()));
+	#[cfg(target_os = "windows")]
 	let hotkey_handle = Rc::new(RefCell::new(start_hotkey_listener(ui_tx, hotkey)));
 	AppShell {
 		tray_menu: RefCell::new(Some(tray_menu)),
 		taskbar,
 		#[cfg(target_os = "windows")]
 		hotkey_handle,
+		#[cfg(target_os = "windows")]
+		power_subclass,
 	}
 }
 
@@ -122,7 +138,7 @@ pub fn toggle_window_visibility(frame: &Frame, tray_hidden: &Cell<bool>) {
 	}
 }
 
-fn is_window_active(frame: &Frame) -> bool {
+pub fn is_window_active(frame: &Frame) -> bool {
 	#[cfg(target_os = "windows")]
 	{
 		use windows::Win32::{Foundation::HWND, UI::WindowsAndMessaging::GetForegroundWindow};
@@ -192,6 +208,57 @@ pub fn start_hotkey_listener(ui_tx: UiCommandSender, hotkey: &HotkeyConfig) -> O
 	Some(HotkeyHandle { thread_id, join_handle })
 }
 
+#[cfg(target_os = "windows")]
+const POWER_SUBCLASS_ID: usize = 0xFED4;
+
+/// Watches the main window for resume-from-sleep broadcasts, since sockets are often dead after a resume.
+#[cfg(target_os = "windows")]
+fn install_power_listener(frame: &Frame, ui_tx: UiCommandSender) -> Option<PowerSubclass> {
+	use windows::Win32::{Foundation::HWND, UI::Shell::SetWindowSubclass};
+	let hwnd = frame.get_handle();
+	if hwnd.is_null() {
+		return None;
+	}
+	let ui_tx_ptr = Box::into_raw(Box::new(ui_tx)) as usize;
+	let installed = unsafe { SetWindowSubclass(HWND(hwnd), Some(power_subclass_proc), POWER_SUBCLASS_ID, ui_tx_ptr) };
+	if installed.as_bool() {
+		Some(PowerSubclass { hwnd, ui_tx_ptr })
+	} else {
+		drop(unsafe { Box::from_raw(ui_tx_ptr as *mut UiCommandSender) });
+		None
+	}
+}
+
+#[cfg(target_os = "windows")]
+fn remove_power_listener(subclass: PowerSubclass) {
+	use windows::Win32::{Foundation::HWND, UI::Shell::RemoveWindowSubclass};
+	unsafe {
+		let _ = RemoveWindowSubclass(HWND(subclass.hwnd), Some(power_subclass_proc), POWER_SUBCLASS_ID);
+		drop(Box::from_raw(subclass.ui_tx_ptr as *mut UiCommandSender));
+	}
+}
+
+#[cfg(target_os = "windows")]
+unsafe extern "system" fn power_subclass_proc(
+	hwnd: windows::Win32::Foundation::HWND,
+	msg: u32,
+	wparam: windows::Win32::Foundation::WPARAM,
+	lparam: windows::Win32::Foundation::LPARAM,
+	_id: usize,
+	ref_data: usize,
+) -> windows::Win32::Foundation::LRESULT {
+	use windows::Win32::UI::{
+		Shell::DefSubclassProc,
+		WindowsAndMessaging::{PBT_APMRESUMEAUTOMATIC, PBT_APMRESUMESUSPEND, WM_POWERBROADCAST},
+	};
+	let is_resume = wparam.0 == PBT_APMRESUMEAUTOMATIC as usize || wparam.0 == PBT_APMRESUMESUSPEND as usize;
+	if msg == WM_POWERBROADCAST && is_resume && ref_data != 0 {
+		let ui_tx = unsafe { &*(ref_data as *const UiCommandSender) };
+		let _ = ui_tx.send(UiCommand::SystemResumed);
+	}
+	unsafe { DefSubclassProc(hwnd, msg, wparam, lparam) }
+}
+
 #[cfg(target_os = "windows")]
 fn char_to_vk(ch: char) -> Option<u32> {
 	use windows::Win32::UI::Input::KeyboardAndMouse::VkKeyScanW;
@@ -15,6 +15,8 @@ mod options;
 mod post_view;
 mod profile;
 mod profile_edit;
+mod recipients;
+mod shortcuts;
 mod user_actions;
 
 pub use accounts::{ManageAccountsResult, show_manage_accounts_dialog};
@@ -26,7 +28,7 @@ pub use find::show_find_dialog;
 pub use follow_list::FollowListDialog;
 pub use link_selection::show_link_selection_dialog;
 pub use list_edit::show_list_edit_dialog;
-pub use list_selection::show_list_selection_dialog;
+pub use list_selection::{prompt_for_list_members, prompt_for_list_memberships, show_list_selection_dialog};
 pub use manage_list_members::ManageListMembersDialog;
 pub use manage_lists::ManageListsDialog;
 pub use media_player::*;
@@ -34,3 +36,5 @@ pub use options::*;
 pub use post_view::show_post_view_dialog;
 pub use profile::*;
 pub use profile_edit::show_profile_edit_dialog;
+pub use recipients::prompt_for_dm_recipients;
+pub use shortcuts::show_shortcuts_dialog;
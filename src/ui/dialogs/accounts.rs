@@ -3,7 +3,7 @@ use std::{cell::RefCell, rc::Rc};
 use url::Url;
 use wxdragon::prelude::*;
 
-use crate::config::Account;
+use crate::config::{Account, DisplayNameEmojiMode};
 
 #[derive(Clone)]
 pub enum ManageAccountsResult {
@@ -17,6 +17,7 @@ pub fn show_manage_accounts_dialog(
 	frame: &Frame,
 	accounts: &[Account],
 	active_id: Option<&str>,
+	emoji_mode: DisplayNameEmojiMode,
 ) -> ManageAccountsResult {
 	let dialog = Dialog::builder(frame, "Account Manager").with_size(400, 350).build();
 	let panel = Panel::builder(&dialog).build();
@@ -34,7 +35,10 @@ pub fn show_manage_accounts_dialog(
 	let active_index = active_id.and_then(|id| accounts.iter().position(|a| a.id == id));
 	for (i, account) in accounts.iter().enumerate() {
 		let handle = format_account(account);
-		let name = account.display_name.as_deref().unwrap_or("Unknown");
+		let name = account.display_name.as_deref().map_or_else(
+			|| "Unknown".to_string(),
+			|name| crate::mastodon::filtered_display_name(name, account.acct.as_deref().unwrap_or(""), emoji_mode),
+		);
 		let status = if Some(i) == active_index { "active" } else { "inactive" };
 		accounts_list.append(&format!("{name}, {handle}, {status}"));
 	}
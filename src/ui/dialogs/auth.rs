@@ -2,6 +2,7 @@ use url::Url;
 use wxdragon::prelude::*;
 
 use super::common::{prompt_text, show_warning};
+use crate::mastodon::InstanceOverview;
 
 pub fn parse_instance_url(value: &str) -> Option<Url> {
 	let trimmed = value.trim();
@@ -31,12 +32,64 @@ pub fn prompt_for_instance(frame: &Frame) -> Option<Url> {
 		let value = dialog.get_value().unwrap_or_default();
 		dialog.destroy();
 		if let Some(instance) = parse_instance_url(&value) {
+			if instance.scheme() == "http" && !confirm_unencrypted_instance(frame, &instance) {
+				continue;
+			}
 			return Some(instance);
 		}
 		show_warning(frame, "Please enter a valid instance URL.", "Invalid Instance");
 	}
 }
 
+/// Warns that an `http://` instance sends everything, including the access token, unencrypted.
+fn confirm_unencrypted_instance(frame: &Frame, instance: &Url) -> bool {
+	let message = format!(
+		"{instance} doesn't use https, so your login and everything you post will be sent unencrypted. Only use \
+		 http:// for a development server on your own computer or network.\n\nContinue anyway?"
+	);
+	let dialog = MessageDialog::builder(frame, &message, "Unencrypted Connection")
+		.with_style(MessageDialogStyle::YesNo | MessageDialogStyle::IconWarning)
+		.build();
+	dialog.show_modal() == ID_YES
+}
+
+/// Shows an instance's description and rules before authorizing. Returns `None` if the user backs out, otherwise
+/// whether to keep showing this for accounts added later.
+pub fn show_instance_info_dialog(frame: &Frame, instance: &Url, overview: &InstanceOverview) -> Option<bool> {
+	let title = format!("About {}", instance.host_str().unwrap_or("this instance"));
+	let dialog = Dialog::builder(frame, &title).with_size(500, 400).build();
+	let panel = Panel::builder(&dialog).build();
+	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let info_label = StaticText::builder(&panel).with_label("Instance &information:").build();
+	let info_text = TextCtrl::builder(&panel).with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly).build();
+	info_text.set_value(&overview.display());
+	let skip_checkbox =
+		CheckBox::builder(&panel).with_label("&Don't show this when adding accounts in the future").build();
+	let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	let continue_button = Button::builder(&panel).with_id(ID_OK).with_label("&Continue").build();
+	continue_button.set_default();
+	let cancel_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("Cancel").build();
+	button_sizer.add_stretch_spacer(1);
+	button_sizer.add(&continue_button, 0, SizerFlag::Right, 8);
+	button_sizer.add(&cancel_button, 0, SizerFlag::Right, 8);
+	main_sizer.add(&info_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add(&info_text, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add(&skip_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add_sizer(&button_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	panel.set_sizer(main_sizer, true);
+	let dialog_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
+	dialog.set_sizer(dialog_sizer, true);
+	dialog.set_affirmative_id(ID_OK);
+	dialog.set_escape_id(ID_CANCEL);
+	dialog.centre();
+	info_text.set_focus();
+	let result = dialog.show_modal();
+	let show_again = !skip_checkbox.get_value();
+	dialog.destroy();
+	(result == ID_OK).then_some(show_again)
+}
+
 pub fn prompt_for_oauth_code(frame: &Frame, instance: &Url) -> Option<String> {
 	let message =
 		format!("After authorizing Fedra on {}, paste the code here.", instance.host_str().unwrap_or("your instance"));
@@ -1,9 +1,73 @@
+use std::collections::HashMap;
+
 use wxdragon::prelude::*;
 
-use crate::mastodon::SearchType;
+use crate::{config::DialogGeometry, mastodon::SearchType};
 
 pub(crate) const KEY_RETURN: i32 = 13;
 
+/// Smallest remembered size worth restoring. Anything smaller is a dialog that was minimized or collapsed by accident.
+const MIN_REMEMBERED_SIZE: i32 = 100;
+
+/// Gives `dialog` the size and position it had when the dialog named `key` was last closed, moved onto a connected
+/// display if it would otherwise open off screen. Returns false when there is nothing to restore, so the caller keeps
+/// its default size and centres it.
+pub fn restore_geometry(dialog: &Dialog, remembered: &HashMap<String, DialogGeometry>, key: &str) -> bool {
+	let Some(geometry) =
+		remembered.get(key).filter(|g| g.width >= MIN_REMEMBERED_SIZE && g.height >= MIN_REMEMBERED_SIZE)
+	else {
+		return false;
+	};
+	let geometry = clamp_to_display(*geometry);
+	dialog.set_size(Size::new(geometry.width, geometry.height));
+	dialog.set_position(Point::new(geometry.x, geometry.y));
+	true
+}
+
+/// Remembers where `dialog` is and how big it is under `key`, for `restore_geometry` the next time it opens.
+pub fn remember_geometry(dialog: &Dialog, remembered: &mut HashMap<String, DialogGeometry>, key: &str) {
+	let size = dialog.get_size();
+	let position = dialog.get_position();
+	let geometry = DialogGeometry { x: position.x, y: position.y, width: size.width, height: size.height };
+	remembered.insert(key.to_string(), geometry);
+}
+
+/// `geometry` moved and shrunk as needed to fit the work area of the display nearest to it, so a dialog last shown on
+/// a display that has since been disconnected still opens where it can be seen.
+#[cfg(target_os = "windows")]
+fn clamp_to_display(geometry: DialogGeometry) -> DialogGeometry {
+	use windows::Win32::{
+		Foundation::RECT,
+		Graphics::Gdi::{GetMonitorInfoW, MONITOR_DEFAULTTONEAREST, MONITORINFO, MonitorFromRect},
+	};
+	let rect = RECT {
+		left: geometry.x,
+		top: geometry.y,
+		right: geometry.x.saturating_add(geometry.width),
+		bottom: geometry.y.saturating_add(geometry.height),
+	};
+	let mut info =
+		MONITORINFO { cbSize: u32::try_from(std::mem::size_of::<MONITORINFO>()).unwrap_or(0), ..Default::default() };
+	let found = unsafe { GetMonitorInfoW(MonitorFromRect(&rect, MONITOR_DEFAULTTONEAREST), &mut info) }.as_bool();
+	if !found {
+		return geometry;
+	}
+	let work = info.rcWork;
+	let width = geometry.width.min(work.right - work.left);
+	let height = geometry.height.min(work.bottom - work.top);
+	DialogGeometry {
+		x: geometry.x.clamp(work.left, work.right - width),
+		y: geometry.y.clamp(work.top, work.bottom - height),
+		width,
+		height,
+	}
+}
+
+#[cfg(not(target_os = "windows"))]
+const fn clamp_to_display(geometry: DialogGeometry) -> DialogGeometry {
+	geometry
+}
+
 pub fn prompt_text(frame: &Frame, message: &str, title: &str) -> Option<String> {
 	let dialog = TextEntryDialog::builder(frame, message, title)
 		.with_style(TextEntryDialogStyle::Default | TextEntryDialogStyle::ProcessEnter)
@@ -92,6 +156,48 @@ pub fn show_error(parent: &dyn WxWidget, err: &anyhow::Error) {
 	dialog.show_modal();
 }
 
+pub fn show_error_details_dialog(frame: &Frame, details: &str) {
+	show_copyable_text_dialog(frame, "Last Error Details", "&Details:", details);
+}
+
+pub fn show_instance_details_dialog(frame: &Frame, details: &str) {
+	show_copyable_text_dialog(frame, "About This Instance", "Instance &details:", details);
+}
+
+fn show_copyable_text_dialog(frame: &Frame, title: &str, label: &str, details: &str) {
+	let dialog = Dialog::builder(frame, title).with_size(560, 360).build();
+	let panel = Panel::builder(&dialog).build();
+	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let details_label = StaticText::builder(&panel).with_label(label).build();
+	let details_text = TextCtrl::builder(&panel)
+		.with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+		.with_value(details)
+		.build();
+	let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	let copy_button = Button::builder(&panel).with_label("&Copy").build();
+	let close_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("Close").build();
+	button_sizer.add(&copy_button, 0, SizerFlag::Right, 8);
+	button_sizer.add_stretch_spacer(1);
+	button_sizer.add(&close_button, 0, SizerFlag::Right, 8);
+	main_sizer.add(&details_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add(&details_text, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add_sizer(&button_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	panel.set_sizer(main_sizer, true);
+	let dialog_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
+	dialog.set_sizer(dialog_sizer, true);
+	dialog.set_escape_id(ID_CANCEL);
+	let details = details.to_string();
+	copy_button.on_click(move |_| {
+		let clipboard = Clipboard::get();
+		let _ = clipboard.set_text(&details);
+	});
+	details_text.set_focus();
+	dialog.centre();
+	dialog.show_modal();
+	dialog.destroy();
+}
+
 pub fn show_warning(frame: &Frame, message: &str, title: &str) {
 	let dialog = MessageDialog::builder(frame, message, title)
 		.with_style(MessageDialogStyle::OK | MessageDialogStyle::IconWarning)
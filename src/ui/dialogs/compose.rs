@@ -1,17 +1,29 @@
-use std::{cell::RefCell, path::Path, rc::Rc};
+use std::{
+	cell::{Cell, RefCell},
+	collections::HashMap,
+	path::Path,
+	rc::Rc,
+};
 
 use chrono::{DateTime, Local, LocalResult, NaiveDate, NaiveTime, SecondsFormat, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
 use wxdragon::prelude::*;
 
-use super::common::{KEY_RETURN, show_warning_widget};
+use super::common::{KEY_RETURN, remember_geometry, restore_geometry, show_warning_widget};
 use crate::{
-	config::ContentWarningDisplay,
-	mastodon::{PollLimits, Status},
+	config::{self, AutoCwRule, ContentWarningDisplay, DialogGeometry, DisplayNameEmojiMode},
+	mastodon::{MediaLimits, PollLimits, Status},
+	template::{ComposeTemplateVars, render_compose_template},
+	text::{mentions_someone, post_length},
+	ui::live_region::LiveRegion,
 };
 
 const DEFAULT_MAX_POST_CHARS: usize = 500;
+const ID_SAVE_DRAFT: i32 = 27_001;
+/// Once this few characters are left the title starts counting down and a bell sounds.
+const LOW_CHARS_WARNING: usize = 20;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PostVisibility {
 	Public,
 	Unlisted,
@@ -43,7 +55,7 @@ impl PostVisibility {
 	}
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostResult {
 	pub content: String,
 	pub visibility: PostVisibility,
@@ -55,16 +67,20 @@ pub struct PostResult {
 	pub poll: Option<PostPoll>,
 	pub scheduled_at: Option<String>,
 	pub continue_thread: bool,
+	/// Set when the dialog was closed with Save Draft, so the post is kept for later instead of sent.
+	pub save_as_draft: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostMedia {
 	pub path: String,
 	pub description: Option<String>,
 	pub is_existing: bool,
+	/// Set when the description of an already uploaded attachment was edited and needs to be sent again.
+	pub description_changed: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostPoll {
 	pub options: Vec<String>,
 	pub expires_in: u32,
@@ -73,7 +89,7 @@ pub struct PostPoll {
 }
 
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComposeDialogConfig {
 	pub title_prefix: String,
 	pub ok_label: String,
@@ -149,6 +165,7 @@ fn prompt_for_poll(
 	let remove_button = Button::builder(&panel).with_label("Remove Option").build();
 	let option_label = StaticText::builder(&panel).with_label("Selected option text:").build();
 	let option_text = TextCtrl::builder(&panel).build();
+	option_text.set_name("Poll option text");
 	let limits = limits.clone();
 	let presets_secs: Vec<u32> = DURATION_PRESETS
 		.iter()
@@ -167,8 +184,11 @@ fn prompt_for_poll(
 		.collect();
 	let duration_label = StaticText::builder(&panel).with_label("Duration:").build();
 	let duration_choice = ComboBox::builder(&panel).with_choices(preset_labels).build();
+	duration_choice.set_name("Poll duration");
 	let multiple_checkbox = CheckBox::builder(&panel).with_label("Allow multiple selections").build();
+	multiple_checkbox.set_name("Allow multiple selections");
 	let hide_totals_checkbox = CheckBox::builder(&panel).with_label("Hide vote counts until poll closes").build();
+	hide_totals_checkbox.set_name("Hide vote counts until poll closes");
 	let remove_poll_button = Button::builder(&panel).with_label("Remove Poll").build();
 	let buttons_sizer = BoxSizer::builder(Orientation::Horizontal).build();
 	let ok_button = Button::builder(&panel).with_id(ID_OK).with_label("Done").build();
@@ -379,12 +399,60 @@ fn prompt_for_poll(
 	}))
 }
 
+/// Whole and tenths of a megabyte, e.g. "16.0 MB".
+fn format_megabytes(bytes: u64) -> String {
+	let tenths = bytes.saturating_mul(10) / (1024 * 1024);
+	format!("{}.{} MB", tenths / 10, tenths % 10)
+}
+
+fn file_name(path: &str) -> String {
+	Path::new(path).file_name().map_or_else(|| path.to_string(), |name| name.to_string_lossy().into_owned())
+}
+
+/// Takes files in formats the instance doesn't accept out of `paths`, returning their names.
+fn remove_unsupported_media(paths: &mut Vec<String>, limits: &MediaLimits) -> Vec<String> {
+	let mut unsupported = Vec::new();
+	paths.retain(|path| {
+		let accepted = limits.accepts(path);
+		if !accepted {
+			unsupported.push(file_name(path));
+		}
+		accepted
+	});
+	unsupported
+}
+
+/// Takes files over the instance's size limit out of `paths`, returning each one described for a warning.
+fn remove_oversized_media(paths: &mut Vec<String>, limits: &MediaLimits) -> Vec<String> {
+	let mut oversized = Vec::new();
+	paths.retain(|path| {
+		let Ok(meta) = std::fs::metadata(path) else { return true };
+		let limit = limits.size_limit_for(path);
+		if meta.len() <= limit {
+			return true;
+		}
+		oversized.push(format!(
+			"{} ({}, the limit is {})",
+			file_name(path),
+			format_megabytes(meta.len()),
+			format_megabytes(limit)
+		));
+		false
+	});
+	oversized
+}
+
 fn prompt_for_media(
 	parent: &dyn WxWidget,
 	initial: Vec<PostMedia>,
 	initial_sensitive: bool,
+	limits: &MediaLimits,
+	geometry: &mut HashMap<String, DialogGeometry>,
 ) -> Option<(Vec<PostMedia>, bool)> {
-	let dialog = Dialog::builder(parent, "Manage Media").with_size(520, 360).build();
+	let dialog = Dialog::builder(parent, "Manage Media")
+		.with_size(520, 360)
+		.with_style(DialogStyle::DefaultDialogStyle | DialogStyle::ResizeBorder)
+		.build();
 	let panel = Panel::builder(&dialog).build();
 	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
 	let list_label = StaticText::builder(&panel).with_label("Attachments:").build();
@@ -392,9 +460,11 @@ fn prompt_for_media(
 	let add_button = Button::builder(&panel).with_label("Add...").build();
 	let remove_button = Button::builder(&panel).with_label("Remove").build();
 	let sensitive_checkbox = CheckBox::builder(&panel).with_label("Mark media as sensitive").build();
+	sensitive_checkbox.set_name("Mark media as sensitive");
 	sensitive_checkbox.set_value(initial_sensitive);
 	let desc_label = StaticText::builder(&panel).with_label("Description for selected media:").build();
 	let desc_text = TextCtrl::builder(&panel).build();
+	desc_text.set_name("Media description");
 	let buttons_sizer = BoxSizer::builder(Orientation::Horizontal).build();
 	let ok_button = Button::builder(&panel).with_id(ID_OK).with_label("Done").build();
 	ok_button.set_default();
@@ -435,7 +505,11 @@ fn prompt_for_media(
 		desc_label.enable(false);
 		desc_text.enable(false);
 	}
+	let max_attachments = limits.max_attachments;
+	add_button.enable(items.borrow().len() < max_attachments);
+	let limits = limits.clone();
 	let items_add = items.clone();
+	let add_button_add = add_button;
 	let media_list_add = media_list;
 	let remove_button_add = remove_button;
 	let desc_label_add = desc_label;
@@ -443,7 +517,7 @@ fn prompt_for_media(
 	add_button.on_click(move |_| {
 		let file_dialog = FileDialog::builder(&panel)
 			.with_message("Select media to attach")
-			.with_wildcard("Media files|*.png;*.jpg;*.jpeg;*.gif;*.webp;*.heic;*.heif;*.avif;*.mp4;*.m4v;*.webm;*.mov;*.mp3;*.ogg;*.wav;*.flac;*.opus;*.aac;*.m4a;*.3gp|All files|*.*")
+			.with_wildcard(&format!("Media files|{}|All files|*.*", limits.file_wildcard()))
 			.with_style(FileDialogStyle::Open | FileDialogStyle::FileMustExist | FileDialogStyle::Multiple)
 			.build();
 		if file_dialog.show_modal() == ID_OK {
@@ -453,11 +527,48 @@ fn prompt_for_media(
 			{
 				paths.push(path);
 			}
+			let unsupported = remove_unsupported_media(&mut paths, &limits);
+			if !unsupported.is_empty() {
+				show_warning_widget(
+					&panel,
+					&format!(
+						"This instance doesn't accept these file formats, so they were not added:\n{}",
+						unsupported.join("\n")
+					),
+					"Media",
+				);
+			}
+			let oversized = remove_oversized_media(&mut paths, &limits);
+			if !oversized.is_empty() {
+				show_warning_widget(
+					&panel,
+					&format!(
+						"These files are too large for this instance and were not added:\n{}",
+						oversized.join("\n")
+					),
+					"Media",
+				);
+			}
+			let free_slots = max_attachments.saturating_sub(items_add.borrow().len());
+			let truncated = paths.len() > free_slots;
+			if truncated {
+				paths.truncate(free_slots);
+				show_warning_widget(
+					&panel,
+					&format!("Maximum {max_attachments} attachments reached. Only the first {free_slots} were added."),
+					"Media",
+				);
+			}
 			if !paths.is_empty() {
 				let new_len = {
 					let mut items = items_add.borrow_mut();
 					for path in paths {
-						items.push(PostMedia { path, description: None, is_existing: false });
+						items.push(PostMedia {
+							path,
+							description: None,
+							is_existing: false,
+							description_changed: false,
+						});
 					}
 					refresh_media_list(media_list_add, &items);
 					items.len()
@@ -471,11 +582,23 @@ fn prompt_for_media(
 					desc_text_add.enable(true);
 					desc_text_add.set_value("");
 				}
+				if new_len >= max_attachments {
+					add_button_add.enable(false);
+					media_list_add.set_focus();
+					if !truncated {
+						show_warning_widget(
+							&panel,
+							&format!("Maximum {max_attachments} attachments reached."),
+							"Media",
+						);
+					}
+				}
 			}
 		}
 	});
 
 	let items_remove = items.clone();
+	let add_button_remove = add_button_add;
 	let media_list_remove = media_list_add;
 	let remove_button_remove = remove_button_add;
 	let desc_label_remove = desc_label;
@@ -491,6 +614,7 @@ fn prompt_for_media(
 				refresh_media_list(media_list_remove, &items);
 				(items.len(), index.min(items.len().saturating_sub(1)))
 			};
+			add_button_remove.enable(items_len < max_attachments);
 			if items_len > 0 {
 				if let Ok(selection) = u32::try_from(next_index) {
 					media_list_remove.set_selection(selection, true);
@@ -545,20 +669,40 @@ fn prompt_for_media(
 		{
 			let value = desc_text_select.get_value();
 			let trimmed = value.trim();
-			items[index].description = if trimmed.is_empty() { None } else { Some(trimmed.to_string()) };
+			let description = if trimmed.is_empty() { None } else { Some(trimmed.to_string()) };
+			let item = &mut items[index];
+			if item.is_existing && item.description != description {
+				item.description_changed = true;
+			}
+			item.description = description;
 		}
 	});
 
-	dialog.centre();
+	if !restore_geometry(&dialog, geometry, "media") {
+		dialog.centre();
+	}
 	let result = dialog.show_modal();
+	remember_geometry(&dialog, geometry, "media");
 	if result != ID_OK {
 		return None;
 	}
 	Some((items.borrow().clone(), sensitive_checkbox.get_value()))
 }
 
-pub fn prompt_for_vote(frame: &Frame, poll: &crate::mastodon::Poll, post_text: &str) -> Option<Vec<usize>> {
-	let dialog = Dialog::builder(frame, "Vote").with_size(400, 500).build();
+pub fn prompt_for_vote(
+	frame: &Frame,
+	author: &str,
+	poll: &crate::mastodon::Poll,
+	post_text: &str,
+	emoji_mode: DisplayNameEmojiMode,
+) -> Option<Vec<usize>> {
+	let titles: Vec<String> =
+		poll.options.iter().map(|option| crate::text::poll_option_title(&option.title, emoji_mode)).collect();
+	let option_count = titles.len();
+	// Titles become control labels, so a literal ampersand must not turn into a mnemonic. The accessible name keeps
+	// the plain title and the option's position so screen readers don't depend on the label text alone.
+	let option_name = |i: usize, title: &str| format!("Option {} of {option_count}: {title}", i + 1);
+	let dialog = Dialog::builder(frame, &format!("Vote on Poll from {author}")).with_size(400, 500).build();
 	let panel = Panel::builder(&dialog).build();
 	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
 
@@ -566,6 +710,7 @@ pub fn prompt_for_vote(frame: &Frame, poll: &crate::mastodon::Poll, post_text: &
 		.with_value(post_text)
 		.with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
 		.build();
+	post_display.set_name("Post");
 	main_sizer.add(&post_display, 1, SizerFlag::Expand | SizerFlag::All, 8);
 
 	let info_text = if poll.expired {
@@ -584,8 +729,9 @@ pub fn prompt_for_vote(frame: &Frame, poll: &crate::mastodon::Poll, post_text: &
 	let mut checkboxes = Vec::new();
 	let mut radio_buttons = Vec::new();
 	if poll.multiple {
-		for option in &poll.options {
-			let cb = CheckBox::builder(&panel).with_label(&option.title).build();
+		for (i, title) in titles.iter().enumerate() {
+			let cb = CheckBox::builder(&panel).with_label(&title.replace('&', "&&")).build();
+			cb.set_name(&option_name(i, title));
 			if poll.expired || poll.voted.unwrap_or(false) {
 				cb.enable(false);
 			}
@@ -593,9 +739,10 @@ pub fn prompt_for_vote(frame: &Frame, poll: &crate::mastodon::Poll, post_text: &
 			checkboxes.push(cb);
 		}
 	} else {
-		for (i, option) in poll.options.iter().enumerate() {
+		for (i, title) in titles.iter().enumerate() {
 			let style = if i == 0 { RadioButtonStyle::GroupStart } else { RadioButtonStyle::Default };
-			let rb = RadioButton::builder(&panel).with_label(&option.title).with_style(style).build();
+			let rb = RadioButton::builder(&panel).with_label(&title.replace('&', "&&")).with_style(style).build();
+			rb.set_name(&option_name(i, title));
 			if poll.expired || poll.voted.unwrap_or(false) {
 				rb.enable(false);
 			}
@@ -608,11 +755,11 @@ pub fn prompt_for_vote(frame: &Frame, poll: &crate::mastodon::Poll, post_text: &
 	if poll.expired || poll.voted.unwrap_or(false) {
 		let total_votes = poll.votes_count.max(1);
 		let results_sizer = BoxSizer::builder(Orientation::Vertical).build();
-		for option in &poll.options {
+		for (option, title) in poll.options.iter().zip(&titles) {
 			let votes = option.votes_count.unwrap_or(0);
 			let percent = votes.saturating_mul(100).saturating_div(total_votes).min(i32::MAX as u64);
 			let percent = i32::try_from(percent).unwrap_or(i32::MAX);
-			let label = format!("{}: {} votes ({}%)", option.title, votes, percent);
+			let label = format!("{}: {votes} votes ({percent}%)", title.replace('&', "&&"));
 			let text = StaticText::builder(&panel).with_label(&label).build();
 			results_sizer.add(&text, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 4);
 		}
@@ -709,8 +856,10 @@ fn prompt_for_schedule(parent: &dyn WxWidget, current: Option<&str>) -> Option<O
 	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
 	let date_label = StaticText::builder(&panel).with_label("Date (YYYY-MM-DD):").build();
 	let date_input = TextCtrl::builder(&panel).with_style(TextCtrlStyle::ProcessEnter).build();
+	date_input.set_name("Date");
 	let time_label = StaticText::builder(&panel).with_label("Time (HH:MM, 24-hour, local):").build();
 	let time_input = TextCtrl::builder(&panel).with_style(TextCtrlStyle::ProcessEnter).build();
+	time_input.set_name("Time");
 	let now_local = Local::now() + chrono::Duration::minutes(10);
 	date_input.set_value(&now_local.format("%Y-%m-%d").to_string());
 	time_input.set_value(&now_local.format("%H:%M").to_string());
@@ -775,11 +924,102 @@ fn prompt_for_schedule(parent: &dyn WxWidget, current: Option<&str>) -> Option<O
 	Some(Some(scheduled_utc.to_rfc3339_opts(SecondsFormat::Secs, true)))
 }
 
+/// Lets the user pick one of the saved compose templates and returns its text with the date and time filled in.
+fn prompt_for_compose_template(parent: &Dialog) -> Option<String> {
+	let templates = config::compose_templates();
+	if templates.is_empty() {
+		let dir = config::compose_templates_dir();
+		show_warning_widget(
+			parent,
+			&format!(
+				"No templates found. Save each template as a .txt file in {}. Write {{date}} and {{time}} where the \
+				 current date and time should go.",
+				dir.display()
+			),
+			"Insert Template",
+		);
+		return None;
+	}
+	let names: Vec<&str> = templates.iter().map(|(name, _)| name.as_str()).collect();
+	let dialog = SingleChoiceDialog::builder(parent, "Choose a template", "Insert Template", &names).build();
+	if dialog.show_modal() != ID_OK {
+		return None;
+	}
+	let (_, path) = usize::try_from(dialog.get_selection()).ok().and_then(|idx| templates.get(idx))?;
+	let template = match std::fs::read_to_string(path) {
+		Ok(template) => template,
+		Err(e) => {
+			show_warning_widget(parent, &format!("Could not read {}: {e}", path.display()), "Insert Template");
+			return None;
+		}
+	};
+	let now = Local::now();
+	let vars = ComposeTemplateVars { date: now.format("%Y-%m-%d").to_string(), time: now.format("%H:%M").to_string() };
+	Some(render_compose_template(template.trim_end(), &vars))
+}
+
+/// Length of the post as the server counts it, including the content warning when one is set.
+fn composed_length(content_text: TextCtrl, cw_checkbox: CheckBox, cw_text: TextCtrl) -> usize {
+	let content = post_length(content_text.get_value().trim());
+	if cw_checkbox.get_value() { content + cw_text.get_value().trim().chars().count() } else { content }
+}
+
+enum UnsavedChoice {
+	SaveDraft,
+	Discard,
+	KeepEditing,
+}
+
+/// Asks what to do with a compose dialog's unsaved changes when it is cancelled.
+fn prompt_for_unsaved_changes(parent: &Dialog) -> UnsavedChoice {
+	const ID_DISCARD: i32 = 27_002;
+	let dialog = Dialog::builder(parent, "Unsaved Post").with_size(460, 150).build();
+	let panel = Panel::builder(&dialog).build();
+	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let message = StaticText::builder(&panel)
+		.with_label(
+			"You have unsaved changes. Save them as a draft you can resume from the Post menu, or discard them?",
+		)
+		.build();
+	let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	let save_button = Button::builder(&panel).with_id(ID_SAVE_DRAFT).with_label("&Save Draft").build();
+	let discard_button = Button::builder(&panel).with_id(ID_DISCARD).with_label("&Discard").build();
+	let keep_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("&Keep Editing").build();
+	button_sizer.add_stretch_spacer(1);
+	button_sizer.add(&save_button, 0, SizerFlag::Right, 8);
+	button_sizer.add(&discard_button, 0, SizerFlag::Right, 8);
+	button_sizer.add(&keep_button, 0, SizerFlag::Right, 8);
+	main_sizer.add(&message, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add_sizer(&button_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	panel.set_sizer(main_sizer, true);
+	let dialog_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
+	dialog.set_sizer(dialog_sizer, true);
+	dialog.set_escape_id(ID_CANCEL);
+	save_button.on_click(move |_| dialog.end_modal(ID_SAVE_DRAFT));
+	discard_button.on_click(move |_| dialog.end_modal(ID_DISCARD));
+	save_button.set_default();
+	dialog.centre();
+	save_button.set_focus();
+	let result = dialog.show_modal();
+	dialog.destroy();
+	match result {
+		ID_SAVE_DRAFT => UnsavedChoice::SaveDraft,
+		ID_DISCARD => UnsavedChoice::Discard,
+		_ => UnsavedChoice::KeepEditing,
+	}
+}
+
 pub fn prompt_for_compose(
 	frame: &Frame,
 	max_chars: Option<usize>,
 	poll_limits: &PollLimits,
+	media_limits: &MediaLimits,
 	enter_to_send: bool,
+	cw_marks_sensitive: bool,
+	auto_cw_rules: &[AutoCwRule],
+	confirm_discard: bool,
+	geometry: &mut HashMap<String, DialogGeometry>,
 	config: ComposeDialogConfig,
 	initial_media: Vec<PostMedia>,
 	initial_poll: Option<PostPoll>,
@@ -790,8 +1030,10 @@ pub fn prompt_for_compose(
 	let initial_content = config.initial_content.clone();
 	let initial_cw = config.initial_cw.clone();
 	let default_visibility = config.default_visibility;
-	let dialog =
-		Dialog::builder(frame, &format!("{title_prefix} - 0 of {max_chars} characters")).with_size(700, 560).build();
+	let dialog = Dialog::builder(frame, &format!("{title_prefix} - 0 of {max_chars} characters"))
+		.with_size(700, 560)
+		.with_style(DialogStyle::DefaultDialogStyle | DialogStyle::ResizeBorder)
+		.build();
 	let panel = Panel::builder(&dialog).build();
 	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
 
@@ -806,15 +1048,19 @@ pub fn prompt_for_compose(
 			.with_value(&quoted_text)
 			.with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
 			.build();
+		quote_text.set_name("Quoted post");
 		main_sizer.add(&quote_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
 		main_sizer.add(&quote_text, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
 	}
 
 	let content_label = StaticText::builder(&panel).with_label("&What's on your mind?").build();
 	let content_text = TextCtrl::builder(&panel).with_style(TextCtrlStyle::MultiLine).build();
+	content_text.set_name("Post text");
 	let cw_checkbox = CheckBox::builder(&panel).with_label("&Content warning").build();
-	let cw_label = StaticText::builder(&panel).with_label("Warning text:").build();
+	cw_checkbox.set_name("Content warning");
+	let cw_label = StaticText::builder(&panel).with_label("Warning te&xt:").build();
 	let cw_text = TextCtrl::builder(&panel).build();
+	cw_text.set_name("Content warning text");
 	cw_label.show(false);
 	cw_text.show(false);
 	let content_type_label = StaticText::builder(&panel).with_label("Content &type (if supported):").build();
@@ -826,10 +1072,12 @@ pub fn prompt_for_compose(
 	];
 	let content_type_labels: Vec<String> = content_type_options.iter().map(|(label, _)| label.clone()).collect();
 	let content_type_choice = Choice::builder(&panel).with_choices(content_type_labels).build();
+	content_type_choice.set_name("Content type");
 	content_type_choice.set_selection(0);
 	let visibility_label = StaticText::builder(&panel).with_label("&Visibility:").build();
 	let visibility_choices: Vec<String> = PostVisibility::all().iter().map(|v| v.display_name().to_string()).collect();
 	let visibility_choice = Choice::builder(&panel).with_choices(visibility_choices).build();
+	visibility_choice.set_name("Visibility");
 	if let Ok(selection) = u32::try_from(visibility_index(default_visibility)) {
 		visibility_choice.set_selection(selection);
 	}
@@ -857,6 +1105,7 @@ pub fn prompt_for_compose(
 	];
 	let language_label = StaticText::builder(&panel).with_label("Post &language (ISO code):").build();
 	let language_combo = ComboBox::builder(&panel).with_choices(language_choices).build();
+	language_combo.set_name("Post language");
 	let initial_language_value =
 		config.initial_language.as_deref().and_then(normalize_language_code).unwrap_or_default();
 	language_combo.set_value(&initial_language_value);
@@ -865,10 +1114,12 @@ pub fn prompt_for_compose(
 	language_sizer.add(&language_combo, 1, SizerFlag::Expand, 0);
 	let media_button = Button::builder(&panel).with_label("Manage &Media...").build();
 	let poll_button = Button::builder(&panel).with_label("Add &Poll...").build();
+	let template_button = Button::builder(&panel).with_label("&Insert Template...").build();
 	let schedule_button = Button::builder(&panel).with_label("&Schedule...").build();
 	let clear_schedule_button = Button::builder(&panel).with_label("Clear Schedule").build();
 	clear_schedule_button.enable(false);
 	let thread_checkbox = CheckBox::builder(&panel).with_label("T&hread mode").build();
+	thread_checkbox.set_name("Thread mode");
 	if !config.show_thread_checkbox {
 		thread_checkbox.show(false);
 	}
@@ -885,6 +1136,11 @@ pub fn prompt_for_compose(
 	button_sizer.add(&cancel_button, 0, SizerFlag::Right, 8);
 	main_sizer.add(&content_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	main_sizer.add(&content_text, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	// An empty, disabled panel that only exists to speak length warnings, so Tab never stops on it.
+	let announcer = Panel::builder(&panel).build();
+	announcer.enable(false);
+	main_sizer.add(&announcer, 0, SizerFlag::empty(), 0);
+	let live_region = LiveRegion::new(&announcer);
 	main_sizer.add(&cw_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	main_sizer.add(&cw_label, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
 	main_sizer.add(&cw_text, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
@@ -893,6 +1149,7 @@ pub fn prompt_for_compose(
 	main_sizer.add_sizer(&language_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	main_sizer.add(&media_button, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top, 8);
 	main_sizer.add(&poll_button, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add(&template_button, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top, 8);
 	if config.show_schedule_controls {
 		let schedule_sizer = BoxSizer::builder(Orientation::Horizontal).build();
 		schedule_sizer.add(&schedule_button, 0, SizerFlag::Right, 8);
@@ -917,17 +1174,47 @@ pub fn prompt_for_compose(
 	dialog.set_escape_id(ID_CANCEL);
 	let media_items: Rc<RefCell<Vec<PostMedia>>> = Rc::new(RefCell::new(initial_media));
 	let sensitive_state: Rc<RefCell<bool>> = Rc::new(RefCell::new(config.initial_sensitive));
+	// Set while the sensitive flag was turned on by adding a content warning, so removing the warning can undo it.
+	let sensitive_from_cw: Rc<Cell<bool>> = Rc::new(Cell::new(false));
 	let media_items_manage = media_items.clone();
 	let sensitive_state_manage = sensitive_state.clone();
+	let sensitive_from_cw_manage = sensitive_from_cw.clone();
 	let media_parent = dialog;
+	let media_limits = media_limits.clone();
+	// The media dialog opens from a button, so it borrows the remembered geometry until this dialog closes.
+	let shared_geometry = Rc::new(RefCell::new(std::mem::take(geometry)));
+	let media_geometry = shared_geometry.clone();
 	media_button.on_click(move |_| {
 		let current = media_items_manage.borrow().clone();
 		let current_sensitive = *sensitive_state_manage.borrow();
-		if let Some((updated, updated_sensitive)) = prompt_for_media(&media_parent, current, current_sensitive) {
+		let media = prompt_for_media(
+			&media_parent,
+			current,
+			current_sensitive,
+			&media_limits,
+			&mut media_geometry.borrow_mut(),
+		);
+		if let Some((updated, updated_sensitive)) = media {
 			*media_items_manage.borrow_mut() = updated;
+			if updated_sensitive != current_sensitive {
+				sensitive_from_cw_manage.set(false);
+			}
 			*sensitive_state_manage.borrow_mut() = updated_sensitive;
 		}
 	});
+	let template_parent = dialog;
+	template_button.on_click(move |_| {
+		if let Some(text) = prompt_for_compose_template(&template_parent) {
+			let current = content_text.get_value();
+			if current.is_empty() || current.ends_with(char::is_whitespace) {
+				content_text.set_value(&format!("{current}{text}"));
+			} else {
+				content_text.set_value(&format!("{current}\n{text}"));
+			}
+			content_text.set_insertion_point_end();
+		}
+		content_text.set_focus();
+	});
 	let poll_state: Rc<RefCell<Option<PostPoll>>> = Rc::new(RefCell::new(initial_poll));
 	{
 		if poll_state.borrow().as_ref().is_some() {
@@ -983,6 +1270,9 @@ pub fn prompt_for_compose(
 	let cw_text_toggle = cw_text;
 	let panel_toggle = panel;
 	let dialog_toggle = dialog;
+	let sensitive_state_toggle = sensitive_state.clone();
+	let sensitive_state_auto = sensitive_state.clone();
+	let sensitive_from_cw_auto = sensitive_from_cw.clone();
 	cw_checkbox.on_toggled(move |event| {
 		let checked = event.is_checked();
 		cw_label_toggle.show(checked);
@@ -990,29 +1280,53 @@ pub fn prompt_for_compose(
 		if !checked {
 			cw_text_toggle.set_value("");
 		}
+		if checked && cw_marks_sensitive && !*sensitive_state_toggle.borrow() {
+			*sensitive_state_toggle.borrow_mut() = true;
+			sensitive_from_cw.set(true);
+		} else if !checked && sensitive_from_cw.replace(false) {
+			*sensitive_state_toggle.borrow_mut() = false;
+		}
 		panel_toggle.layout();
 		dialog_toggle.layout();
 	});
 	if !initial_content.is_empty() {
 		content_text.set_value(&initial_content);
 	}
-	let content_text_title = content_text;
 	let dialog_title = dialog;
 	let title_prefix_update = title_prefix.clone();
 	let update_title = move || {
-		let text = content_text_title.get_value();
-		let char_count = text.chars().count();
-		dialog_title.set_label(&format!("{title_prefix_update} - {char_count} of {max_chars} characters"));
+		let char_count = composed_length(content_text, cw_checkbox, cw_text);
+		let remaining = match max_chars.checked_sub(char_count) {
+			Some(left) if left <= LOW_CHARS_WARNING => format!(", {left} left"),
+			Some(_) => String::new(),
+			None => format!(", {} over", char_count - max_chars),
+		};
+		dialog_title.set_label(&format!("{title_prefix_update} - {char_count} of {max_chars} characters{remaining}"));
 	};
 	update_title();
-	let update_title_on_change = update_title;
-	content_text.on_text_changed(move |_| {
-		let current = content_text.get_value();
-		if current.chars().count() > max_chars {
+	let initial_count = composed_length(content_text, cw_checkbox, cw_text);
+	let was_low = Cell::new(initial_count + LOW_CHARS_WARNING >= max_chars);
+	let last_count = Cell::new(initial_count);
+	let check_length = move || {
+		let char_count = composed_length(content_text, cw_checkbox, cw_text);
+		let is_low = char_count + LOW_CHARS_WARNING >= max_chars;
+		if char_count > max_chars || (is_low && !was_low.get()) {
 			bell();
 		}
-		update_title_on_change();
-	});
+		// Growing by more than a keystroke means a paste or similar, which the title alone won't make heard.
+		if char_count > max_chars && char_count > last_count.get() + 1 {
+			let over = char_count - max_chars;
+			let unit = if over == 1 { "character" } else { "characters" };
+			live_region.announce(&format!("Over limit by {over} {unit}"));
+		}
+		was_low.set(is_low);
+		last_count.set(char_count);
+		update_title();
+	};
+	let check_length = Rc::new(check_length);
+	let check_length_content = check_length.clone();
+	content_text.on_text_changed(move |_| check_length_content());
+	cw_text.on_text_changed(move |_| check_length());
 	if let Some(cw) = initial_cw.as_deref().map(str::trim)
 		&& !cw.is_empty()
 	{
@@ -1024,12 +1338,77 @@ pub fn prompt_for_compose(
 	if config.show_schedule_controls {
 		schedule_button.set_label(&schedule_button_label(scheduled_state.borrow().as_deref()));
 	}
+	let auto_cw_rules = auto_cw_rules.to_vec();
+	// When a draft without a content warning matches a rule, fill one in and ask before posting.
+	let confirm_auto_cw = Rc::new(move || {
+		if cw_checkbox.get_value() {
+			return true;
+		}
+		let content = content_text.get_value();
+		let Some((rule, keyword)) =
+			auto_cw_rules.iter().find_map(|rule| rule.matched_keyword(&content).map(|keyword| (rule, keyword)))
+		else {
+			return true;
+		};
+		let set_cw = |checked: bool| {
+			cw_checkbox.set_value(checked);
+			cw_label.show(checked);
+			cw_text.show(checked);
+			cw_text.set_value(if checked { rule.spoiler_text.as_str() } else { "" });
+			if checked && cw_marks_sensitive && !*sensitive_state_auto.borrow() {
+				*sensitive_state_auto.borrow_mut() = true;
+				sensitive_from_cw_auto.set(true);
+			} else if !checked && sensitive_from_cw_auto.replace(false) {
+				*sensitive_state_auto.borrow_mut() = false;
+			}
+			panel.layout();
+			dialog.layout();
+		};
+		set_cw(true);
+		let message = format!(
+			"This post mentions \"{keyword}\", so the content warning \"{}\" was added. Post it with this warning?\n\nChoose No to post without a warning, or Cancel to keep editing.",
+			rule.spoiler_text
+		);
+		let confirm = MessageDialog::builder(&dialog, &message, "Content Warning")
+			.with_style(MessageDialogStyle::YesNo | MessageDialogStyle::Cancel | MessageDialogStyle::IconQuestion)
+			.build();
+		match confirm.show_modal() {
+			ID_YES => true,
+			ID_NO => {
+				set_cw(false);
+				true
+			}
+			_ => {
+				cw_text.set_focus();
+				false
+			}
+		}
+	});
+	let has_recipients = Rc::new(move || {
+		let visibility_idx = visibility_choice.get_selection().unwrap_or(0) as usize;
+		if PostVisibility::all().get(visibility_idx) != Some(&PostVisibility::Direct)
+			|| mentions_someone(&content_text.get_value())
+		{
+			return true;
+		}
+		show_warning_widget(
+			&dialog,
+			"A direct message only reaches the people it mentions. Add at least one @mention.",
+			"Direct Message",
+		);
+		content_text.set_focus();
+		false
+	});
 	let dialog_ok = dialog;
 	let content_text_ok = content_text;
 	let title_prefix_ok = title_prefix.clone();
+	let confirm_auto_cw_ok = confirm_auto_cw.clone();
+	let has_recipients_ok = has_recipients.clone();
 	ok_button.on_click(move |_| {
-		let content = content_text_ok.get_value();
-		let char_count = content.trim().chars().count();
+		if !has_recipients_ok() || !confirm_auto_cw_ok() {
+			return;
+		}
+		let char_count = composed_length(content_text_ok, cw_checkbox, cw_text);
 		if char_count > max_chars {
 			show_warning_widget(
 				&dialog_ok,
@@ -1056,16 +1435,17 @@ pub fn prompt_for_compose(
 			};
 
 			if should_submit {
-				let content = content_text_enter.get_value();
-				let char_count = content.trim().chars().count();
-				if char_count > max_chars {
-					show_warning_widget(
-						&dialog_enter,
-						&format!("Post is {char_count} characters, which exceeds the {max_chars} character limit."),
-						&title_prefix_enter,
-					);
-				} else {
-					dialog_enter.end_modal(ID_OK);
+				if has_recipients() && confirm_auto_cw() {
+					let char_count = composed_length(content_text_enter, cw_checkbox, cw_text);
+					if char_count > max_chars {
+						show_warning_widget(
+							&dialog_enter,
+							&format!("Post is {char_count} characters, which exceeds the {max_chars} character limit."),
+							&title_prefix_enter,
+						);
+					} else {
+						dialog_enter.end_modal(ID_OK);
+					}
 				}
 				event.skip(false);
 			} else {
@@ -1075,13 +1455,44 @@ pub fn prompt_for_compose(
 			event.skip(true);
 		}
 	});
-	dialog.centre();
+	let initial_cw_text = initial_cw.clone().unwrap_or_default();
+	let initial_media_count = media_items.borrow().len();
+	let initial_has_poll = poll_state.borrow().is_some();
+	let initial_content_cancel = initial_content.clone();
+	let media_items_cancel = media_items.clone();
+	let poll_state_cancel = poll_state.clone();
+	// Escape and closing the window go through the Cancel button too, so this guards every way out.
+	cancel_button.on_click(move |_| {
+		let content = content_text.get_value();
+		let cw = if cw_checkbox.get_value() { cw_text.get_value() } else { String::new() };
+		let media_count = media_items_cancel.borrow().len();
+		let has_poll = poll_state_cancel.borrow().is_some();
+		let is_empty = content.trim().is_empty() && cw.trim().is_empty() && media_count == 0 && !has_poll;
+		let changed = content != initial_content_cancel
+			|| cw.trim() != initial_cw_text.trim()
+			|| media_count != initial_media_count
+			|| has_poll != initial_has_poll;
+		if !confirm_discard || is_empty || !changed {
+			dialog.end_modal(ID_CANCEL);
+			return;
+		}
+		match prompt_for_unsaved_changes(&dialog) {
+			UnsavedChoice::SaveDraft => dialog.end_modal(ID_SAVE_DRAFT),
+			UnsavedChoice::Discard => dialog.end_modal(ID_CANCEL),
+			UnsavedChoice::KeepEditing => content_text.set_focus(),
+		}
+	});
+	if !restore_geometry(&dialog, &shared_geometry.borrow(), "compose") {
+		dialog.centre();
+	}
 	content_text.set_focus();
 	if !initial_content.is_empty() {
 		content_text.set_insertion_point_end();
 	}
 	let result = dialog.show_modal();
-	if result != ID_OK {
+	*geometry = shared_geometry.take();
+	remember_geometry(&dialog, geometry, "compose");
+	if result != ID_OK && result != ID_SAVE_DRAFT {
 		return None;
 	}
 	let content = content_text.get_value();
@@ -1115,6 +1526,7 @@ pub fn prompt_for_compose(
 			poll,
 			scheduled_at: scheduled_state.borrow().clone(),
 			continue_thread: thread_checkbox.get_value(),
+			save_as_draft: result == ID_SAVE_DRAFT,
 		},
 		config,
 	))
@@ -1124,14 +1536,24 @@ pub fn prompt_for_post(
 	frame: &Frame,
 	max_chars: Option<usize>,
 	poll_limits: &PollLimits,
+	media_limits: &MediaLimits,
 	enter_to_send: bool,
+	cw_marks_sensitive: bool,
+	auto_cw_rules: &[AutoCwRule],
+	confirm_discard: bool,
+	geometry: &mut HashMap<String, DialogGeometry>,
 	default_visibility: Option<PostVisibility>,
 ) -> Option<(PostResult, ComposeDialogConfig)> {
 	prompt_for_compose(
 		frame,
 		max_chars,
 		poll_limits,
+		media_limits,
 		enter_to_send,
+		cw_marks_sensitive,
+		auto_cw_rules,
+		confirm_discard,
+		geometry,
 		ComposeDialogConfig {
 			title_prefix: "Post".to_string(),
 			ok_label: "Post".to_string(),
@@ -1151,41 +1573,83 @@ pub fn prompt_for_post(
 	)
 }
 
+/// Composes a new direct message to `recipients`, with visibility locked to Direct.
+pub fn prompt_for_direct_message(
+	frame: &Frame,
+	max_chars: Option<usize>,
+	poll_limits: &PollLimits,
+	media_limits: &MediaLimits,
+	enter_to_send: bool,
+	cw_marks_sensitive: bool,
+	auto_cw_rules: &[AutoCwRule],
+	confirm_discard: bool,
+	geometry: &mut HashMap<String, DialogGeometry>,
+	recipients: &[String],
+) -> Option<(PostResult, ComposeDialogConfig)> {
+	prompt_for_compose(
+		frame,
+		max_chars,
+		poll_limits,
+		media_limits,
+		enter_to_send,
+		cw_marks_sensitive,
+		auto_cw_rules,
+		confirm_discard,
+		geometry,
+		ComposeDialogConfig {
+			title_prefix: "Direct Message".to_string(),
+			ok_label: "Send".to_string(),
+			initial_content: recipients.join(" ") + " ",
+			initial_cw: None,
+			initial_sensitive: false,
+			initial_language: None,
+			default_visibility: PostVisibility::Direct,
+			can_change_visibility: false,
+			show_schedule_controls: true,
+			show_thread_checkbox: false,
+			initial_thread_mode: false,
+			quoted_text: None,
+		},
+		Vec::new(),
+		None,
+	)
+}
+
 pub fn prompt_for_reply(
 	frame: &Frame,
 	replying_to: &Status,
 	max_chars: Option<usize>,
 	poll_limits: &PollLimits,
+	media_limits: &MediaLimits,
 	reply_all: bool,
-	self_acct: Option<&str>,
+	self_account: Option<&crate::config::Account>,
 	enter_to_send: bool,
+	cw_marks_sensitive: bool,
+	auto_cw_rules: &[AutoCwRule],
+	confirm_discard: bool,
+	geometry: &mut HashMap<String, DialogGeometry>,
 	initial_thread_mode: bool,
 ) -> Option<(PostResult, ComposeDialogConfig)> {
 	let author = replying_to.account.display_name_or_username();
-	let mention = if reply_all {
-		let mut accts = Vec::new();
-		let self_acct = self_acct.map(|acct| acct.trim().trim_start_matches('@')).filter(|acct| !acct.is_empty());
-		if let Some(self_acct) = self_acct {
-			if !self_acct.eq_ignore_ascii_case(replying_to.account.full_acct().trim().trim_start_matches('@')) {
-				accts.push(replying_to.account.full_acct());
-			}
-		} else {
-			accts.push(replying_to.account.full_acct());
-		}
+	let mut accts = Vec::new();
+	if !self_account.is_some_and(|account| replying_to.account.is_self(account)) {
+		accts.push(replying_to.account.full_acct());
+	}
+	if reply_all {
 		for m in &replying_to.mentions {
-			if let Some(self_acct) = self_acct
-				&& is_self_mention(self_acct, m)
-			{
+			if self_account.is_some_and(|account| m.is_self(account)) {
 				continue;
 			}
 			let m_full_acct = m.full_acct();
-			if !accts.iter().any(|a| a == &m_full_acct) {
+			if !accts.iter().any(|a| a.eq_ignore_ascii_case(&m_full_acct)) {
 				accts.push(m_full_acct);
 			}
 		}
-		accts.iter().map(|a| format!("@{a}")).collect::<Vec<_>>().join(" ") + " "
+	}
+	let mention = if accts.is_empty() {
+		String::new()
 	} else {
-		format!("@{} ", replying_to.account.full_acct())
+		accts.iter().map(|a| format!("@{a}")).collect::<Vec<_>>().join(" ") + " "
 	};
 	let default_visibility = match replying_to.visibility.as_str() {
 		"unlisted" => PostVisibility::Unlisted,
@@ -1199,7 +1663,12 @@ pub fn prompt_for_reply(
 		frame,
 		max_chars,
 		poll_limits,
+		media_limits,
 		enter_to_send,
+		cw_marks_sensitive,
+		auto_cw_rules,
+		confirm_discard,
+		geometry,
 		ComposeDialogConfig {
 			title_prefix: format!("Reply to {author}"),
 			ok_label: "Post".to_string(),
@@ -1225,7 +1694,12 @@ pub fn prompt_for_edit(
 	source_text: Option<&str>,
 	max_chars: Option<usize>,
 	poll_limits: &PollLimits,
+	media_limits: &MediaLimits,
 	enter_to_send: bool,
+	cw_marks_sensitive: bool,
+	auto_cw_rules: &[AutoCwRule],
+	confirm_discard: bool,
+	geometry: &mut HashMap<String, DialogGeometry>,
 ) -> Option<(PostResult, ComposeDialogConfig)> {
 	let default_visibility = match status.visibility.as_str() {
 		"unlisted" => PostVisibility::Unlisted,
@@ -1237,20 +1711,33 @@ pub fn prompt_for_edit(
 	let initial_media = status
 		.media_attachments
 		.iter()
-		.map(|m| PostMedia { path: m.id.clone(), description: m.description.clone(), is_existing: true })
+		.map(|m| PostMedia {
+			path: m.id.clone(),
+			description: m.description.clone(),
+			is_existing: true,
+			description_changed: false,
+		})
 		.collect();
 	let initial_poll = status.poll.as_ref().map(|p| PostPoll {
 		options: p.options.iter().map(|o| o.title.clone()).collect(),
-		expires_in: 3600, // API doesn't return original expires_in, defaulting to 1 hour
+		// The API only reports when the poll ends, so keep the remaining time and fall back to an hour.
+		expires_in: p.expires_in().map_or(3600, |secs| {
+			secs.clamp(poll_limits.min_expiration, poll_limits.max_expiration.max(poll_limits.min_expiration))
+		}),
 		multiple: p.multiple,
-		hide_totals: false, // API doesn't return hide_totals, defaulting to false
+		hide_totals: p.hide_totals.unwrap_or(false),
 	});
 
 	prompt_for_compose(
 		frame,
 		max_chars,
 		poll_limits,
+		media_limits,
 		enter_to_send,
+		cw_marks_sensitive,
+		auto_cw_rules,
+		confirm_discard,
+		geometry,
 		ComposeDialogConfig {
 			title_prefix: "Edit Post".to_string(),
 			ok_label: "Save".to_string(),
@@ -1275,7 +1762,12 @@ pub fn prompt_for_quote(
 	quoting: &Status,
 	max_chars: Option<usize>,
 	poll_limits: &PollLimits,
+	media_limits: &MediaLimits,
 	enter_to_send: bool,
+	cw_marks_sensitive: bool,
+	auto_cw_rules: &[AutoCwRule],
+	confirm_discard: bool,
+	geometry: &mut HashMap<String, DialogGeometry>,
 ) -> Option<(PostResult, ComposeDialogConfig)> {
 	let author = quoting.account.display_name_or_username();
 	let default_visibility = match quoting.visibility.as_str() {
@@ -1284,13 +1776,18 @@ pub fn prompt_for_quote(
 		"direct" => PostVisibility::Direct,
 		_ => PostVisibility::Public,
 	};
-	let quoted_text = quoting.content_with_cw(ContentWarningDisplay::Inline, true);
+	let quoted_text = quoting.content_with_cw(ContentWarningDisplay::Inline, true, DisplayNameEmojiMode::None);
 
 	prompt_for_compose(
 		frame,
 		max_chars,
 		poll_limits,
+		media_limits,
 		enter_to_send,
+		cw_marks_sensitive,
+		auto_cw_rules,
+		confirm_discard,
+		geometry,
 		ComposeDialogConfig {
 			title_prefix: format!("Quote {author}"),
 			ok_label: "Post".to_string(),
@@ -1309,15 +1806,3 @@ pub fn prompt_for_quote(
 		None,
 	)
 }
-
-fn is_self_mention(self_acct: &str, mention: &crate::mastodon::Mention) -> bool {
-	let mention_acct = mention.full_acct();
-	let mention_acct = mention_acct.trim().trim_start_matches('@');
-	if self_acct.eq_ignore_ascii_case(mention_acct) {
-		return true;
-	}
-	if self_acct.contains('@') {
-		return false;
-	}
-	self_acct.eq_ignore_ascii_case(mention.username.trim().trim_start_matches('@'))
-}
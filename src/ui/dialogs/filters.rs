@@ -10,6 +10,8 @@ pub enum ManageFiltersResult {
 	Add,
 	Edit(String),
 	Delete(String),
+	Export,
+	Import,
 	None,
 }
 
@@ -26,18 +28,25 @@ pub fn prompt_manage_filters(frame: &Frame, filters: &[Filter]) -> ManageFilters
 			FilterAction::Blur => "Hide media with warning",
 			FilterAction::Other(s) => s,
 		};
-		let label = format!("{} ({})", filter.title, action_label);
+		let label = match filter.expiry_label() {
+			Some(expiry) => format!("{} ({}, {})", filter.title, action_label, expiry),
+			None => format!("{} ({})", filter.title, action_label),
+		};
 		filters_list.append(&label);
 	}
 	let buttons_sizer = BoxSizer::builder(Orientation::Horizontal).build();
 	let add_button = Button::builder(&panel).with_label("Add...").build();
 	let edit_button = Button::builder(&panel).with_label("Edit...").build();
 	let remove_button = Button::builder(&panel).with_label("Delete").build();
+	let export_button = Button::builder(&panel).with_label("Export...").build();
+	let import_button = Button::builder(&panel).with_label("Import...").build();
 	let close_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("Close").build();
 	close_button.set_default();
 	buttons_sizer.add(&add_button, 0, SizerFlag::Right, 8);
 	buttons_sizer.add(&edit_button, 0, SizerFlag::Right, 8);
 	buttons_sizer.add(&remove_button, 0, SizerFlag::Right, 8);
+	buttons_sizer.add(&export_button, 0, SizerFlag::Right, 8);
+	buttons_sizer.add(&import_button, 0, SizerFlag::Right, 8);
 	buttons_sizer.add_stretch_spacer(1);
 	buttons_sizer.add(&close_button, 0, SizerFlag::Right, 8);
 	main_sizer.add(&filters_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
@@ -51,6 +60,7 @@ pub fn prompt_manage_filters(frame: &Frame, filters: &[Filter]) -> ManageFilters
 	dialog.set_escape_id(ID_CANCEL);
 	edit_button.enable(false);
 	remove_button.enable(false);
+	export_button.enable(!filters.is_empty());
 	let result = Rc::new(RefCell::new(ManageFiltersResult::None));
 	let filters_list_select = filters_list;
 	let edit_button_select = edit_button;
@@ -65,6 +75,16 @@ pub fn prompt_manage_filters(frame: &Frame, filters: &[Filter]) -> ManageFilters
 		*result_add.borrow_mut() = ManageFiltersResult::Add;
 		dialog.end_modal(ID_OK);
 	});
+	let result_export = result.clone();
+	export_button.on_click(move |_| {
+		*result_export.borrow_mut() = ManageFiltersResult::Export;
+		dialog.end_modal(ID_OK);
+	});
+	let result_import = result.clone();
+	import_button.on_click(move |_| {
+		*result_import.borrow_mut() = ManageFiltersResult::Import;
+		dialog.end_modal(ID_OK);
+	});
 	let result_edit = result.clone();
 	let filters_list_edit = filters_list;
 	let filter_ids: Vec<String> = filters.iter().map(|f| f.id.clone()).collect();
@@ -102,6 +122,22 @@ pub fn prompt_manage_filters(frame: &Frame, filters: &[Filter]) -> ManageFilters
 	(*result.borrow()).clone()
 }
 
+/// Asks where to save exported filters, or which file to import them from.
+pub fn prompt_filter_file(frame: &Frame, save: bool) -> Option<String> {
+	let style = if save {
+		FileDialogStyle::Save | FileDialogStyle::OverwritePrompt
+	} else {
+		FileDialogStyle::Open | FileDialogStyle::FileMustExist
+	};
+	let dialog = FileDialog::builder(frame)
+		.with_message(if save { "Export Filters" } else { "Import Filters" })
+		.with_default_file(if save { "filters.json" } else { "" })
+		.with_wildcard("JSON files|*.json|All files|*.*")
+		.with_style(style)
+		.build();
+	if dialog.show_modal() == ID_OK { dialog.get_path() } else { None }
+}
+
 pub struct FilterDialogResult {
 	pub title: String,
 	pub contexts: Vec<FilterContext>,
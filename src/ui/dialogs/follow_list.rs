@@ -4,6 +4,7 @@ use wxdragon::{event::MenuEvents, prelude::*};
 
 use super::user_actions;
 use crate::{
+	config::DisplayNameEmojiMode,
 	mastodon::{Account, Relationship},
 	network::NetworkCommand,
 };
@@ -17,6 +18,7 @@ pub struct FollowListDialog {
 	title_base: String,
 	total_count: u64,
 	loaded: Rc<RefCell<bool>>,
+	emoji_mode: DisplayNameEmojiMode,
 	pub account_id: Option<String>,
 }
 
@@ -28,6 +30,7 @@ impl FollowListDialog {
 		first_page: &[Account],
 		total_count: u64,
 		account_id: Option<String>,
+		emoji_mode: DisplayNameEmojiMode,
 		net_tx: Sender<NetworkCommand>,
 		ui_tx: crate::ui_wake::UiCommandSender,
 		on_view_timeline: F,
@@ -50,11 +53,11 @@ impl FollowListDialog {
 			.build();
 
 		for account in first_page {
-			account_list.append(&Self::account_label(account));
+			account_list.append(&Self::account_label(account, emoji_mode));
 		}
 		if let Some(first) = first_page.first() {
 			account_list.set_selection(0, true);
-			profile_text.set_value(&first.profile_display());
+			profile_text.set_value(&first.profile_display(emoji_mode));
 		}
 
 		let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
@@ -90,7 +93,7 @@ impl FollowListDialog {
 			if let Some(index) = selection
 				&& let Some(account) = accounts_sel.borrow().get(index)
 			{
-				let mut text = account.profile_display();
+				let mut text = account.profile_display(emoji_mode);
 				if let Some(rel) = relationships_sel.borrow().get(&account.id) {
 					user_actions::append_relationship_text(&mut text, rel, false);
 				}
@@ -192,7 +195,8 @@ impl FollowListDialog {
 				return;
 			}
 			if id == user_actions::ID_ACTION_ADD_TO_LIST {
-				let _ = ui_tx.send(crate::commands::UiCommand::AddUserToList(account_id));
+				let acct = account.acct.clone();
+				let _ = ui_tx.send(crate::commands::UiCommand::AddUserToList { account_id, acct });
 				return;
 			}
 			let rel = relationships_handler.borrow().get(&account_id).cloned();
@@ -278,6 +282,7 @@ impl FollowListDialog {
 			title_base: title.to_string(),
 			total_count,
 			loaded: Rc::new(RefCell::new(false)),
+			emoji_mode,
 			account_id,
 		}
 	}
@@ -296,7 +301,7 @@ impl FollowListDialog {
 		if let Some(sel) = self.account_list.get_selection() {
 			let accounts = self.accounts.borrow();
 			if let Some(account) = accounts.get(sel as usize) {
-				let mut text = account.profile_display();
+				let mut text = account.profile_display(self.emoji_mode);
 				if let Some(rel) = self.relationships.borrow().get(&account.id) {
 					user_actions::append_relationship_text(&mut text, rel, false);
 				}
@@ -307,7 +312,7 @@ impl FollowListDialog {
 
 	pub fn append_accounts(&self, accounts: &[Account]) {
 		for account in accounts {
-			self.account_list.append(&Self::account_label(account));
+			self.account_list.append(&Self::account_label(account, self.emoji_mode));
 		}
 		self.accounts.borrow_mut().extend_from_slice(accounts);
 		let shown = self.accounts.borrow().len() as u64;
@@ -321,8 +326,8 @@ impl FollowListDialog {
 		self.dialog.set_label(&Self::make_title(&self.title_base, shown, self.total_count, true));
 	}
 
-	fn account_label(account: &Account) -> String {
-		let name = account.display_name_or_username();
+	fn account_label(account: &Account, emoji_mode: DisplayNameEmojiMode) -> String {
+		let name = account.timeline_display_name(emoji_mode);
 		if name.is_empty() { format!("@{}", account.acct) } else { format!("{} (@{})", name, account.acct) }
 	}
 
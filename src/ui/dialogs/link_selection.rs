@@ -3,7 +3,9 @@ use wxdragon::prelude::*;
 use crate::html::Link;
 
 pub fn show_link_selection_dialog(frame: &Frame, links: &[Link]) -> Option<String> {
-	let dialog = Dialog::builder(frame, "Select Link").with_size(500, 300).build();
+	let title =
+		if links.len() == 1 { "Open Link".to_string() } else { format!("Open Link ({} Links in Post)", links.len()) };
+	let dialog = Dialog::builder(frame, &title).with_size(500, 300).build();
 	let panel = Panel::builder(&dialog).build();
 	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
 	let list_label = StaticText::builder(&panel).with_label("Links found in post:").build();
@@ -18,9 +20,11 @@ pub fn show_link_selection_dialog(frame: &Frame, links: &[Link]) -> Option<Strin
 	let open_button = Button::builder(&panel).with_id(ID_OK).with_label("Open").build();
 	open_button.set_default();
 	let copy_button = Button::builder(&panel).with_label("Copy").build();
+	let copy_all_button = Button::builder(&panel).with_label("Copy &All").build();
 	let close_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("Close").build();
 	button_sizer.add(&open_button, 0, SizerFlag::Right, 8);
 	button_sizer.add(&copy_button, 0, SizerFlag::Right, 8);
+	button_sizer.add(&copy_all_button, 0, SizerFlag::Right, 8);
 	button_sizer.add_stretch_spacer(1);
 	button_sizer.add(&close_button, 0, SizerFlag::Right, 8);
 	main_sizer.add(&list_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
@@ -46,6 +50,10 @@ pub fn show_link_selection_dialog(frame: &Frame, links: &[Link]) -> Option<Strin
 	copy_button.on_click(move |_| {
 		copy_action_btn();
 	});
+	let all_urls = links.iter().map(|link| link.url.as_str()).collect::<Vec<_>>().join("\n");
+	copy_all_button.on_click(move |_| {
+		let _ = Clipboard::get().set_text(&all_urls);
+	});
 	let copy_action_key = copy_action;
 	link_list_copy.on_key_down(move |event| {
 		if let WindowEventData::Keyboard(ref key_event) = event {
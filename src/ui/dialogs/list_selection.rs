@@ -1,6 +1,6 @@
 use wxdragon::prelude::*;
 
-use crate::mastodon::List;
+use crate::mastodon::{Account, List};
 
 pub fn show_list_selection_dialog(frame: &Frame, lists: &[List], title: &str, button_label: &str) -> Option<List> {
 	let dialog = Dialog::builder(frame, title).with_size(300, 400).build();
@@ -40,3 +40,96 @@ pub fn show_list_selection_dialog(frame: &Frame, lists: &[List], title: &str, bu
 	}
 	None
 }
+
+/// Lets the user tick which accounts to put on a new list. All accounts start checked.
+pub fn prompt_for_list_members(frame: &Frame, accounts: &[Account]) -> Option<Vec<Account>> {
+	let dialog = Dialog::builder(frame, "Create List from Authors").with_size(400, 450).build();
+	let panel = Panel::builder(&dialog).build();
+	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let accounts_label = StaticText::builder(&panel).with_label("&Authors to add:").build();
+	let choices: Vec<String> =
+		accounts.iter().map(|a| format!("{} (@{})", a.display_name_or_username(), a.acct)).collect();
+	let accounts_list = CheckListBox::builder(&panel).with_choices(choices).build();
+	for index in 0..accounts.len() {
+		accounts_list.check(u32::try_from(index).unwrap_or(u32::MAX), true);
+	}
+	let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	let ok_button = Button::builder(&panel).with_id(ID_OK).with_label("&Next").build();
+	ok_button.set_default();
+	let cancel_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("Cancel").build();
+	button_sizer.add_stretch_spacer(1);
+	button_sizer.add(&ok_button, 0, SizerFlag::Right, 8);
+	button_sizer.add(&cancel_button, 0, SizerFlag::Right, 8);
+	main_sizer.add(&accounts_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add(&accounts_list, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add_sizer(&button_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	panel.set_sizer(main_sizer, true);
+	let dialog_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
+	dialog.set_sizer(dialog_sizer, true);
+	dialog.set_affirmative_id(ID_OK);
+	dialog.set_escape_id(ID_CANCEL);
+	dialog.centre();
+	accounts_list.set_focus();
+	if dialog.show_modal() != ID_OK {
+		return None;
+	}
+	let selected = accounts
+		.iter()
+		.enumerate()
+		.filter(|(index, _)| accounts_list.is_checked(u32::try_from(*index).unwrap_or(u32::MAX)))
+		.map(|(_, account)| account.clone())
+		.collect();
+	Some(selected)
+}
+
+/// Shows every list with the ones containing `acct` checked. Returns the lists to add the account to and remove it from.
+pub fn prompt_for_list_memberships(
+	frame: &Frame,
+	acct: &str,
+	lists: &[List],
+	containing: &[List],
+) -> Option<(Vec<List>, Vec<List>)> {
+	let dialog = Dialog::builder(frame, &format!("Lists Containing @{acct}")).with_size(400, 400).build();
+	let panel = Panel::builder(&dialog).build();
+	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let lists_label = StaticText::builder(&panel).with_label("&Lists:").build();
+	let choices: Vec<String> = lists.iter().map(|l| l.title.clone()).collect();
+	let lists_ctrl = CheckListBox::builder(&panel).with_choices(choices).build();
+	let was_member: Vec<bool> = lists.iter().map(|l| containing.iter().any(|c| c.id == l.id)).collect();
+	for (index, member) in was_member.iter().enumerate() {
+		lists_ctrl.check(u32::try_from(index).unwrap_or(u32::MAX), *member);
+	}
+	let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	let ok_button = Button::builder(&panel).with_id(ID_OK).with_label("&Save").build();
+	ok_button.set_default();
+	let cancel_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("Cancel").build();
+	button_sizer.add_stretch_spacer(1);
+	button_sizer.add(&ok_button, 0, SizerFlag::Right, 8);
+	button_sizer.add(&cancel_button, 0, SizerFlag::Right, 8);
+	main_sizer.add(&lists_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add(&lists_ctrl, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add_sizer(&button_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	panel.set_sizer(main_sizer, true);
+	let dialog_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
+	dialog.set_sizer(dialog_sizer, true);
+	dialog.set_affirmative_id(ID_OK);
+	dialog.set_escape_id(ID_CANCEL);
+	dialog.centre();
+	lists_ctrl.set_focus();
+	if dialog.show_modal() != ID_OK {
+		return None;
+	}
+	let mut add = Vec::new();
+	let mut remove = Vec::new();
+	for (index, list) in lists.iter().enumerate() {
+		let checked = lists_ctrl.is_checked(u32::try_from(index).unwrap_or(u32::MAX));
+		if checked && !was_member[index] {
+			add.push(list.clone());
+		} else if !checked && was_member[index] {
+			remove.push(list.clone());
+		}
+	}
+	Some((add, remove))
+}
@@ -9,12 +9,11 @@ use std::{
 	time::Duration,
 };
 
-use accesskit::{ActionHandler, ActionRequest, ActivationHandler, Node, NodeId, Role, Tree, TreeUpdate};
-use accesskit_windows::SubclassingAdapter;
 use url::Url;
-use windows::Win32::Foundation::HWND;
 use wxdragon::{prelude::*, widgets::media_ctrl::SeekMode};
 
+use crate::ui::live_region::LiveRegion;
+
 thread_local! {
 	static ACTIVE_PROGRESS: RefCell<Option<ProgressDialog>> = const { RefCell::new(None) };
 	static ACTIVE_MEDIA_FRAMES: RefCell<std::collections::HashMap<usize, Frame>> = RefCell::new(std::collections::HashMap::new());
@@ -23,83 +22,10 @@ thread_local! {
 
 static DOWNLOAD_TASK_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
 
-const LR_ROOT_ID: NodeId = NodeId(1);
-const LR_ANNOUNCEMENT_ID: NodeId = NodeId(2);
-
-struct MediaActivationHandler;
-
-impl ActivationHandler for MediaActivationHandler {
-	fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
-		let mut root = Node::new(Role::Window);
-		root.set_children(vec![LR_ANNOUNCEMENT_ID]);
-
-		let mut ann_node = Node::new(Role::Label);
-		ann_node.set_value("");
-		ann_node.set_live(accesskit::Live::Polite);
-
-		Some(TreeUpdate {
-			nodes: vec![(LR_ANNOUNCEMENT_ID, ann_node), (LR_ROOT_ID, root)],
-			tree: Some(Tree::new(LR_ROOT_ID)),
-			focus: LR_ROOT_ID,
-			tree_id: accesskit::TreeId::ROOT,
-		})
-	}
-}
-
-struct MediaActionHandler;
-
-impl ActionHandler for MediaActionHandler {
-	fn do_action(&mut self, _request: ActionRequest) {}
-}
-
-#[derive(Clone)]
-struct MediaLiveRegion {
-	adapter: Rc<RefCell<SubclassingAdapter>>,
-	last_announcement: Rc<RefCell<Option<String>>>,
-}
-
-impl MediaLiveRegion {
-	fn new(frame: &Frame) -> Self {
-		let hwnd = HWND(frame.get_handle() as *mut _);
-		let last_announcement = Rc::new(RefCell::new(None::<String>));
-		let adapter = SubclassingAdapter::new(hwnd, MediaActivationHandler, MediaActionHandler);
-		Self { adapter: Rc::new(RefCell::new(adapter)), last_announcement }
-	}
-
-	fn announce(&self, text: &str) {
-		let mut new_text = text.to_string();
-		let mut last = self.last_announcement.borrow_mut();
-		if let Some(old) = last.as_ref() {
-			if *old == new_text {
-				new_text.push('\u{00A0}');
-			}
-		}
-		*last = Some(new_text.clone());
-
-		let mut node = Node::new(Role::Label);
-		node.set_value(new_text);
-		node.set_live(accesskit::Live::Polite);
-
-		let mut root = Node::new(Role::Window);
-		root.set_children(vec![LR_ANNOUNCEMENT_ID]);
-
-		let update = TreeUpdate {
-			nodes: vec![(LR_ANNOUNCEMENT_ID, node), (LR_ROOT_ID, root)],
-			tree: None,
-			focus: LR_ROOT_ID,
-			tree_id: accesskit::TreeId::ROOT,
-		};
-		let mut adapter = self.adapter.borrow_mut();
-		if let Some(events) = adapter.update_if_active(|| update) {
-			events.raise();
-		}
-	}
-}
-
 pub fn show_media_player(_parent: &dyn WxWidget, url: String, _access_token: Option<String>) {
 	const ID_MEDIA_CTRL: i32 = 10000;
 	let frame = Frame::builder().with_title("Media Player").with_size(Size::new(800, 600)).build();
-	let lr = MediaLiveRegion::new(&frame);
+	let lr = LiveRegion::new(&frame);
 	let sizer = BoxSizer::builder(Orientation::Vertical).build();
 	let media_ctrl = wxdragon::widgets::MediaCtrl::builder(&frame)
 		.with_id(ID_MEDIA_CTRL)
@@ -236,7 +162,10 @@ pub fn show_media_player(_parent: &dyn WxWidget, url: String, _access_token: Opt
 
 						thread::spawn(move || {
 							let result: anyhow::Result<()> = (|| {
-								let client = reqwest::blocking::Client::builder().user_agent("Fedra/0.1").build()?;
+								let client = crate::proxy::configure(
+									reqwest::blocking::Client::builder().user_agent(crate::mastodon::user_agent()),
+								)?
+								.build()?;
 								let mut resp = client.get(download_url).send()?.error_for_status()?;
 								let total_size = resp.content_length().unwrap_or(0);
 								d_total.store(total_size, Ordering::Relaxed);
@@ -4,10 +4,12 @@ use wxdragon::prelude::*;
 
 use crate::{
 	config::{
-		AutoloadMode, ContentWarningDisplay, DefaultTimeline, DisplayNameEmojiMode, HotkeyConfig,
-		NotificationPreference, PerTimelineTemplates, PostTemplates, SortOrder,
+		APP_NAME, AutoCwRule, AutoloadMode, ContentWarningDisplay, DefaultTimeline, DisplayNameEmojiMode, HotkeyConfig,
+		LinkOpenMode, NotificationPreference, NotificationTemplates, PerTimelineTemplates, PostTemplates, SortOrder,
+		TimelineEnterAction,
 	},
-	template::{DEFAULT_BOOST_TEMPLATE, DEFAULT_POST_TEMPLATE},
+	template::{self, DEFAULT_BOOST_TEMPLATE, DEFAULT_POST_TEMPLATE},
+	ui::dialogs::show_warning_widget,
 };
 
 pub fn prompt_for_default_timelines(frame: &Frame, initial: &[DefaultTimeline]) -> Option<Vec<DefaultTimeline>> {
@@ -117,6 +119,106 @@ fn prompt_for_hotkey(parent: &dyn WxWidget, initial: &HotkeyConfig) -> Option<Ho
 	})
 }
 
+const NOTIFICATION_TEMPLATE_KINDS: [&str; 5] = ["Mention", "Favorite", "Boost", "Follow", "Poll"];
+
+fn notification_template_defaults() -> [&'static str; 5] {
+	[
+		template::DEFAULT_MENTION_NOTIFICATION_TEMPLATE,
+		template::DEFAULT_FAVORITE_NOTIFICATION_TEMPLATE,
+		DEFAULT_BOOST_TEMPLATE,
+		template::DEFAULT_FOLLOW_NOTIFICATION_TEMPLATE,
+		template::DEFAULT_POLL_NOTIFICATION_TEMPLATE,
+	]
+}
+
+fn prompt_for_notification_templates(
+	parent: &dyn WxWidget,
+	initial: &NotificationTemplates,
+) -> Option<NotificationTemplates> {
+	let dialog = Dialog::builder(parent, "Notification Templates").with_size(500, 360).build();
+	let panel = Panel::builder(&dialog).build();
+	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let defaults = notification_template_defaults();
+	let values = Rc::new(RefCell::new([
+		initial.resolve_mention_template().to_string(),
+		initial.resolve_favorite_template().to_string(),
+		initial.boost.clone().unwrap_or_else(|| DEFAULT_BOOST_TEMPLATE.to_string()),
+		initial.resolve_follow_template().to_string(),
+		initial.resolve_poll_template().to_string(),
+	]));
+
+	let kind_label = StaticText::builder(&panel).with_label("Notification &type:").build();
+	let kind_choices: Vec<String> = NOTIFICATION_TEMPLATE_KINDS.iter().map(|kind| (*kind).to_string()).collect();
+	let kind_choice = ComboBox::builder(&panel).with_choices(kind_choices).with_style(ComboBoxStyle::ReadOnly).build();
+	kind_choice.set_selection(0);
+	let kind_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	kind_sizer.add(&kind_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
+	kind_sizer.add(&kind_choice, 1, SizerFlag::Expand, 0);
+	let template_label = StaticText::builder(&panel).with_label("T&emplate:").build();
+	let template_text =
+		TextCtrl::builder(&panel).with_style(TextCtrlStyle::MultiLine).with_value(&values.borrow()[0]).build();
+	let help_label = StaticText::builder(&panel)
+		.with_label(
+			"Available: {{ actor }}, {{ actor_username }}, {{ post }}, and the post template variables of the \
+			 notification's post, such as {{ author }} and {{ content }}.",
+		)
+		.build();
+	let reset_button = Button::builder(&panel).with_label("&Reset to default").build();
+	main_sizer.add_sizer(&kind_sizer, 0, SizerFlag::Expand | SizerFlag::All, 10);
+	main_sizer.add(&template_label, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 10);
+	main_sizer.add(&template_text, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 10);
+	main_sizer.add(&help_label, 0, SizerFlag::Expand | SizerFlag::All, 10);
+	main_sizer.add(&reset_button, 0, SizerFlag::Left | SizerFlag::Right, 10);
+
+	let current_kind = Rc::new(RefCell::new(0usize));
+	let values_change = values.clone();
+	let current_kind_change = current_kind.clone();
+	kind_choice.on_selection_changed(move |_| {
+		let Some(index) = kind_choice.get_selection() else { return };
+		let index = index as usize;
+		let mut values = values_change.borrow_mut();
+		let previous = *current_kind_change.borrow();
+		values[previous] = template_text.get_value();
+		template_text.set_value(&values[index]);
+		*current_kind_change.borrow_mut() = index;
+	});
+	let current_kind_reset = current_kind.clone();
+	reset_button.on_click(move |_| {
+		template_text.set_value(defaults[*current_kind_reset.borrow()]);
+	});
+
+	let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	let ok_button = Button::builder(&panel).with_id(ID_OK).with_label("OK").build();
+	let cancel_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("Cancel").build();
+	button_sizer.add_stretch_spacer(1);
+	button_sizer.add(&ok_button, 0, SizerFlag::Right, 8);
+	button_sizer.add(&cancel_button, 0, SizerFlag::Right, 8);
+	main_sizer.add_sizer(&button_sizer, 0, SizerFlag::Expand | SizerFlag::All, 10);
+
+	panel.set_sizer(main_sizer, true);
+	let dialog_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
+	dialog.set_sizer(dialog_sizer, true);
+	dialog.set_affirmative_id(ID_OK);
+	dialog.set_escape_id(ID_CANCEL);
+	dialog.centre();
+
+	if dialog.show_modal() != ID_OK {
+		return None;
+	}
+	let mut values = values.borrow().clone();
+	values[*current_kind.borrow()] = template_text.get_value();
+	let [mention, favorite, boost, follow, poll] = values;
+	let custom = |value: String, default: &str| (!value.trim().is_empty() && value != default).then_some(value);
+	Some(NotificationTemplates {
+		mention: custom(mention, defaults[0]),
+		favorite: custom(favorite, defaults[1]),
+		boost: custom(boost, defaults[2]),
+		follow: custom(follow, defaults[3]),
+		poll: custom(poll, defaults[4]),
+	})
+}
+
 fn hotkey_key_display_name(key: char) -> String {
 	match key {
 		' ' => "Space".to_string(),
@@ -140,7 +242,8 @@ fn parse_hotkey_key(input: &str) -> Option<char> {
 
 pub struct OptionsDialogInput {
 	pub enter_to_send: bool,
-	pub always_show_link_dialog: bool,
+	pub link_open_mode: LinkOpenMode,
+	pub link_prompt_threshold: u8,
 	pub show_link_previews: bool,
 	pub strip_tracking: bool,
 	pub quick_action_keys: bool,
@@ -160,12 +263,38 @@ pub struct OptionsDialogInput {
 	pub filters: crate::config::TimelineFilters,
 	pub find_loading_mode: crate::config::FindLoadingMode,
 	pub window_title_template: String,
+	pub oauth_scopes: String,
+	pub announce_stream_status: bool,
+	pub show_follow_markers: bool,
+	pub timeline_enter_action: TimelineEnterAction,
+	pub announce_interaction_counts: bool,
+	pub reply_all_default: bool,
+	pub cw_marks_sensitive: bool,
+	pub notification_templates: NotificationTemplates,
+	pub confirm_own_interactions: bool,
+	pub show_media_indicator: bool,
+	pub debug_logging: bool,
+	pub proxy_url: Option<String>,
+	pub client_name: Option<String>,
+	pub client_website: Option<String>,
+	pub user_agent: Option<String>,
+	pub hide_warned_posts: bool,
+	pub hide_sensitive_media: bool,
+	pub auto_cw_rules: Vec<AutoCwRule>,
+	pub mark_mentions_of_me: bool,
+	pub mention_sound: bool,
+	pub confirm_discard_compose: bool,
+	pub announce_timeline_on_focus: bool,
+	pub show_detail_pane: bool,
+	pub timeline_row_length: u16,
+	pub show_instance_overview: bool,
 }
 
 #[allow(clippy::struct_excessive_bools)]
 pub struct OptionsDialogResult {
 	pub enter_to_send: bool,
-	pub always_show_link_dialog: bool,
+	pub link_open_mode: LinkOpenMode,
+	pub link_prompt_threshold: u8,
 	pub show_link_previews: bool,
 	pub strip_tracking: bool,
 	pub quick_action_keys: bool,
@@ -185,6 +314,31 @@ pub struct OptionsDialogResult {
 	pub filters: crate::config::TimelineFilters,
 	pub find_loading_mode: crate::config::FindLoadingMode,
 	pub window_title_template: String,
+	pub oauth_scopes: String,
+	pub announce_stream_status: bool,
+	pub show_follow_markers: bool,
+	pub timeline_enter_action: TimelineEnterAction,
+	pub announce_interaction_counts: bool,
+	pub reply_all_default: bool,
+	pub cw_marks_sensitive: bool,
+	pub notification_templates: NotificationTemplates,
+	pub confirm_own_interactions: bool,
+	pub show_media_indicator: bool,
+	pub debug_logging: bool,
+	pub proxy_url: Option<String>,
+	pub client_name: Option<String>,
+	pub client_website: Option<String>,
+	pub user_agent: Option<String>,
+	pub hide_warned_posts: bool,
+	pub hide_sensitive_media: bool,
+	pub auto_cw_rules: Vec<AutoCwRule>,
+	pub mark_mentions_of_me: bool,
+	pub mention_sound: bool,
+	pub confirm_discard_compose: bool,
+	pub announce_timeline_on_focus: bool,
+	pub show_detail_pane: bool,
+	pub timeline_row_length: u16,
+	pub show_instance_overview: bool,
 }
 
 type TemplateState = HashMap<String, (String, String, String)>;
@@ -192,7 +346,8 @@ type TemplateState = HashMap<String, (String, String, String)>;
 pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<OptionsDialogResult> {
 	let OptionsDialogInput {
 		enter_to_send,
-		always_show_link_dialog,
+		link_open_mode,
+		link_prompt_threshold,
 		show_link_previews,
 		strip_tracking,
 		quick_action_keys,
@@ -212,6 +367,31 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 		filters,
 		find_loading_mode,
 		window_title_template,
+		oauth_scopes,
+		announce_stream_status,
+		show_follow_markers,
+		timeline_enter_action,
+		announce_interaction_counts,
+		reply_all_default,
+		cw_marks_sensitive,
+		notification_templates,
+		confirm_own_interactions,
+		show_media_indicator,
+		debug_logging,
+		proxy_url,
+		client_name,
+		client_website,
+		user_agent,
+		hide_warned_posts,
+		hide_sensitive_media,
+		auto_cw_rules,
+		mark_mentions_of_me,
+		mention_sound,
+		confirm_discard_compose,
+		announce_timeline_on_focus,
+		show_detail_pane,
+		timeline_row_length,
+		show_instance_overview,
 	} = input;
 	let dialog = Dialog::builder(frame, "Options").with_size(500, 520).build();
 	let panel = Panel::builder(&dialog).build();
@@ -221,18 +401,72 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 	let general_sizer = BoxSizer::builder(Orientation::Vertical).build();
 	let enter_checkbox = CheckBox::builder(&general_panel).with_label("Use &enter to send posts").build();
 	enter_checkbox.set_value(enter_to_send);
-	let link_checkbox = CheckBox::builder(&general_panel).with_label("Always prompt to open &links").build();
-	link_checkbox.set_value(always_show_link_dialog);
+	let cw_sensitive_checkbox =
+		CheckBox::builder(&general_panel).with_label("Mark media as &sensitive when adding a content warning").build();
+	cw_sensitive_checkbox.set_value(cw_marks_sensitive);
+	let auto_cw_label = StaticText::builder(&general_panel)
+		.with_label("Automatic content &warnings, one rule per line (keyword, keyword = warning text):")
+		.build();
+	let auto_cw_text = TextCtrl::builder(&general_panel).with_style(TextCtrlStyle::MultiLine).build();
+	auto_cw_text.set_value(&auto_cw_rules.iter().map(AutoCwRule::to_line).collect::<Vec<_>>().join("\n"));
+	let confirm_discard_checkbox =
+		CheckBox::builder(&general_panel).with_label("&Confirm before discarding an unsaved post").build();
+	confirm_discard_checkbox.set_value(confirm_discard_compose);
+	let link_mode_label = StaticText::builder(&general_panel).with_label("&Open links:").build();
+	let link_mode_choices = vec![
+		"Open the first link".to_string(),
+		"Always ask which link to open".to_string(),
+		"Ask when a post has more links than a set number".to_string(),
+	];
+	let link_mode_choice =
+		ComboBox::builder(&general_panel).with_choices(link_mode_choices).with_style(ComboBoxStyle::ReadOnly).build();
+	link_mode_choice.set_selection(match link_open_mode {
+		LinkOpenMode::OpenFirst => 0,
+		LinkOpenMode::AlwaysPrompt => 1,
+		LinkOpenMode::PromptAboveThreshold => 2,
+	});
+	let link_mode_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	link_mode_sizer.add(&link_mode_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
+	link_mode_sizer.add(&link_mode_choice, 1, SizerFlag::Expand, 0);
+	let link_threshold_label =
+		StaticText::builder(&general_panel).with_label("Ask when a post has more lin&ks than:").build();
+	let link_threshold_spin = SpinCtrl::builder(&general_panel)
+		.with_range(1, 20)
+		.with_initial_value(i32::from(link_prompt_threshold))
+		.build();
+	link_threshold_spin.enable(link_open_mode == LinkOpenMode::PromptAboveThreshold);
+	link_mode_choice.on_selection_changed(move |_| {
+		link_threshold_spin.enable(link_mode_choice.get_selection() == Some(2));
+	});
+	let link_threshold_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	link_threshold_sizer.add(&link_threshold_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
+	link_threshold_sizer.add(&link_threshold_spin, 0, SizerFlag::empty(), 0);
 	let previews_checkbox = CheckBox::builder(&general_panel).with_label("Read &link previews in timelines").build();
 	previews_checkbox.set_value(show_link_previews);
 	let strip_tracking_checkbox =
 		CheckBox::builder(&general_panel).with_label("Strip &tracking parameters from URLs").build();
 	strip_tracking_checkbox.set_value(strip_tracking);
+	let reply_all_checkbox =
+		CheckBox::builder(&general_panel).with_label("Reply key replies to &all mentioned users").build();
+	reply_all_checkbox.set_value(reply_all_default);
+	let confirm_own_checkbox =
+		CheckBox::builder(&general_panel).with_label("Confirm before boosting or favoriting your own &posts").build();
+	confirm_own_checkbox.set_value(confirm_own_interactions);
 	let quick_action_checkbox =
 		CheckBox::builder(&general_panel).with_label("Use &quick action keys in timelines").build();
 	quick_action_checkbox.set_value(quick_action_keys);
+	let stream_status_checkbox =
+		CheckBox::builder(&general_panel).with_label("Announce when live updates &disconnect or reconnect").build();
+	stream_status_checkbox.set_value(announce_stream_status);
+	let instance_overview_checkbox = CheckBox::builder(&general_panel)
+		.with_label("Show an instance's description and &rules before adding an account")
+		.build();
+	instance_overview_checkbox.set_value(show_instance_overview);
 	let update_checkbox = CheckBox::builder(&general_panel).with_label("Check for &updates on startup").build();
 	update_checkbox.set_value(check_for_updates);
+	let debug_log_checkbox =
+		CheckBox::builder(&general_panel).with_label("Write failed requests to a debu&g log").build();
+	debug_log_checkbox.set_value(debug_logging);
 
 	let channel_label = StaticText::builder(&general_panel).with_label("Update Channel:").build();
 	let channel_choices = vec!["Stable".to_string(), "Dev".to_string()];
@@ -264,11 +498,21 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 	notification_sizer.add(&notification_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
 	notification_sizer.add(&notification_choice, 1, SizerFlag::Expand, 0);
 	general_sizer.add(&enter_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
-	general_sizer.add(&link_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add(&cw_sensitive_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add(&auto_cw_label, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top, 8);
+	general_sizer.add(&auto_cw_text, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add(&confirm_discard_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add_sizer(&link_mode_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add_sizer(&link_threshold_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	general_sizer.add(&previews_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	general_sizer.add(&strip_tracking_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add(&reply_all_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add(&confirm_own_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	general_sizer.add(&quick_action_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add(&stream_status_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add(&instance_overview_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	general_sizer.add(&update_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	general_sizer.add(&debug_log_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	general_sizer.add_sizer(&channel_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	general_sizer.add_sizer(&notification_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	let hotkey_button = Button::builder(&general_panel).with_label("Customize Window Hotkey...").build();
@@ -304,13 +548,37 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 	let autoload_sizer = BoxSizer::builder(Orientation::Horizontal).build();
 	autoload_sizer.add(&autoload_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
 	autoload_sizer.add(&autoload_choice, 1, SizerFlag::Expand, 0);
-	let fetch_limit_label =
-		StaticText::builder(&timeline_panel).with_label("Posts to &fetch when loading more:").build();
+	let enter_action_label = StaticText::builder(&timeline_panel).with_label("Enter &key on a post:").build();
+	let enter_action_choices = vec!["View thread".to_string(), "Reply".to_string(), "View post details".to_string()];
+	let enter_action_choice = ComboBox::builder(&timeline_panel)
+		.with_choices(enter_action_choices)
+		.with_style(ComboBoxStyle::ReadOnly)
+		.build();
+	let enter_action_index = match timeline_enter_action {
+		TimelineEnterAction::ViewThread => 0,
+		TimelineEnterAction::Reply => 1,
+		TimelineEnterAction::ViewPost => 2,
+	};
+	enter_action_choice.set_selection(enter_action_index);
+	let enter_action_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	enter_action_sizer.add(&enter_action_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
+	enter_action_sizer.add(&enter_action_choice, 1, SizerFlag::Expand, 0);
+	let fetch_limit_label = StaticText::builder(&timeline_panel).with_label("Posts to &fetch per request:").build();
 	let fetch_limit_spin =
 		SpinCtrl::builder(&timeline_panel).with_range(1, 40).with_initial_value(i32::from(fetch_limit)).build();
 	let fetch_limit_sizer = BoxSizer::builder(Orientation::Horizontal).build();
 	fetch_limit_sizer.add(&fetch_limit_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
 	fetch_limit_sizer.add(&fetch_limit_spin, 0, SizerFlag::empty(), 0);
+	let row_length_label = StaticText::builder(&timeline_panel)
+		.with_label("Shorten entries lon&ger than this many characters (0 for no limit):")
+		.build();
+	let row_length_spin = SpinCtrl::builder(&timeline_panel)
+		.with_range(0, 2000)
+		.with_initial_value(i32::from(timeline_row_length))
+		.build();
+	let row_length_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	row_length_sizer.add(&row_length_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 8);
+	row_length_sizer.add(&row_length_spin, 0, SizerFlag::empty(), 0);
 	let cw_label = StaticText::builder(&timeline_panel).with_label("Content warning display:").build();
 	let cw_choices = vec!["Show inline".to_string(), "Don't show".to_string(), "CW only".to_string()];
 	let cw_choice =
@@ -346,14 +614,55 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 
 	let find_load_checkbox = CheckBox::builder(&timeline_panel).with_label("Load more on find &next").build();
 	find_load_checkbox.set_value(find_loading_mode == crate::config::FindLoadingMode::LoadOnNext);
+	let follow_markers_checkbox =
+		CheckBox::builder(&timeline_panel).with_label("Show follo&w status next to post authors").build();
+	follow_markers_checkbox.set_value(show_follow_markers);
+	let media_indicator_checkbox =
+		CheckBox::builder(&timeline_panel).with_label("Show &media types and counts at the start of posts").build();
+	media_indicator_checkbox.set_value(show_media_indicator);
+	let interaction_counts_checkbox = CheckBox::builder(&timeline_panel)
+		.with_label("Announce boost, favorite and reply &counts when selecting a post")
+		.build();
+	interaction_counts_checkbox.set_value(announce_interaction_counts);
+	let timeline_focus_checkbox = CheckBox::builder(&timeline_panel)
+		.with_label("Announce the timeline name and position when moving to the post &list")
+		.build();
+	timeline_focus_checkbox.set_value(announce_timeline_on_focus);
+	let detail_pane_checkbox = CheckBox::builder(&timeline_panel)
+		.with_label("Show the full text of the selected entry in a &detail pane below the list")
+		.build();
+	detail_pane_checkbox.set_value(show_detail_pane);
+	let hide_warned_checkbox =
+		CheckBox::builder(&timeline_panel).with_label("&Hide posts that server filters only warn about").build();
+	hide_warned_checkbox.set_value(hide_warned_posts);
+	let hide_sensitive_checkbox =
+		CheckBox::builder(&timeline_panel).with_label("Hide descriptions of &sensitive media until expanded").build();
+	hide_sensitive_checkbox.set_value(hide_sensitive_media);
+	let mark_mentions_checkbox = CheckBox::builder(&timeline_panel).with_label("Mark posts that mention &you").build();
+	mark_mentions_checkbox.set_value(mark_mentions_of_me);
+	let mention_sound_checkbox = CheckBox::builder(&timeline_panel)
+		.with_label("&Play a sound when a post mentioning you arrives in a live timeline")
+		.build();
+	mention_sound_checkbox.set_value(mention_sound);
 
 	timeline_sizer.add_sizer(&autoload_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add_sizer(&enter_action_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	timeline_sizer.add_sizer(&fetch_limit_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add_sizer(&row_length_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	timeline_sizer.add_sizer(&cw_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	timeline_sizer.add_sizer(&emoji_mode_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	timeline_sizer.add(&sort_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	timeline_sizer.add(&thread_order_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	timeline_sizer.add(&find_load_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add(&follow_markers_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add(&media_indicator_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add(&interaction_counts_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add(&timeline_focus_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add(&detail_pane_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add(&hide_warned_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add(&hide_sensitive_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add(&mark_mentions_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	timeline_sizer.add(&mention_sound_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	timeline_sizer.add(&restore_timelines_checkbox, 0, SizerFlag::Expand | SizerFlag::All, 8);
 	let customize_button = Button::builder(&timeline_panel).with_label("Customize Default Timelines...").build();
 	let current_defaults = Rc::new(RefCell::new(default_timelines_val));
@@ -424,7 +733,19 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 		.build();
 	let template_button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
 	let reset_button = Button::builder(&template_panel).with_label("Reset to default").build();
+	let notification_templates_button =
+		Button::builder(&template_panel).with_label("&Notification Templates...").build();
+	let current_notification_templates = Rc::new(RefCell::new(notification_templates));
+	let notification_templates_clone = current_notification_templates.clone();
+	let notification_templates_frame = *frame;
+	notification_templates_button.on_click(move |_| {
+		let initial = notification_templates_clone.borrow().clone();
+		if let Some(updated) = prompt_for_notification_templates(&notification_templates_frame, &initial) {
+			*notification_templates_clone.borrow_mut() = updated;
+		}
+	});
 	template_button_sizer.add(&reset_button, 0, SizerFlag::empty(), 0);
+	template_button_sizer.add(&notification_templates_button, 0, SizerFlag::Left, 8);
 	template_sizer.add(
 		&window_title_template_label,
 		0,
@@ -563,6 +884,56 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 	filters_panel.set_sizer(filters_sizer, true);
 	notebook.add_page(&filters_panel, "Filters", false, None);
 
+	let advanced_panel = Panel::builder(&notebook).with_style(PanelStyle::TabTraversal).build();
+	let advanced_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let scopes_label = StaticText::builder(&advanced_panel).with_label("OAuth &scopes for new accounts:").build();
+	let scopes_text = TextCtrl::builder(&advanced_panel).with_value(&oauth_scopes).build();
+	advanced_sizer.add(&scopes_label, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top, 8);
+	advanced_sizer.add(&scopes_text, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	let proxy_label = StaticText::builder(&advanced_panel)
+		.with_label("&Proxy address (leave blank to use the system proxy):")
+		.build();
+	let proxy_text = TextCtrl::builder(&advanced_panel).with_value(proxy_url.as_deref().unwrap_or_default()).build();
+	advanced_sizer.add(&proxy_label, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top, 8);
+	advanced_sizer.add(&proxy_text, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	let client_name_label =
+		StaticText::builder(&advanced_panel).with_label("App &name shown to instance admins for new accounts:").build();
+	let client_name_text =
+		TextCtrl::builder(&advanced_panel).with_value(client_name.as_deref().unwrap_or(APP_NAME)).build();
+	let client_website_label =
+		StaticText::builder(&advanced_panel).with_label("App &website for new accounts:").build();
+	let client_website_text =
+		TextCtrl::builder(&advanced_panel).with_value(client_website.as_deref().unwrap_or_default()).build();
+	let user_agent_label = StaticText::builder(&advanced_panel)
+		.with_label(&format!("&User-Agent (leave blank for {}):", crate::mastodon::default_user_agent()))
+		.build();
+	let user_agent_text =
+		TextCtrl::builder(&advanced_panel).with_value(user_agent.as_deref().unwrap_or_default()).build();
+	advanced_sizer.add(
+		&client_name_label,
+		0,
+		SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top,
+		8,
+	);
+	advanced_sizer.add(&client_name_text, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	advanced_sizer.add(
+		&client_website_label,
+		0,
+		SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top,
+		8,
+	);
+	advanced_sizer.add(&client_website_text, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	advanced_sizer.add(
+		&user_agent_label,
+		0,
+		SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top,
+		8,
+	);
+	advanced_sizer.add(&user_agent_text, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	advanced_sizer.add_stretch_spacer(1);
+	advanced_panel.set_sizer(advanced_sizer, true);
+	notebook.add_page(&advanced_panel, "Advanced", false, None);
+
 	let filters_state = Rc::new(RefCell::new(filters));
 	let current_filter_key = Rc::new(RefCell::new("Home".to_string()));
 	{
@@ -712,6 +1083,34 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 	dialog.set_sizer(dialog_sizer, true);
 	dialog.set_affirmative_id(ID_OK);
 	dialog.set_escape_id(ID_CANCEL);
+	let dialog_ok = dialog;
+	let proxy_text_ok = proxy_text;
+	let auto_cw_text_ok = auto_cw_text;
+	ok_button.on_click(move |_| {
+		let proxy = proxy_text_ok.get_value();
+		let invalid_rule = auto_cw_text_ok
+			.get_value()
+			.lines()
+			.position(|line| !line.trim().is_empty() && AutoCwRule::parse(line).is_none())
+			.map(|index| index + 1);
+		if !proxy.trim().is_empty()
+			&& let Err(message) = crate::proxy::parse_proxy_url(&proxy)
+		{
+			show_warning_widget(&dialog_ok, &message, "Invalid Proxy");
+			proxy_text_ok.set_focus();
+		} else if let Some(line) = invalid_rule {
+			show_warning_widget(
+				&dialog_ok,
+				&format!(
+					"Line {line} of the automatic content warnings isn't a rule. Write each rule as keyword, keyword = warning text."
+				),
+				"Invalid Content Warning Rule",
+			);
+			auto_cw_text_ok.set_focus();
+		} else {
+			dialog_ok.end_modal(ID_OK);
+		}
+	});
 	dialog.centre();
 	let result = dialog.show_modal();
 	if result != ID_OK {
@@ -737,6 +1136,12 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 		Some(2) => AutoloadMode::AtBoundary,
 		_ => autoload,
 	};
+	let new_enter_action = match enter_action_choice.get_selection() {
+		Some(0) => TimelineEnterAction::ViewThread,
+		Some(1) => TimelineEnterAction::Reply,
+		Some(2) => TimelineEnterAction::ViewPost,
+		_ => timeline_enter_action,
+	};
 	let new_fetch_limit = u8::try_from(fetch_limit_spin.value()).unwrap_or(1).clamp(1, 40);
 	let new_notification_preference = match notification_choice.get_selection() {
 		Some(0) => crate::config::NotificationPreference::Classic,
@@ -762,6 +1167,9 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 			(post_template_text.get_value(), boost_template_text.get_value(), quote_template_text.get_value()),
 		);
 	}
+	let new_oauth_scopes = scopes_text.get_value().split_whitespace().collect::<Vec<_>>().join(" ");
+	let new_oauth_scopes =
+		if new_oauth_scopes.is_empty() { crate::mastodon::DEFAULT_SCOPES.to_string() } else { new_oauth_scopes };
 	let new_templates = {
 		let ts = template_state.borrow();
 		let mut per_timeline = HashMap::new();
@@ -783,10 +1191,20 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 	};
 	Some(OptionsDialogResult {
 		enter_to_send: enter_checkbox.get_value(),
-		always_show_link_dialog: link_checkbox.get_value(),
+		cw_marks_sensitive: cw_sensitive_checkbox.get_value(),
+		confirm_discard_compose: confirm_discard_checkbox.get_value(),
+		auto_cw_rules: auto_cw_text.get_value().lines().filter_map(AutoCwRule::parse).collect(),
+		link_open_mode: match link_mode_choice.get_selection() {
+			Some(0) => LinkOpenMode::OpenFirst,
+			Some(1) => LinkOpenMode::AlwaysPrompt,
+			Some(2) => LinkOpenMode::PromptAboveThreshold,
+			_ => link_open_mode,
+		},
+		link_prompt_threshold: u8::try_from(link_threshold_spin.value()).unwrap_or(1).clamp(1, 20),
 		show_link_previews: previews_checkbox.get_value(),
 		strip_tracking: strip_tracking_checkbox.get_value(),
 		quick_action_keys: quick_action_checkbox.get_value(),
+		show_instance_overview: instance_overview_checkbox.get_value(),
 		check_for_updates: update_checkbox.get_value(),
 		restore_open_timelines: restore_timelines_checkbox.get_value(),
 		update_channel: new_update_channel,
@@ -803,5 +1221,27 @@ pub fn prompt_for_options(frame: &Frame, input: OptionsDialogInput) -> Option<Op
 		filters: filters_state.borrow().clone(),
 		find_loading_mode: new_find_loading_mode,
 		window_title_template: window_title_template_text.get_value(),
+		oauth_scopes: new_oauth_scopes,
+		announce_stream_status: stream_status_checkbox.get_value(),
+		show_follow_markers: follow_markers_checkbox.get_value(),
+		show_media_indicator: media_indicator_checkbox.get_value(),
+		timeline_enter_action: new_enter_action,
+		announce_interaction_counts: interaction_counts_checkbox.get_value(),
+		announce_timeline_on_focus: timeline_focus_checkbox.get_value(),
+		show_detail_pane: detail_pane_checkbox.get_value(),
+		timeline_row_length: u16::try_from(row_length_spin.value()).unwrap_or(0).min(2000),
+		reply_all_default: reply_all_checkbox.get_value(),
+		confirm_own_interactions: confirm_own_checkbox.get_value(),
+		debug_logging: debug_log_checkbox.get_value(),
+		proxy_url: Some(proxy_text.get_value().trim().to_string()).filter(|proxy| !proxy.is_empty()),
+		client_name: Some(client_name_text.get_value().trim().to_string())
+			.filter(|name| !name.is_empty() && name != APP_NAME),
+		client_website: Some(client_website_text.get_value().trim().to_string()).filter(|website| !website.is_empty()),
+		user_agent: Some(user_agent_text.get_value().trim().to_string()).filter(|agent| !agent.is_empty()),
+		hide_warned_posts: hide_warned_checkbox.get_value(),
+		hide_sensitive_media: hide_sensitive_checkbox.get_value(),
+		mark_mentions_of_me: mark_mentions_checkbox.get_value(),
+		mention_sound: mention_sound_checkbox.get_value(),
+		notification_templates: current_notification_templates.borrow().clone(),
 	})
 }
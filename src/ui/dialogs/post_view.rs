@@ -1,10 +1,13 @@
+use std::collections::HashMap;
+
 use wxdragon::{
 	event::{WebViewEventData, WebViewEvents},
 	prelude::*,
 	widgets::WebView,
 };
 
-use crate::{ID_BOOST, ID_FAVORITE, ID_REPLY, UiCommand, mastodon::Status};
+use super::common::{remember_geometry, restore_geometry};
+use crate::{ID_BOOKMARK, ID_BOOST, ID_FAVORITE, ID_REPLY, UiCommand, config::DialogGeometry, mastodon::Status};
 
 fn strip_quote_html(html: &str) -> String {
 	if let Some(start) = html.find("<span class=\"quote-inline\">") {
@@ -64,9 +67,16 @@ fn strip_quote_html(html: &str) -> String {
 	html.to_string()
 }
 
-pub fn show_post_view_dialog(parent: &Frame, status: &Status) -> Option<UiCommand> {
+pub fn show_post_view_dialog(
+	parent: &Frame,
+	status: &Status,
+	geometry: &mut HashMap<String, DialogGeometry>,
+) -> Option<UiCommand> {
 	let title = format!("Post by {}", status.account.display_name_or_username());
-	let dialog = Dialog::builder(parent, &title).with_size(600, 500).build();
+	let dialog = Dialog::builder(parent, &title)
+		.with_size(600, 500)
+		.with_style(DialogStyle::DefaultDialogStyle | DialogStyle::ResizeBorder)
+		.build();
 	let panel = Panel::builder(&dialog).build();
 	let sizer = BoxSizer::builder(Orientation::Vertical).build();
 	let web_view = WebView::builder(&panel).build();
@@ -170,11 +180,16 @@ pub fn show_post_view_dialog(parent: &Frame, status: &Status) -> Option<UiComman
 		.with_id(ID_FAVORITE)
 		.with_label(if status.favourited { "Unfavorite" } else { "Favorite" })
 		.build();
+	let bookmark_btn = Button::builder(&panel)
+		.with_id(ID_BOOKMARK)
+		.with_label(if status.bookmarked { "Unbookmark" } else { "Bookmark" })
+		.build();
 	let close_btn = Button::builder(&panel).with_id(ID_CANCEL).with_label("Close").build();
 	close_btn.set_default();
 	button_sizer.add(&reply_btn, 0, SizerFlag::All, 5);
 	button_sizer.add(&boost_btn, 0, SizerFlag::All, 5);
 	button_sizer.add(&fav_btn, 0, SizerFlag::All, 5);
+	button_sizer.add(&bookmark_btn, 0, SizerFlag::All, 5);
 	button_sizer.add_stretch_spacer(1);
 	button_sizer.add(&close_btn, 0, SizerFlag::All, 5);
 	sizer.add(&web_view, 1, SizerFlag::Expand | SizerFlag::All, 5);
@@ -184,7 +199,9 @@ pub fn show_post_view_dialog(parent: &Frame, status: &Status) -> Option<UiComman
 	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
 	dialog.set_sizer(dialog_sizer, true);
 	dialog.set_escape_id(ID_CANCEL);
-	dialog.centre();
+	if !restore_geometry(&dialog, geometry, "post_view") {
+		dialog.centre();
+	}
 	let dialog_reply = dialog;
 	reply_btn.on_click(move |_| {
 		dialog_reply.end_modal(ID_REPLY);
@@ -197,15 +214,21 @@ pub fn show_post_view_dialog(parent: &Frame, status: &Status) -> Option<UiComman
 	fav_btn.on_click(move |_| {
 		dialog_fav.end_modal(ID_FAVORITE);
 	});
+	let dialog_bookmark = dialog;
+	bookmark_btn.on_click(move |_| {
+		dialog_bookmark.end_modal(ID_BOOKMARK);
+	});
 	let dialog_close = dialog;
 	close_btn.on_click(move |_| {
 		dialog_close.end_modal(ID_CANCEL);
 	});
 	let result = dialog.show_modal();
+	remember_geometry(&dialog, geometry, "post_view");
 	match result {
 		ID_REPLY => Some(UiCommand::Reply { reply_all: true }),
 		ID_BOOST => Some(UiCommand::Boost),
 		ID_FAVORITE => Some(UiCommand::Favorite),
+		ID_BOOKMARK => Some(UiCommand::Bookmark),
 		_ => None,
 	}
 }
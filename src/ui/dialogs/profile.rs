@@ -1,9 +1,14 @@
-use std::{cell::RefCell, rc::Rc, sync::mpsc::Sender};
+use std::{
+	cell::{Cell, RefCell},
+	rc::Rc,
+	sync::mpsc::Sender,
+};
 
 use wxdragon::prelude::*;
 
 use super::user_actions;
 use crate::{
+	config::{Config, DisplayNameEmojiMode},
 	mastodon::{Account as MastodonAccount, Mention, Tag},
 	network::NetworkCommand,
 	ui::dialogs::UserLookupAction,
@@ -15,6 +20,7 @@ pub struct ProfileDialog {
 	profile_text: TextCtrl,
 	account: Rc<RefCell<MastodonAccount>>,
 	is_own_account: bool,
+	emoji_mode: DisplayNameEmojiMode,
 }
 
 impl ProfileDialog {
@@ -22,6 +28,7 @@ impl ProfileDialog {
 		frame: &Frame,
 		account: MastodonAccount,
 		current_user_id: Option<&str>,
+		config: &Config,
 		net_tx: std::sync::mpsc::Sender<NetworkCommand>,
 		ui_tx: crate::ui_wake::UiCommandSender,
 		on_view_timeline: F,
@@ -32,14 +39,16 @@ impl ProfileDialog {
 		C: Fn() + 'static + Clone,
 	{
 		let is_own_account = current_user_id.is_some_and(|id| id == account.id);
-		let title = format!("Profile for {}", account.display_name_or_username());
+		let emoji_mode = config.display_name_emoji_mode;
+		let boosts_hidden = Rc::new(Cell::new(config.hides_boosts_from(&account.full_acct())));
+		let title = format!("Profile for {}", account.timeline_display_name(emoji_mode));
 		let dialog = Dialog::builder(frame, &title).with_size(500, 400).build();
 		let panel = Panel::builder(&dialog).build();
 		let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
 		let profile_text = TextCtrl::builder(&panel)
 			.with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly | TextCtrlStyle::DontWrap)
 			.build();
-		profile_text.set_value(&account.profile_display());
+		profile_text.set_value(&account.profile_display(emoji_mode));
 		let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
 		let actions_button = Button::builder(&panel).with_label("&Actions...").build();
 		let timeline_button = Button::builder(&panel).with_id(ID_OK).with_label("View &Timeline").build();
@@ -70,6 +79,7 @@ impl ProfileDialog {
 			actions_button,
 			account_rc.clone(),
 			relationship.clone(),
+			boosts_hidden,
 			net_tx,
 			ui_tx,
 		);
@@ -91,7 +101,7 @@ impl ProfileDialog {
 		});
 
 		dialog.centre();
-		Self { dialog, relationship, profile_text, account: account_rc, is_own_account }
+		Self { dialog, relationship, profile_text, account: account_rc, is_own_account, emoji_mode }
 	}
 
 	pub fn show(&self) {
@@ -104,9 +114,9 @@ impl ProfileDialog {
 
 	pub fn update_account(&self, account: &MastodonAccount) {
 		self.account.replace(account.clone());
-		self.dialog.set_label(&format!("Profile for {}", account.display_name_or_username()));
+		self.dialog.set_label(&format!("Profile for {}", account.timeline_display_name(self.emoji_mode)));
 
-		let mut text = account.profile_display();
+		let mut text = account.profile_display(self.emoji_mode);
 
 		if let Some(rel) = self.relationship.borrow().clone() {
 			user_actions::append_relationship_text(&mut text, &rel, self.is_own_account);
@@ -118,7 +128,7 @@ impl ProfileDialog {
 	pub fn update_relationship(&self, relationship: &crate::mastodon::Relationship) {
 		*self.relationship.borrow_mut() = Some(relationship.clone());
 		let account = self.account.borrow();
-		let mut text = account.profile_display();
+		let mut text = account.profile_display(self.emoji_mode);
 		user_actions::append_relationship_text(&mut text, relationship, self.is_own_account);
 		self.profile_text.set_value(&text);
 	}
@@ -126,13 +136,15 @@ impl ProfileDialog {
 
 pub fn prompt_for_mentions(
 	frame: &Frame,
+	title: &str,
+	label: &str,
 	mentions: &[crate::mastodon::Mention],
 ) -> Option<(Mention, UserLookupAction)> {
 	const ID_VIEW_TIMELINE: i32 = 10041;
-	let dialog = Dialog::builder(frame, "Mentions").with_size(500, 300).build();
+	let dialog = Dialog::builder(frame, title).with_size(500, 300).build();
 	let panel = Panel::builder(&dialog).build();
 	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
-	let list_label = StaticText::builder(&panel).with_label("Users mentioned in post:").build();
+	let list_label = StaticText::builder(&panel).with_label(label).build();
 	let mention_list = ListBox::builder(&panel).build();
 	for mention in mentions {
 		mention_list.append(&format!("@{}", mention.acct));
@@ -179,6 +191,7 @@ pub fn prompt_for_account_list(
 	title: &str,
 	label: &str,
 	accounts: &[MastodonAccount],
+	emoji_mode: DisplayNameEmojiMode,
 ) -> Option<(MastodonAccount, UserLookupAction)> {
 	const ID_VIEW_TIMELINE: i32 = 10043;
 	let dialog = Dialog::builder(frame, title).with_size(500, 300).build();
@@ -187,7 +200,7 @@ pub fn prompt_for_account_list(
 	let list_label = StaticText::builder(&panel).with_label(label).build();
 	let account_list = ListBox::builder(&panel).build();
 	for account in accounts {
-		let name = account.display_name_or_username();
+		let name = account.timeline_display_name(emoji_mode);
 		let entry =
 			if name.is_empty() { format!("@{}", account.acct) } else { format!("{} (@{})", name, account.acct) };
 		account_list.append(&entry);
@@ -229,11 +242,12 @@ pub fn prompt_for_account_list(
 
 pub fn prompt_for_account_selection(
 	frame: &Frame,
+	title: &str,
 	accounts: &[&MastodonAccount],
 	labels: &[&str],
 ) -> Option<(MastodonAccount, UserLookupAction)> {
 	const ID_VIEW_TIMELINE: i32 = 10042;
-	let dialog = Dialog::builder(frame, "Select User").with_size(400, 150).build();
+	let dialog = Dialog::builder(frame, title).with_size(400, 150).build();
 	let panel = Panel::builder(&dialog).build();
 	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
 	let list_label = StaticText::builder(&panel).with_label("User:").build();
@@ -274,10 +288,11 @@ pub fn prompt_for_account_selection(
 
 pub fn prompt_for_account_choice(
 	parent: &dyn WxWidget,
+	title: &str,
 	accounts: &[&MastodonAccount],
 	labels: &[&str],
 ) -> Option<MastodonAccount> {
-	let dialog = Dialog::builder(parent, "Select User").with_size(400, 150).build();
+	let dialog = Dialog::builder(parent, title).with_size(400, 150).build();
 	let panel = Panel::builder(&dialog).build();
 	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
 	let list_label = StaticText::builder(&panel).with_label("User:").build();
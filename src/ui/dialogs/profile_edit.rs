@@ -15,8 +15,14 @@ pub fn show_profile_edit_dialog(frame: &Frame, current: &Account) -> Option<Prof
 	content_sizer.add(&name_label, 0, SizerFlag::All, 5);
 	content_sizer.add(&name_text, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 5);
 	let note_label = StaticText::builder(&scroll_win).with_label("Bio").build();
+	// The source note keeps the user's own line breaks and links; the rendered note is the fallback.
+	let note_value = current
+		.source
+		.as_ref()
+		.and_then(|source| source.note.clone())
+		.unwrap_or_else(|| html::strip_html(&current.note));
 	let note_text = TextCtrl::builder(&scroll_win)
-		.with_value(&html::strip_html(&current.note))
+		.with_value(&note_value)
 		.with_style(TextCtrlStyle::MultiLine)
 		.with_size(Size::new(-1, 100))
 		.build();
@@ -79,8 +85,8 @@ pub fn show_profile_edit_dialog(frame: &Frame, current: &Account) -> Option<Prof
 		content_sizer.add_sizer(&row_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
 		field_controls.push((name_ctrl, val_ctrl));
 	}
-	let (privacy_choice_opt, sensitive_cb_opt, lang_text_opt) =
-		current.source.as_ref().map_or((None, None, None), |source| {
+	let (privacy_choice_opt, sensitive_cb_opt, lang_text_opt, quote_choice_opt) =
+		current.source.as_ref().map_or((None, None, None, None), |source| {
 			let privacy_sizer = BoxSizer::builder(Orientation::Horizontal).build();
 			let privacy_label = StaticText::builder(&scroll_win).with_label("Default post visibility").build();
 			let privacy_choices: Vec<String> =
@@ -92,6 +98,7 @@ pub fn show_profile_edit_dialog(frame: &Frame, current: &Account) -> Option<Prof
 				_ => 0,
 			};
 			privacy_choice.set_selection(sel);
+			privacy_choice.set_name("Default post visibility");
 			privacy_sizer.add(&privacy_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 5);
 			privacy_sizer.add(&privacy_choice, 1, SizerFlag::Expand, 0);
 			content_sizer.add_sizer(&privacy_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
@@ -104,7 +111,25 @@ pub fn show_profile_edit_dialog(frame: &Frame, current: &Account) -> Option<Prof
 			lang_sizer.add(&lang_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 5);
 			lang_sizer.add(&lang_text, 1, SizerFlag::Expand, 0);
 			content_sizer.add_sizer(&lang_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
-			(Some(privacy_choice), Some(sensitive_cb), Some(lang_text))
+			let quote_choice = source.quote_policy.as_deref().map(|policy| {
+				let quote_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+				let quote_label = StaticText::builder(&scroll_win).with_label("Who can quote your posts").build();
+				let quote_choices: Vec<String> =
+					vec!["Anyone".to_string(), "Followers only".to_string(), "Just me".to_string()];
+				let quote_choice = Choice::builder(&scroll_win).with_choices(quote_choices).build();
+				let sel = match policy {
+					"followers" => 1,
+					"nobody" => 2,
+					_ => 0,
+				};
+				quote_choice.set_selection(sel);
+				quote_choice.set_name("Who can quote your posts");
+				quote_sizer.add(&quote_label, 0, SizerFlag::AlignCenterVertical | SizerFlag::Right, 5);
+				quote_sizer.add(&quote_choice, 1, SizerFlag::Expand, 0);
+				content_sizer.add_sizer(&quote_sizer, 0, SizerFlag::Expand | SizerFlag::All, 5);
+				quote_choice
+			});
+			(Some(privacy_choice), Some(sensitive_cb), Some(lang_text), quote_choice)
 		});
 	scroll_win.set_sizer(content_sizer, true);
 	main_sizer.add(&scroll_win, 1, SizerFlag::Expand, 0);
@@ -175,10 +200,20 @@ pub fn show_profile_edit_dialog(frame: &Frame, current: &Account) -> Option<Prof
 			_ => "public",
 		}
 		.to_string();
+		let quote_policy = quote_choice_opt.map(|quote_choice| {
+			match quote_choice.get_selection() {
+				Some(1) => "followers",
+				Some(2) => "nobody",
+				_ => "public",
+			}
+			.to_string()
+		});
 		Some(crate::mastodon::Source {
 			privacy: Some(privacy),
 			sensitive: Some(sensitive_cb.get_value()),
 			language: Some(lang_text.get_value()),
+			note: None,
+			quote_policy,
 		})
 	} else {
 		None
@@ -0,0 +1,205 @@
+use std::{
+	cell::{Cell, RefCell},
+	rc::Rc,
+	thread,
+};
+
+use wxdragon::prelude::*;
+
+use super::common::{KEY_RETURN, show_warning_widget};
+use crate::mastodon::Account;
+
+type SearchDone = Rc<dyn Fn(u64, &str, anyhow::Result<Vec<Account>>)>;
+
+thread_local! {
+	/// Shows account search results in the open recipient picker once a search finishes off the UI thread.
+	static SEARCH_DONE: RefCell<Option<SearchDone>> = const { RefCell::new(None) };
+}
+
+fn account_label(account: &Account) -> String {
+	format!("{} (@{})", account.display_name_or_username(), account.full_acct())
+}
+
+fn refresh_account_list(list: ListBox, accounts: &[Account]) {
+	list.clear();
+	for account in accounts {
+		list.append(&account_label(account));
+	}
+	if !accounts.is_empty() {
+		list.set_selection(0, true);
+	}
+}
+
+/// Picks who a new direct message goes to. `suggestions` fill the results until the first search, and `search` looks
+/// up accounts on the instance from a background thread. Returns the chosen handles, such as "@alice@example.social".
+pub fn prompt_for_dm_recipients(
+	frame: &Frame,
+	suggestions: Vec<Account>,
+	search: impl Fn(&str) -> anyhow::Result<Vec<Account>> + Clone + Send + 'static,
+) -> Option<Vec<String>> {
+	let dialog = Dialog::builder(frame, "New Direct Message").with_size(460, 480).build();
+	let panel = Panel::builder(&dialog).build();
+	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let query_label = StaticText::builder(&panel).with_label("&Find account:").build();
+	let query_input = TextCtrl::builder(&panel).build();
+	let search_button = Button::builder(&panel).with_label("&Search").build();
+	let query_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	query_sizer.add(&query_input, 1, SizerFlag::Expand | SizerFlag::Right, 8);
+	query_sizer.add(&search_button, 0, SizerFlag::AlignCenterVertical, 0);
+	let results_label = StaticText::builder(&panel).with_label("R&esults:").build();
+	let results_list = ListBox::builder(&panel).build();
+	let add_button = Button::builder(&panel).with_label("&Add").build();
+	let recipients_label = StaticText::builder(&panel).with_label("Rec&ipients:").build();
+	let recipients_list = ListBox::builder(&panel).build();
+	let remove_button = Button::builder(&panel).with_label("&Remove").build();
+	remove_button.enable(false);
+	let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	let ok_button = Button::builder(&panel).with_id(ID_OK).with_label("&Write Message").build();
+	let cancel_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("Cancel").build();
+	button_sizer.add_stretch_spacer(1);
+	button_sizer.add(&ok_button, 0, SizerFlag::Right, 8);
+	button_sizer.add(&cancel_button, 0, SizerFlag::Right, 8);
+	main_sizer.add(&query_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add_sizer(&query_sizer, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add(&results_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add(&results_list, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add(&add_button, 0, SizerFlag::Left | SizerFlag::Top, 8);
+	main_sizer.add(&recipients_label, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add(&recipients_list, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add(&remove_button, 0, SizerFlag::Left | SizerFlag::Top, 8);
+	main_sizer.add_sizer(&button_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	panel.set_sizer(main_sizer, true);
+	let dialog_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
+	dialog.set_sizer(dialog_sizer, true);
+	dialog.set_escape_id(ID_CANCEL);
+
+	let results: Rc<RefCell<Vec<Account>>> = Rc::new(RefCell::new(suggestions));
+	let recipients: Rc<RefCell<Vec<Account>>> = Rc::new(RefCell::new(Vec::new()));
+	refresh_account_list(results_list, &results.borrow());
+	add_button.enable(!results.borrow().is_empty());
+
+	// Only the latest search's results are shown, in case an earlier one finishes after it.
+	let search_generation = Rc::new(Cell::new(0u64));
+	let results_done = results.clone();
+	let generation_done = search_generation.clone();
+	let search_done: SearchDone = Rc::new(move |generation, query, result| {
+		if generation != generation_done.get() {
+			return;
+		}
+		match result {
+			Ok(found) => {
+				refresh_account_list(results_list, &found);
+				add_button.enable(!found.is_empty());
+				if found.is_empty() {
+					show_warning_widget(&dialog, &format!("No accounts found for \"{query}\"."), "Search");
+				} else {
+					results_list.set_focus();
+				}
+				*results_done.borrow_mut() = found;
+			}
+			Err(err) => {
+				results_list.clear();
+				show_warning_widget(&dialog, &format!("Search failed: {err}"), "Search");
+			}
+		}
+	});
+	SEARCH_DONE.with(|done| *done.borrow_mut() = Some(search_done));
+	let results_search = results.clone();
+	let run_search = Rc::new(move || {
+		let query = query_input.get_value().trim().to_string();
+		if query.is_empty() {
+			return;
+		}
+		let generation = search_generation.get() + 1;
+		search_generation.set(generation);
+		results_search.borrow_mut().clear();
+		results_list.clear();
+		results_list.append("Searching...");
+		add_button.enable(false);
+		let search = search.clone();
+		thread::spawn(move || {
+			let result = search(&query);
+			wxdragon::call_after(Box::new(move || {
+				let done = SEARCH_DONE.with(|done| done.borrow().clone());
+				if let Some(done) = done {
+					done(generation, &query, result);
+				}
+			}));
+		});
+	});
+	let run_search_button = run_search.clone();
+	search_button.on_click(move |_| run_search_button());
+	query_input.on_key_down(move |event| {
+		if let WindowEventData::Keyboard(ref key_event) = event
+			&& key_event.get_key_code() == Some(KEY_RETURN)
+		{
+			run_search();
+			event.skip(false);
+		} else {
+			event.skip(true);
+		}
+	});
+
+	let recipients_add = recipients.clone();
+	let add_selected = Rc::new(move || {
+		let Some(index) = results_list.get_selection().map(|sel| sel as usize) else { return };
+		let Some(account) = results.borrow().get(index).cloned() else { return };
+		let mut recipients = recipients_add.borrow_mut();
+		if !recipients.iter().any(|existing| existing.id == account.id) {
+			recipients.push(account);
+			refresh_account_list(recipients_list, &recipients);
+			if let Ok(last) = u32::try_from(recipients.len() - 1) {
+				recipients_list.set_selection(last, true);
+			}
+			remove_button.enable(true);
+		}
+	});
+	let add_selected_button = add_selected.clone();
+	add_button.on_click(move |_| add_selected_button());
+	results_list.on_key_down(move |event| {
+		if let WindowEventData::Keyboard(ref key_event) = event
+			&& key_event.get_key_code() == Some(KEY_RETURN)
+		{
+			add_selected();
+			event.skip(false);
+		} else {
+			event.skip(true);
+		}
+	});
+
+	let recipients_remove = recipients.clone();
+	remove_button.on_click(move |_| {
+		let Some(index) = recipients_list.get_selection().map(|sel| sel as usize) else { return };
+		let mut recipients = recipients_remove.borrow_mut();
+		if index < recipients.len() {
+			recipients.remove(index);
+		}
+		refresh_account_list(recipients_list, &recipients);
+		if let Ok(selection) = u32::try_from(index.min(recipients.len().saturating_sub(1))) {
+			recipients_list.set_selection(selection, true);
+		}
+		remove_button.enable(!recipients.is_empty());
+	});
+
+	let recipients_ok = recipients.clone();
+	ok_button.on_click(move |_| {
+		if recipients_ok.borrow().is_empty() {
+			show_warning_widget(&dialog, "Add at least one recipient before writing the message.", "Direct Message");
+			query_input.set_focus();
+		} else {
+			dialog.end_modal(ID_OK);
+		}
+	});
+
+	dialog.centre();
+	query_input.set_focus();
+	let result = dialog.show_modal();
+	SEARCH_DONE.with(|done| done.borrow_mut().take());
+	dialog.destroy();
+	if result != ID_OK {
+		return None;
+	}
+	let handles = recipients.borrow().iter().map(|account| format!("@{}", account.full_acct())).collect();
+	Some(handles)
+}
@@ -0,0 +1,88 @@
+use std::rc::Rc;
+
+use wxdragon::prelude::*;
+
+use crate::keymap::{Shortcut, ShortcutCategory};
+
+pub const ID_OPEN_MANUAL: i32 = 10060;
+
+fn fill_shortcut_list(list: &ListBox, shortcuts: &[Shortcut], category: Option<ShortcutCategory>, query: &str) {
+	list.clear();
+	let query = query.trim();
+	for shortcut in shortcuts {
+		if category.is_some_and(|c| c != shortcut.category) {
+			continue;
+		}
+		if !query.is_empty() && !shortcut.matches_query(query) {
+			continue;
+		}
+		list.append(&format!("{}: {} ({})", shortcut.keys, shortcut.description, shortcut.category.display_name()));
+	}
+	if list.get_count() > 0 {
+		list.set_selection(0, true);
+	}
+}
+
+/// Shows the keyboard shortcut reference. Returns true if the user asked to open the full manual.
+pub fn show_shortcuts_dialog(frame: &Frame, shortcuts: Vec<Shortcut>) -> bool {
+	let dialog = Dialog::builder(frame, "Keyboard Shortcuts").with_size(500, 500).build();
+	let panel = Panel::builder(&dialog).build();
+	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let search_label = StaticText::builder(&panel).with_label("&Search shortcuts:").build();
+	let search_text = TextCtrl::builder(&panel).build();
+	let category_label = StaticText::builder(&panel).with_label("&Category:").build();
+	let mut category_choices = vec!["All".to_string()];
+	category_choices.extend(ShortcutCategory::all().iter().map(|c| c.display_name().to_string()));
+	let category_choice =
+		ComboBox::builder(&panel).with_choices(category_choices).with_style(ComboBoxStyle::ReadOnly).build();
+	category_choice.set_selection(0);
+	let list_label = StaticText::builder(&panel).with_label("S&hortcuts:").build();
+	let shortcut_list = ListBox::builder(&panel).build();
+	let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	let manual_button = Button::builder(&panel).with_id(ID_OPEN_MANUAL).with_label("Open &Manual").build();
+	let close_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("Close").build();
+	button_sizer.add(&manual_button, 0, SizerFlag::Right, 8);
+	button_sizer.add_stretch_spacer(1);
+	button_sizer.add(&close_button, 0, SizerFlag::Right, 8);
+	main_sizer.add(&search_label, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top, 8);
+	main_sizer.add(&search_text, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add(&category_label, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add(&category_choice, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add(&list_label, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add(&shortcut_list, 1, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add_sizer(&button_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	panel.set_sizer(main_sizer, true);
+	let dialog_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
+	dialog.set_sizer(dialog_sizer, true);
+	dialog.set_escape_id(ID_CANCEL);
+
+	let shortcuts = Rc::new(shortcuts);
+	let selected_category = move |choice: &ComboBox| {
+		choice
+			.get_selection()
+			.and_then(|index| (index as usize).checked_sub(1))
+			.and_then(|index| ShortcutCategory::all().get(index).copied())
+	};
+	fill_shortcut_list(&shortcut_list, &shortcuts, None, "");
+
+	let shortcuts_search = shortcuts.clone();
+	search_text.on_text_changed(move |_| {
+		let category = selected_category(&category_choice);
+		fill_shortcut_list(&shortcut_list, &shortcuts_search, category, &search_text.get_value());
+	});
+	let shortcuts_category = shortcuts;
+	category_choice.on_selection_changed(move |_| {
+		let category = selected_category(&category_choice);
+		fill_shortcut_list(&shortcut_list, &shortcuts_category, category, &search_text.get_value());
+	});
+	manual_button.on_click(move |_| {
+		dialog.end_modal(ID_OPEN_MANUAL);
+	});
+
+	dialog.centre();
+	search_text.set_focus();
+	let result = dialog.show_modal();
+	dialog.destroy();
+	result == ID_OPEN_MANUAL
+}
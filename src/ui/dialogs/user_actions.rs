@@ -1,4 +1,9 @@
-use std::{cell::RefCell, fmt::Write, rc::Rc, sync::mpsc::Sender};
+use std::{
+	cell::{Cell, RefCell},
+	fmt::Write,
+	rc::Rc,
+	sync::mpsc::Sender,
+};
 
 use wxdragon::prelude::*;
 
@@ -21,6 +26,8 @@ pub(crate) const ID_ACTION_VIEW_FOLLOWING: i32 = 6011;
 pub(crate) const ID_ACTION_ACCEPT_FOLLOW_REQUEST: i32 = 6012;
 pub(crate) const ID_ACTION_REJECT_FOLLOW_REQUEST: i32 = 6013;
 pub(crate) const ID_ACTION_ADD_TO_LIST: i32 = 6014;
+pub(crate) const ID_ACTION_LISTS_CONTAINING: i32 = 6015;
+pub(crate) const ID_ACTION_TOGGLE_HIDDEN_BOOSTS: i32 = 6016;
 
 pub(crate) fn append_relationship_text(text: &mut String, relationship: &Relationship, is_own_account: bool) {
 	text.push_str("\r\n\r\nRelationship:\r\n");
@@ -62,10 +69,12 @@ pub(crate) fn setup_actions_button(
 	button: Button,
 	account: Rc<RefCell<Account>>,
 	relationship: Rc<RefCell<Option<Relationship>>>,
+	boosts_hidden: Rc<Cell<bool>>,
 	net_tx: Sender<NetworkCommand>,
 	ui_tx: crate::ui_wake::UiCommandSender,
 ) {
 	let relationship_click = relationship.clone();
+	let boosts_hidden_click = boosts_hidden.clone();
 	button.on_click(move |_| {
 		let mut menu = Menu::builder().build();
 		{
@@ -78,6 +87,8 @@ pub(crate) fn setup_actions_button(
 					} else {
 						menu.append(ID_ACTION_SHOW_BOOSTS, "Show Boosts", "", ItemKind::Normal);
 					}
+					let label = if boosts_hidden_click.get() { "Show Boosts in Fedra" } else { "Hide Boosts in Fedra" };
+					menu.append(ID_ACTION_TOGGLE_HIDDEN_BOOSTS, label, "", ItemKind::Normal);
 				} else if r.requested {
 					menu.append(ID_ACTION_UNFOLLOW, "Cancel Follow Request", "", ItemKind::Normal);
 				} else {
@@ -106,6 +117,7 @@ pub(crate) fn setup_actions_button(
 		menu.append(ID_ACTION_VIEW_FOLLOWING, "View Following", "", ItemKind::Normal);
 		menu.append_separator();
 		menu.append(ID_ACTION_ADD_TO_LIST, "Add to List...", "", ItemKind::Normal);
+		menu.append(ID_ACTION_LISTS_CONTAINING, "Lists Containing This User...", "", ItemKind::Normal);
 		panel.popup_menu(&mut menu, None);
 	});
 
@@ -131,7 +143,19 @@ pub(crate) fn setup_actions_button(
 			return;
 		}
 		if id == ID_ACTION_ADD_TO_LIST {
-			let _ = ui_tx.send(crate::commands::UiCommand::AddUserToList(account_id));
+			let acct = account.acct.clone();
+			let _ = ui_tx.send(crate::commands::UiCommand::AddUserToList { account_id, acct });
+			return;
+		}
+		if id == ID_ACTION_TOGGLE_HIDDEN_BOOSTS {
+			let acct = account.full_acct();
+			boosts_hidden.set(!boosts_hidden.get());
+			let _ = ui_tx.send(crate::commands::UiCommand::ToggleHiddenBoosts { acct, name: target_name });
+			return;
+		}
+		if id == ID_ACTION_LISTS_CONTAINING {
+			let acct = account.acct.clone();
+			let _ = net_tx.send(NetworkCommand::FetchAccountLists { account_id, acct });
 			return;
 		}
 		let cmd = match id {
@@ -8,15 +8,18 @@ seq_ids! {
 	ID_REPLY_AUTHOR,
 	ID_FAVORITE,
 	ID_BOOKMARK,
+	ID_TAG_BOOKMARK,
 	ID_BOOST,
 	ID_DELETE_POST,
 	ID_EDIT_POST,
 	ID_PIN_POST,
+	ID_MUTE_CONVERSATION,
 	ID_QUOTE,
 	ID_VOTE,
 	// Post navigation
 	ID_VIEW_THREAD,
 	ID_VIEW_QUOTED_THREAD,
+	ID_LOAD_MORE_REPLIES,
 	ID_OPEN_LINKS,
 	ID_VIEW_IN_BROWSER,
 	ID_VIEW_MENTIONS,
@@ -25,10 +28,14 @@ seq_ids! {
 	ID_VIEW_FAVORITES,
 	ID_COPY_POST,
 	ID_COPY_POST_LINK,
+	ID_COPY_RAW_JSON,
+	ID_POST_STATS,
 	ID_VIEW_POST,
 	ID_PLAY_MEDIA,
 	// User actions
 	ID_TOGGLE_FOLLOW,
+	ID_FOLLOW_BACK,
+	ID_ADD_AUTHOR_TO_LIST,
 	ID_VIEW_PROFILE,
 	ID_VIEW_USER_TIMELINE,
 	ID_OPEN_USER_TIMELINE_BY_INPUT,
@@ -36,14 +43,22 @@ seq_ids! {
 	// Timeline actions
 	ID_LOCAL_TIMELINE,
 	ID_FEDERATED_TIMELINE,
+	ID_REMOTE_TIMELINE,
+	ID_MEDIA_TIMELINE,
 	ID_DIRECT_TIMELINE,
 	ID_BOOKMARKS_TIMELINE,
 	ID_FAVORITES_TIMELINE,
 	ID_MENTIONS_TIMELINE,
 	ID_OPEN_LIST,
+	ID_MANAGE_CURRENT_LIST,
+	ID_CREATE_LIST_FROM_AUTHORS,
 	ID_CLOSE_TIMELINE,
+	ID_CLOSE_SECONDARY_TIMELINES,
+	ID_PIN_TIMELINE,
 	ID_REFRESH,
+	ID_RECONNECT_STREAMS,
 	ID_LOAD_MORE,
+	ID_FILTER_BOOKMARKS_BY_TAG,
 	// Account/settings
 	ID_OPTIONS,
 	ID_MANAGE_ACCOUNTS,
@@ -56,10 +71,23 @@ seq_ids! {
 	// Help
 	ID_VIEW_HELP,
 	ID_CHECK_FOR_UPDATES,
+	ID_SHOW_LAST_ERROR,
+	ID_OPEN_LOG_FOLDER,
+	ID_ABOUT_INSTANCE,
 	ID_SEARCH,
 	ID_FIND,
 	ID_FIND_NEXT,
 	ID_FIND_PREV,
+	ID_NEXT_MENTION,
+	ID_FOLLOW_LIVE,
+	ID_REFRESH_POST,
+	ID_HIDE_BOOSTS_FROM_BOOSTER,
+	ID_COPY_ALL_LINKS,
+	ID_FIND_HANDLES,
+	ID_ADD_ACCOUNT,
+	ID_GO_TO_POST,
+	ID_RESUME_DRAFT,
+	ID_SIGN_IN_AGAIN,
 	// Internal
 	ID_UI_WAKE,
 }
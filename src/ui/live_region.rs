@@ -0,0 +1,80 @@
+use std::{cell::RefCell, rc::Rc};
+
+use accesskit::{ActionHandler, ActionRequest, ActivationHandler, Node, NodeId, Role, Tree, TreeUpdate};
+use accesskit_windows::SubclassingAdapter;
+use windows::Win32::Foundation::HWND;
+use wxdragon::prelude::*;
+
+const LR_ROOT_ID: NodeId = NodeId(1);
+const LR_ANNOUNCEMENT_ID: NodeId = NodeId(2);
+
+struct LiveRegionActivationHandler;
+
+impl ActivationHandler for LiveRegionActivationHandler {
+	fn request_initial_tree(&mut self) -> Option<TreeUpdate> {
+		let mut root = Node::new(Role::Window);
+		root.set_children(vec![LR_ANNOUNCEMENT_ID]);
+
+		let mut ann_node = Node::new(Role::Label);
+		ann_node.set_value("");
+		ann_node.set_live(accesskit::Live::Polite);
+
+		Some(TreeUpdate {
+			nodes: vec![(LR_ANNOUNCEMENT_ID, ann_node), (LR_ROOT_ID, root)],
+			tree: Some(Tree::new(LR_ROOT_ID)),
+			focus: LR_ROOT_ID,
+			tree_id: accesskit::TreeId::ROOT,
+		})
+	}
+}
+
+struct LiveRegionActionHandler;
+
+impl ActionHandler for LiveRegionActionHandler {
+	fn do_action(&mut self, _request: ActionRequest) {}
+}
+
+/// Speaks short messages through screen readers from a window that has no other accessible content of its own.
+#[derive(Clone)]
+pub struct LiveRegion {
+	adapter: Rc<RefCell<SubclassingAdapter>>,
+	last_announcement: Rc<RefCell<Option<String>>>,
+}
+
+impl LiveRegion {
+	pub fn new(window: &impl WxWidget) -> Self {
+		let hwnd = HWND(window.get_handle() as *mut _);
+		let last_announcement = Rc::new(RefCell::new(None::<String>));
+		let adapter = SubclassingAdapter::new(hwnd, LiveRegionActivationHandler, LiveRegionActionHandler);
+		Self { adapter: Rc::new(RefCell::new(adapter)), last_announcement }
+	}
+
+	pub fn announce(&self, text: &str) {
+		let mut new_text = text.to_string();
+		let mut last = self.last_announcement.borrow_mut();
+		if let Some(old) = last.as_ref() {
+			if *old == new_text {
+				new_text.push('\u{00A0}');
+			}
+		}
+		*last = Some(new_text.clone());
+
+		let mut node = Node::new(Role::Label);
+		node.set_value(new_text);
+		node.set_live(accesskit::Live::Polite);
+
+		let mut root = Node::new(Role::Window);
+		root.set_children(vec![LR_ANNOUNCEMENT_ID]);
+
+		let update = TreeUpdate {
+			nodes: vec![(LR_ANNOUNCEMENT_ID, node), (LR_ROOT_ID, root)],
+			tree: None,
+			focus: LR_ROOT_ID,
+			tree_id: accesskit::TreeId::ROOT,
+		};
+		let mut adapter = self.adapter.borrow_mut();
+		if let Some(events) = adapter.update_if_active(|| update) {
+			events.raise();
+		}
+	}
+}
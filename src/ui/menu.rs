@@ -7,17 +7,25 @@ use crate::{
 	ID_OPEN_INSTANCE_TIMELINE_BY_INPUT, ID_OPEN_LINKS, ID_OPEN_USER_TIMELINE_BY_INPUT, ID_OPTIONS, ID_PIN_POST,
 	ID_PLAY_MEDIA, ID_QUOTE, ID_REFRESH, ID_REPLY, ID_REPLY_AUTHOR, ID_SEARCH, ID_TOGGLE_FOLLOW, ID_VIEW_BOOSTS,
 	ID_VIEW_FAVORITES, ID_VIEW_HASHTAGS, ID_VIEW_HELP, ID_VIEW_IN_BROWSER, ID_VIEW_MENTIONS, ID_VIEW_PROFILE,
-	ID_VIEW_QUOTED_THREAD, ID_VIEW_THREAD, ID_VIEW_USER_TIMELINE, commands::get_selected_status,
+	ID_VIEW_QUOTED_THREAD, ID_VIEW_THREAD, ID_VIEW_USER_TIMELINE,
+	commands::{get_selected_follow_notification, get_selected_status, update_detail_pane},
 };
 
 pub fn build_menu_bar() -> MenuBar {
 	let file_menu = Menu::builder().build();
+	file_menu.append(crate::ui::ids::ID_ADD_ACCOUNT, "A&dd Account...", "Sign in to another account", ItemKind::Normal);
 	file_menu.append(
 		ID_MANAGE_ACCOUNTS,
 		"Manage &Accounts...\tCtrl+Alt+A",
 		"Add, remove or switch accounts",
 		ItemKind::Normal,
 	);
+	file_menu.append(
+		crate::ui::ids::ID_SIGN_IN_AGAIN,
+		"Sign &In Again...",
+		"Sign in to the current account again, for example after its session expired",
+		ItemKind::Normal,
+	);
 	file_menu.append(
 		crate::ui::ids::ID_MANAGE_FILTERS,
 		"Manage &Filters...",
@@ -37,6 +45,14 @@ pub fn build_menu_bar() -> MenuBar {
 	post_menu
 		.append(ID_NEW_POST, "&New Post...\tCtrl+N", "Create a new post", ItemKind::Normal)
 		.expect("Failed to append new post menu item");
+	post_menu
+		.append(
+			crate::ui::ids::ID_RESUME_DRAFT,
+			"Res&ume Draft...",
+			"Reopen the post you saved as a draft",
+			ItemKind::Normal,
+		)
+		.expect("Failed to append resume draft menu item");
 	post_menu
 		.append(ID_REPLY, "&Reply...\tCtrl+R", "Reply to all mentioned users", ItemKind::Normal)
 		.expect("Failed to append reply menu item");
@@ -49,18 +65,50 @@ pub fn build_menu_bar() -> MenuBar {
 	post_menu
 		.append(ID_TOGGLE_FOLLOW, "Toggle &Follow\tAlt+F", "Follow or unfollow the author", ItemKind::Normal)
 		.expect("Failed to append toggle follow menu item");
+	post_menu
+		.append(
+			crate::ui::ids::ID_FOLLOW_BACK,
+			"Follow &Back",
+			"Follow the account from the selected follow notification",
+			ItemKind::Normal,
+		)
+		.expect("Failed to append follow back menu item");
+	post_menu
+		.append(
+			crate::ui::ids::ID_ADD_AUTHOR_TO_LIST,
+			"Add Author to Li&st...",
+			"Add the selected post's author to one of your lists",
+			ItemKind::Normal,
+		)
+		.expect("Failed to append add author to list menu item");
 	post_menu
 		.append(ID_VIEW_PROFILE, "View &Profile\tCtrl+P", "View profile of selected post's author", ItemKind::Normal)
 		.expect("Failed to append view profile menu item");
 	post_menu
 		.append(ID_VIEW_MENTIONS, "View &Mentions\tCtrl+M", "View mentions in selected post", ItemKind::Normal)
 		.expect("Failed to append view mentions menu item");
+	post_menu
+		.append(
+			crate::ui::ids::ID_FIND_HANDLES,
+			"Find Handles in Te&xt...",
+			"List @user@domain handles written in the selected post, including ones the server didn't link",
+			ItemKind::Normal,
+		)
+		.expect("Failed to append find handles menu item");
 	post_menu
 		.append(ID_VIEW_HASHTAGS, "View &Hashtags\tCtrl+H", "View hashtags in selected post", ItemKind::Normal)
 		.expect("Failed to append view hashtags menu item");
 	post_menu
-		.append(ID_OPEN_LINKS, "Open &Links\tEnter", "Open links in selected post", ItemKind::Normal)
+		.append(ID_OPEN_LINKS, "Open &Links\tAlt+Enter", "Open links in selected post", ItemKind::Normal)
 		.expect("Failed to append open links menu item");
+	post_menu
+		.append(
+			crate::ui::ids::ID_COPY_ALL_LINKS,
+			"Copy All Lin&ks",
+			"Copy every link in the selected post, one per line",
+			ItemKind::Normal,
+		)
+		.expect("Failed to append copy all links menu item");
 	post_menu
 		.append(ID_PLAY_MEDIA, "Play &Media\tCtrl+I", "Play media attached to selected post", ItemKind::Normal)
 		.expect("Failed to append play media menu item");
@@ -78,6 +126,22 @@ pub fn build_menu_bar() -> MenuBar {
 	post_menu
 		.append(ID_COPY_POST_LINK, "Copy Post &Link\tCtrl+C", "Copy selected post URL", ItemKind::Normal)
 		.expect("Failed to append copy post link menu item");
+	post_menu
+		.append(
+			crate::ui::ids::ID_POST_STATS,
+			"Post &Statistics\tCtrl+Shift+S",
+			"Announce word count and reading time of selected post",
+			ItemKind::Normal,
+		)
+		.expect("Failed to append post statistics menu item");
+	post_menu
+		.append(
+			crate::ui::ids::ID_REFRESH_POST,
+			"Refres&h Post\tShift+F5",
+			"Fetch the latest counts, content, and poll results for the selected post",
+			ItemKind::Normal,
+		)
+		.expect("Failed to append refresh post menu item");
 	post_menu
 		.append(
 			crate::ui::ids::ID_VIEW_POST,
@@ -87,12 +151,7 @@ pub fn build_menu_bar() -> MenuBar {
 		)
 		.expect("Failed to append view post menu item");
 	post_menu
-		.append(
-			ID_VIEW_THREAD,
-			"View &Thread\tAlt+Enter",
-			"View conversation thread for selected post",
-			ItemKind::Normal,
-		)
+		.append(ID_VIEW_THREAD, "View &Thread\tEnter", "View conversation thread for selected post", ItemKind::Normal)
 		.expect("Failed to append view thread menu item");
 	post_menu
 		.append(
@@ -128,15 +187,37 @@ pub fn build_menu_bar() -> MenuBar {
 	post_menu
 		.append(ID_BOOST, "&Boost\tCtrl+Shift+B", "Boost or unboost selected post", ItemKind::Normal)
 		.expect("Failed to append boost menu item");
+	post_menu
+		.append(
+			crate::ui::ids::ID_MUTE_CONVERSATION,
+			"Mute &Conversation",
+			"Mute or unmute notifications from this conversation",
+			ItemKind::Normal,
+		)
+		.expect("Failed to append mute conversation menu item");
+	post_menu
+		.append(
+			crate::ui::ids::ID_TAG_BOOKMARK,
+			"&Tag Bookmark...",
+			"Set local tags on the selected bookmark",
+			ItemKind::Normal,
+		)
+		.expect("Failed to append tag bookmark menu item");
 	post_menu.append_separator();
 	let timelines_menu = Menu::builder()
 		.append_item(ID_VIEW_USER_TIMELINE, "&User Timeline\tCtrl+T", "Open timeline of selected post's author")
 		.append_item(ID_OPEN_USER_TIMELINE_BY_INPUT, "Open &User...\tCtrl+U", "Open a user by username")
 		.append_item(ID_SEARCH, "&Search...\tCtrl+/", "Search for accounts, hashtags, or posts")
+		.append_item(crate::ui::ids::ID_GO_TO_POST, "&Go to Post...\tCtrl+G", "Open a post's thread by ID or link")
 		.append_separator()
 		.append_item(crate::ui::ids::ID_FIND, "&Find in Timeline...\tCtrl+F", "Find text in current timeline")
 		.append_item(crate::ui::ids::ID_FIND_NEXT, "Find &Next\tF3", "Find next occurrence")
-		.append_item(crate::ui::ids::ID_FIND_PREV, "Find &Previous\tShift+F3", "Find previous occurrence")
+		.append_item(crate::ui::ids::ID_FIND_PREV, "Find Pre&vious\tShift+F3", "Find previous occurrence")
+		.append_item(
+			crate::ui::ids::ID_NEXT_MENTION,
+			"Ne&xt Mention of Me\tCtrl+Shift+N",
+			"Jump to the next post in this timeline that mentions you",
+		)
 		.append_separator()
 		.append_item(ID_LOCAL_TIMELINE, "&Local Timeline\tCtrl+L", "Open local timeline")
 		.append_item(
@@ -145,21 +226,78 @@ pub fn build_menu_bar() -> MenuBar {
 			"Open an instance's local timeline by domain",
 		)
 		.append_item(ID_FEDERATED_TIMELINE, "&Federated Timeline", "Open federated timeline")
+		.append_item(
+			crate::ui::ids::ID_REMOTE_TIMELINE,
+			"R&emote Timeline",
+			"Open federated timeline without posts from your instance",
+		)
+		.append_item(
+			crate::ui::ids::ID_MEDIA_TIMELINE,
+			"Media-Onl&y Timeline...",
+			"Open a local, federated or remote timeline showing only posts with media",
+		)
 		.append_item(ID_DIRECT_TIMELINE, "&Direct Messages\tCtrl+D", "Open direct messages timeline")
 		.append_item(crate::ui::ids::ID_MENTIONS_TIMELINE, "&Mentions\tCtrl+Shift+M", "Open mentions timeline")
 		.append_item(crate::ID_BOOKMARKS_TIMELINE, "&Bookmarks", "Open bookmarks timeline")
+		.append_item(
+			crate::ui::ids::ID_FILTER_BOOKMARKS_BY_TAG,
+			"Filter Bookmarks by &Tag...",
+			"Show only bookmarks with a local tag",
+		)
 		.append_item(crate::ID_FAVORITES_TIMELINE, "F&avorites", "Open favorites timeline")
 		.append_item(crate::ui::ids::ID_OPEN_LIST, "Open &List...", "Open a Mastodon list")
+		.append_item(
+			crate::ui::ids::ID_MANAGE_CURRENT_LIST,
+			"Manage &This List...",
+			"Add or remove members of the open list",
+		)
+		.append_item(
+			crate::ui::ids::ID_CREATE_LIST_FROM_AUTHORS,
+			"Create List from &Authors...",
+			"Create a new list from the authors in the current timeline",
+		)
 		.append_separator()
 		.append_item(ID_LOAD_MORE, "Load &More\t.", "Load more posts from server")
 		.append_separator()
 		.append_item(ID_CLOSE_TIMELINE, "&Close Timeline", "Close current timeline")
+		.append_item(
+			crate::ui::ids::ID_CLOSE_SECONDARY_TIMELINES,
+			"Cl&ose All Secondary Timelines",
+			"Close user, thread, list, search, and hashtag timelines, returning to Home",
+		)
+		.append_item(
+			crate::ui::ids::ID_PIN_TIMELINE,
+			"&Pin Timeline",
+			"Keep the current timeline at the front of the list",
+		)
+		.append_item(
+			crate::ui::ids::ID_FOLLOW_LIVE,
+			"Follo&w Live\tCtrl+Shift+L",
+			"Keep the selection on the newest post as live updates arrive",
+		)
 		.append_separator()
 		.append_item(ID_REFRESH, "&Refresh\tF5", "Refresh current timeline")
+		.append_item(
+			crate::ui::ids::ID_RECONNECT_STREAMS,
+			"Reconnect &Live Updates\tCtrl+Shift+F5",
+			"Restart streaming for every open timeline",
+		)
 		.build();
 	let help_menu = Menu::builder()
 		.append_item(ID_CHECK_FOR_UPDATES, "Check for &Updates...", "Check for application updates")
-		.append_item(ID_VIEW_HELP, "View &Help\tF1", "Open documentation")
+		.append_item(ID_VIEW_HELP, "&Keyboard Shortcuts...\tF1", "List keyboard shortcuts and open the manual")
+		.append_separator()
+		.append_item(
+			crate::ui::ids::ID_SHOW_LAST_ERROR,
+			"Show Last &Error Details...",
+			"Show the full message of the most recent error",
+		)
+		.append_item(crate::ui::ids::ID_OPEN_LOG_FOLDER, "Open &Log Folder", "Open the folder containing the debug log")
+		.append_item(
+			crate::ui::ids::ID_ABOUT_INSTANCE,
+			"About This &Instance...",
+			"Show your instance's software, version, and post length limit",
+		)
 		.build();
 	MenuBar::builder()
 		.append(file_menu, "&Options")
@@ -236,19 +374,29 @@ pub fn update_menu_labels(menu_bar: &MenuBar, state: &AppState) {
 		};
 		boost_item.set_label(&label);
 	}
+	let (post_keys, thread_keys) = crate::keymap::enter_shortcuts(state.config.timeline_enter_action);
+	if let Some(open_links_item) = menu_bar.find_item(ID_OPEN_LINKS) {
+		open_links_item.set_label(&crate::keymap::menu_label("Open &Links", crate::keymap::OPEN_LINKS_SHORTCUT));
+	}
+	if let Some(view_post_item) = menu_bar.find_item(crate::ui::ids::ID_VIEW_POST) {
+		view_post_item.set_label(&crate::keymap::menu_label("View &Post Details", post_keys));
+	}
+	if let Some(view_thread_item) = menu_bar.find_item(ID_VIEW_THREAD) {
+		view_thread_item.set_label(&crate::keymap::menu_label("View &Thread", thread_keys));
+	}
 	if let Some(new_post_item) = menu_bar.find_item(ID_NEW_POST) {
 		let shortcut = if state.config.quick_action_keys { "C" } else { "Ctrl+N" };
 		let label = format!("&New Post...\t{shortcut}");
 		new_post_item.set_label(&label);
 	}
+	let (reply_all_shortcut, reply_author_shortcut) =
+		crate::keymap::reply_shortcuts(state.config.quick_action_keys, state.config.reply_all_default);
 	if let Some(reply_item) = menu_bar.find_item(ID_REPLY) {
-		let shortcut = if state.config.quick_action_keys { "R" } else { "Ctrl+R" };
-		let label = format!("&Reply...\t{shortcut}");
+		let label = format!("&Reply...\t{reply_all_shortcut}");
 		reply_item.set_label(&label);
 	}
 	if let Some(reply_author_item) = menu_bar.find_item(ID_REPLY_AUTHOR) {
-		let shortcut = if state.config.quick_action_keys { "Ctrl+R" } else { "Ctrl+Shift+R" };
-		let label = format!("Reply to &Author...\t{shortcut}");
+		let label = format!("Reply to &Author...\t{reply_author_shortcut}");
 		reply_author_item.set_label(&label);
 	}
 	if let Some(quote_item) = menu_bar.find_item(ID_QUOTE) {
@@ -271,6 +419,17 @@ pub fn update_menu_labels(menu_bar: &MenuBar, state: &AppState) {
 		let label = format!("View &Mentions\t{shortcut}");
 		view_mentions_item.set_label(&label);
 	}
+	if let Some(mute_item) = menu_bar.find_item(crate::ui::ids::ID_MUTE_CONVERSATION) {
+		let label = if target.is_some_and(|t| t.muted) { "Unmute &Conversation" } else { "Mute &Conversation" };
+		mute_item.set_label(label);
+	}
+	if let Some(tag_item) = menu_bar.find_item(crate::ui::ids::ID_TAG_BOOKMARK) {
+		tag_item.enable(target.is_some_and(|t| t.bookmarked));
+	}
+	let is_follow_notification = get_selected_follow_notification(state).is_some();
+	if let Some(follow_back_item) = menu_bar.find_item(crate::ui::ids::ID_FOLLOW_BACK) {
+		follow_back_item.enable(is_follow_notification);
+	}
 	if let Some(copy_post_item) = menu_bar.find_item(ID_COPY_POST) {
 		copy_post_item.enable(status.is_some());
 	}
@@ -431,13 +590,44 @@ pub fn update_menu_labels(menu_bar: &MenuBar, state: &AppState) {
 	}
 
 	state.context_menu_state.set(ContextMenuState {
+		has_entry: crate::commands::get_selected_entry(state).is_some(),
+		has_status: target.is_some(),
 		favourited: target.is_some_and(|t| t.favourited),
 		reblogged: target.is_some_and(|t| t.reblogged),
 		bookmarked: target.is_some_and(|t| t.bookmarked),
 		pinned: target.is_some_and(|t| t.pinned),
+		muted: target.is_some_and(|t| t.muted),
 		is_direct: target.is_some_and(|t| t.visibility == "direct"),
 		is_own,
+		is_follow_notification,
+		is_notification: matches!(
+			crate::commands::get_selected_entry(state),
+			Some(crate::timeline::TimelineEntry::Notification(_))
+		),
+		has_related_post: matches!(
+			crate::commands::get_selected_entry(state),
+			Some(crate::timeline::TimelineEntry::Notification(notification)) if notification.status.is_some()
+		),
+		has_poll,
+		has_media: target.is_some_and(|t| !t.media_attachments.is_empty()),
+		has_quote: target.is_some_and(|t| t.quote.as_ref().is_some_and(|q| q.quoted_status.is_some())),
+		has_url: target.is_some_and(|t| t.url.is_some()),
+		has_mentions: target.is_some_and(|t| !t.mentions.is_empty()),
+		has_tags: target.is_some_and(|t| !t.tags.is_empty()),
+		has_boosts: target.is_some_and(|t| t.reblogs_count > 0),
+		has_favorites: target.is_some_and(|t| t.favourites_count > 0),
+		has_raw_json: state.config.debug_logging
+			&& crate::commands::get_selected_status(state).is_some_and(|s| s.raw_json.is_some()),
+		has_more_replies: matches!(
+			crate::commands::get_selected_entry(state),
+			Some(crate::timeline::TimelineEntry::MoreReplies(_))
+		) || state.timeline_manager.active().is_some_and(|timeline| {
+			crate::commands::get_selected_status(state).is_some_and(|s| timeline.has_unloaded_replies(s))
+		}),
+		is_boost: crate::commands::get_selected_status(state).is_some_and(|s| s.reblog.is_some()),
 		quick_action_keys: state.config.quick_action_keys,
+		enter_action: state.config.timeline_enter_action,
+		reply_all_default: state.config.reply_all_default,
 	});
 	if let Some(load_more_item) = menu_bar.find_item(ID_LOAD_MORE) {
 		let shortcut = if state.config.quick_action_keys { "." } else { "Ctrl+." };
@@ -447,6 +637,28 @@ pub fn update_menu_labels(menu_bar: &MenuBar, state: &AppState) {
 			state.timeline_manager.active().is_some_and(|timeline| timeline.timeline_type.supports_paging());
 		load_more_item.enable(supports_paging);
 	}
+	if let Some(pin_item) = menu_bar.find_item(crate::ui::ids::ID_PIN_TIMELINE) {
+		let pinned = state
+			.timeline_manager
+			.active()
+			.is_some_and(|timeline| state.config.pinned_timelines().contains(&timeline.timeline_type));
+		pin_item.set_label(if pinned { "Un&pin Timeline" } else { "&Pin Timeline" });
+	}
+	if let Some(follow_item) = menu_bar.find_item(crate::ui::ids::ID_FOLLOW_LIVE) {
+		let following = state.timeline_manager.active().is_some_and(|timeline| timeline.follow_live);
+		follow_item.set_label(if following {
+			"Stop Follo&wing Live\tCtrl+Shift+L"
+		} else {
+			"Follo&w Live\tCtrl+Shift+L"
+		});
+	}
+	if let Some(manage_list_item) = menu_bar.find_item(crate::ui::ids::ID_MANAGE_CURRENT_LIST) {
+		let is_list = state
+			.timeline_manager
+			.active()
+			.is_some_and(|timeline| matches!(timeline.timeline_type, crate::timeline::TimelineType::List { .. }));
+		manage_list_item.enable(is_list);
+	}
 	if let Some(search_item) = menu_bar.find_item(ID_SEARCH) {
 		let shortcut = if state.config.quick_action_keys { "/" } else { "Ctrl+/" };
 		let label = format!("&Search...\t{shortcut}");
@@ -467,4 +679,6 @@ pub fn update_menu_labels(menu_bar: &MenuBar, state: &AppState) {
 		let label = format!("Play &Media\t{shortcut}");
 		play_media_item.set_label(&label);
 	}
+	// Every change to the selection or the selected entry passes through here, so the detail pane follows it too.
+	update_detail_pane(state);
 }
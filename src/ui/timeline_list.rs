@@ -21,8 +21,11 @@ impl ActionHandler for TimelineActionHandler {
 
 pub const ROOT_ID: NodeId = NodeId(1);
 pub const ANNOUNCEMENT_ID: NodeId = NodeId(2);
+pub const PLACEHOLDER_ID: NodeId = NodeId(3);
 struct ListState {
 	entries: Vec<(NodeId, String)>,
+	/// Text of a row shown in place of entries when the list is empty. It can't be selected or focused.
+	placeholder: Option<String>,
 	selected_index: Option<usize>,
 	current_tree_announcement: Option<String>,
 	search_buffer: String,
@@ -58,6 +61,11 @@ impl ActivationHandler for TimelineActivationHandler {
 			nodes.push((*id, node));
 		}
 
+		if let Some(node) = placeholder_node(&state) {
+			children.push(PLACEHOLDER_ID);
+			nodes.push((PLACEHOLDER_ID, node));
+		}
+
 		children.push(ANNOUNCEMENT_ID);
 		let mut ann_node = Node::new(Role::Label);
 		ann_node.set_value("");
@@ -70,6 +78,14 @@ impl ActivationHandler for TimelineActivationHandler {
 	}
 }
 
+fn placeholder_node(state: &ListState) -> Option<Node> {
+	let text = state.placeholder.as_ref().filter(|_| state.entries.is_empty())?;
+	let mut node = Node::new(Role::ListBoxOption);
+	node.set_label(text.clone());
+	node.set_disabled();
+	Some(node)
+}
+
 struct Inner {
 	adapter: SubclassingAdapter,
 	state: Rc<RefCell<ListState>>,
@@ -113,6 +129,7 @@ impl TimelineList {
 		let hwnd = HWND(panel.get_handle() as *mut _);
 		let list_state = Rc::new(RefCell::new(ListState {
 			entries: Vec::new(),
+			placeholder: None,
 			selected_index: None,
 			current_tree_announcement: None,
 			search_buffer: String::new(),
@@ -287,6 +304,7 @@ impl TimelineList {
 		let state_rc = { self.inner.borrow().state.clone() };
 		let mut state = state_rc.borrow_mut();
 		state.entries.clear();
+		state.placeholder = None;
 		state.selected_index = None;
 		drop(state);
 		let mut root = Node::new(Role::ListBox);
@@ -325,7 +343,7 @@ impl TimelineList {
 		self.inner.borrow_mut().on_key_down = Some(Box::new(callback));
 	}
 
-	pub fn update_entries(&self, entries: &[(NodeId, String)], selected_id: Option<NodeId>) {
+	pub fn update_entries(&self, entries: &[(NodeId, String)], selected_id: Option<NodeId>, placeholder: Option<&str>) {
 		let mut seen_ids = std::collections::HashSet::new();
 		let mut unique_entries = Vec::with_capacity(entries.len());
 		for (id, text) in entries {
@@ -361,6 +379,11 @@ impl TimelineList {
 		} else {
 			state.selected_index = None;
 		}
+		state.placeholder = placeholder.map(str::to_string);
+		if let Some(node) = placeholder_node(&state) {
+			children.push(PLACEHOLDER_ID);
+			nodes.push((PLACEHOLDER_ID, node));
+		}
 		children.push(ANNOUNCEMENT_ID);
 		// Do not push ANNOUNCEMENT_ID to nodes here, so AccessKit uses the existing node.
 		drop(state);
@@ -518,6 +541,9 @@ impl TimelineList {
 		for (id, _) in state.entries.iter() {
 			children.push(*id);
 		}
+		if state.entries.is_empty() && state.placeholder.is_some() {
+			children.push(PLACEHOLDER_ID);
+		}
 		children.push(ANNOUNCEMENT_ID);
 		root.set_children(children);
 
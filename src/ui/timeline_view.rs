@@ -12,6 +12,9 @@ use crate::{
 	ui::timeline_list::TimelineList,
 };
 
+/// Row shown in a timeline that loaded successfully but has nothing in it.
+const EMPTY_PLACEHOLDER: &str = "(empty)";
+
 pub fn entry_id_to_node_id(id: &str) -> NodeId {
 	let mut hasher = DefaultHasher::new();
 	id.hash(&mut hasher);
@@ -45,6 +48,7 @@ pub fn update_timeline_ui(
 	cw_expanded: &HashSet<String>,
 	_timeline_index: usize,
 	selected_id: Option<&str>,
+	empty_placeholder: Option<&str>,
 ) {
 	let iter: Box<dyn Iterator<Item = &TimelineEntry>> = match sort_order {
 		SortOrder::NewestToOldest => Box::new(entries.iter()),
@@ -54,12 +58,25 @@ pub fn update_timeline_ui(
 	let mut list_entries = Vec::with_capacity(entries.len());
 	for entry in iter {
 		let is_expanded = cw_expanded.contains(entry.id());
-		let text = entry.display_text(text_options, is_expanded);
+		let text = shorten_row(entry.display_text(&text_options, is_expanded), text_options.row_length);
 		list_entries.push((entry_id_to_node_id(entry.id()), text));
 	}
 
 	let selected_node_id = selected_id.map(entry_id_to_node_id);
-	timeline_list.update_entries(&list_entries, selected_node_id);
+	timeline_list.update_entries(&list_entries, selected_node_id, empty_placeholder);
+}
+
+/// Cuts `text` to at most `max_chars` characters, ending on a word boundary where one is close and marking the cut
+/// with an ellipsis.
+fn shorten_row(text: String, max_chars: Option<usize>) -> String {
+	let Some(max_chars) = max_chars else { return text };
+	let Some((end, _)) = text.char_indices().nth(max_chars) else { return text };
+	let cut = &text[..end];
+	let cut = match cut.rfind(char::is_whitespace) {
+		Some(space) if cut[..space].chars().count() * 4 >= max_chars * 3 => &cut[..space],
+		_ => cut,
+	};
+	format!("{}...", cut.trim_end())
 }
 
 pub fn with_suppressed_selection<T>(suppress_selection: &Cell<bool>, f: impl FnOnce() -> T) -> T {
@@ -159,15 +176,16 @@ pub fn update_active_timeline_ui(
 		with_suppressed_selection(suppress_selection, || {
 			// Apply selection before update so we can pass the correctly calculated selection ID
 			apply_timeline_selection(timeline_list, timeline, effective_sort_order);
-
+			let text_options = options.text_options.with_thread_replies(timeline);
 			update_timeline_ui(
 				timeline_list,
 				&timeline.entries,
 				effective_sort_order,
-				&options.text_options,
+				&text_options,
 				cw_expanded,
 				timeline_index,
 				timeline.selected_id.as_deref(),
+				timeline.loaded.then_some(EMPTY_PLACEHOLDER),
 			);
 		});
 	});
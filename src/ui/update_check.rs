@@ -1,33 +1,197 @@
-use std::{env, sync::Arc};
+use std::{
+	cell::RefCell,
+	env,
+	sync::{
+		Arc,
+		atomic::{AtomicBool, AtomicU64, Ordering},
+	},
+	thread,
+	time::Duration,
+};
 
-use ship_shape::{UpdateChannel as ShipChannel, UpdaterConfig};
 use wxdragon::prelude::*;
 
-const FEDRA_GITHUB_REPO: &str = "trypsynth/fedra";
-const FEDRA_MINISIGN_KEY: &str = "RWTlkclKA9G3Jhv3wkicYywPfi5XqULERn6LrK7aIv9nYQUPbhQaxSqZ";
+use crate::{
+	config::UpdateChannel,
+	updater::{self, AvailableUpdate},
+};
 
-pub fn run_update_check(frame: Frame, silent: bool) {
-	let config = crate::config::ConfigStore::new().load();
-	let channel = match config.update_channel {
-		crate::config::UpdateChannel::Stable => ShipChannel::Stable,
-		crate::config::UpdateChannel::Dev => ShipChannel::Dev,
-	};
-	let updater_config = Arc::new(UpdaterConfig::new(
-		FEDRA_GITHUB_REPO,
-		"fedra",
-		"Fedra",
-		FEDRA_MINISIGN_KEY,
-		format!("fedra/{}", env!("CARGO_PKG_VERSION")),
-	));
-	ship_shape::ui::run_update_check(
-		updater_config,
-		frame.handle_ptr() as usize,
-		env!("CARGO_PKG_VERSION"),
-		env!("FEDRA_COMMIT_HASH"),
-		is_installer_distribution(),
-		channel,
-		silent,
-	);
+const ID_INSTALL: i32 = 20001;
+
+thread_local! {
+	static UPDATE_FRAME: RefCell<Option<Frame>> = const { RefCell::new(None) };
+	static UPDATE_PROGRESS: RefCell<Option<ProgressDialog>> = const { RefCell::new(None) };
+}
+
+fn with_frame(f: impl FnOnce(&Frame)) {
+	let frame = UPDATE_FRAME.with(|frame| frame.borrow().clone());
+	if let Some(frame) = frame.filter(|frame| frame.is_valid()) {
+		f(&frame);
+	}
+}
+
+fn show_message(frame: &Frame, message: &str, style: MessageDialogStyle) {
+	let dialog =
+		MessageDialog::builder(frame, message, "Fedra Update").with_style(MessageDialogStyle::OK | style).build();
+	dialog.show_modal();
+	dialog.destroy();
+}
+
+/// Checks for a newer build in the background. `silent` checks, made at startup, only speak up when there is one.
+pub fn run_update_check(frame: Frame, channel: UpdateChannel, silent: bool) {
+	UPDATE_FRAME.with(|stored| *stored.borrow_mut() = Some(frame));
+	let installer = is_installer_distribution();
+	thread::spawn(move || {
+		let result = updater::check_for_update(channel, installer);
+		wxdragon::call_after(Box::new(move || {
+			with_frame(|frame| match result {
+				Ok(Some(update)) => {
+					if prompt_for_update(frame, &update) {
+						start_download(frame, update);
+					}
+				}
+				Ok(None) if !silent => {
+					show_message(
+						frame,
+						"You are running the latest version of Fedra.",
+						MessageDialogStyle::IconInformation,
+					);
+				}
+				Err(err) if !silent => {
+					show_message(frame, &format!("Couldn't check for updates: {err:#}"), MessageDialogStyle::IconError);
+				}
+				_ => {}
+			});
+		}));
+	});
+}
+
+fn prompt_for_update(frame: &Frame, update: &AvailableUpdate) -> bool {
+	let dialog = Dialog::builder(frame, "Fedra Update").with_size(560, 400).build();
+	let panel = Panel::builder(&dialog).build();
+	let main_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	let message = StaticText::builder(&panel)
+		.with_label(&format!("Fedra {} is available. Download and install it now?", update.version))
+		.build();
+	let notes_label = StaticText::builder(&panel).with_label("&What's new:").build();
+	let notes_text = TextCtrl::builder(&panel)
+		.with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly)
+		.with_value(&update.notes)
+		.build();
+	let button_sizer = BoxSizer::builder(Orientation::Horizontal).build();
+	let install_button = Button::builder(&panel).with_id(ID_INSTALL).with_label("&Download and Install").build();
+	let cancel_button = Button::builder(&panel).with_id(ID_CANCEL).with_label("&Not Now").build();
+	button_sizer.add_stretch_spacer(1);
+	button_sizer.add(&install_button, 0, SizerFlag::Right, 8);
+	button_sizer.add(&cancel_button, 0, SizerFlag::Right, 8);
+	main_sizer.add(&message, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	main_sizer.add(&notes_label, 0, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right, 8);
+	main_sizer.add(&notes_text, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Top, 8);
+	main_sizer.add_sizer(&button_sizer, 0, SizerFlag::Expand | SizerFlag::All, 8);
+	panel.set_sizer(main_sizer, true);
+	let dialog_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	dialog_sizer.add(&panel, 1, SizerFlag::Expand, 0);
+	dialog.set_sizer(dialog_sizer, true);
+	dialog.set_escape_id(ID_CANCEL);
+	install_button.on_click(move |_| dialog.end_modal(ID_INSTALL));
+	install_button.set_default();
+	dialog.centre();
+	notes_text.set_focus();
+	let result = dialog.show_modal();
+	dialog.destroy();
+	result == ID_INSTALL
+}
+
+/// Downloads `update` behind a progress dialog whose message gives the percentage so far, then installs it once its
+/// signature and checksum have been verified. A failed verification aborts with a warning.
+fn start_download(frame: &Frame, update: AvailableUpdate) {
+	let progress = ProgressDialog::builder(frame, "Downloading Update", "Downloading update...", 100)
+		.with_style(
+			ProgressDialogStyle::AutoHide
+				| ProgressDialogStyle::AppModal
+				| ProgressDialogStyle::RemainingTime
+				| ProgressDialogStyle::CanAbort,
+		)
+		.build();
+	UPDATE_PROGRESS.with(|stored| *stored.borrow_mut() = Some(progress));
+	let downloaded = Arc::new(AtomicU64::new(0));
+	let total = Arc::new(AtomicU64::new(0));
+	let is_running = Arc::new(AtomicBool::new(true));
+	let cancelled = Arc::new(AtomicBool::new(false));
+	let hb_downloaded = downloaded.clone();
+	let hb_total = total.clone();
+	let hb_is_running = is_running.clone();
+	let hb_cancelled = cancelled.clone();
+	thread::spawn(move || {
+		while hb_is_running.load(Ordering::Relaxed) {
+			let d = hb_downloaded.load(Ordering::Relaxed);
+			let t = hb_total.load(Ordering::Relaxed);
+			let current_cancelled = hb_cancelled.clone();
+			wxdragon::call_after(Box::new(move || {
+				UPDATE_PROGRESS.with(|p| {
+					if let Some(dialog) = p.borrow().as_ref() {
+						let keep_going = if t > 0 {
+							let percent = i32::try_from(d * 100 / t).unwrap_or(100).min(100);
+							// Stepping the message in tens gives screen readers something to announce without
+							// repeating every update.
+							let message = format!("Downloading update: {}%", percent / 10 * 10);
+							dialog.update(percent, Some(message.as_str()))
+						} else {
+							dialog.pulse(None)
+						};
+						if !keep_going {
+							current_cancelled.store(true, Ordering::Relaxed);
+						}
+					}
+				});
+			}));
+			thread::sleep(Duration::from_millis(200));
+		}
+	});
+	thread::spawn(move || {
+		let result = updater::download_verified(&update, |received, size| {
+			downloaded.store(received, Ordering::Relaxed);
+			total.store(size, Ordering::Relaxed);
+			!cancelled.load(Ordering::Relaxed)
+		});
+		is_running.store(false, Ordering::Relaxed);
+		wxdragon::call_after(Box::new(move || {
+			UPDATE_PROGRESS.with(|p| {
+				if let Some(dialog) = p.borrow_mut().take() {
+					dialog.destroy();
+				}
+			});
+			with_frame(|frame| match result {
+				Ok(Some(path)) => {
+					show_message(
+						frame,
+						"The update was downloaded and verified. Fedra will now close to install it.",
+						MessageDialogStyle::IconInformation,
+					);
+					match updater::launch_update(&path) {
+						Ok(()) => {
+							frame.close(true);
+						}
+						Err(err) => {
+							show_message(
+								frame,
+								&format!("Couldn't install the update: {err:#}"),
+								MessageDialogStyle::IconError,
+							);
+						}
+					}
+				}
+				Ok(None) => {}
+				Err(err) => {
+					show_message(
+						frame,
+						&format!("The update was not installed. {err:#}"),
+						MessageDialogStyle::IconWarning,
+					);
+				}
+			});
+		}));
+	});
 }
 
 fn is_installer_distribution() -> bool {
@@ -10,7 +10,7 @@ use crate::{
 	ID_REPLY, ID_REPLY_AUTHOR, ID_SEARCH, ID_TOGGLE_FOLLOW, ID_VIEW_BOOSTS, ID_VIEW_FAVORITES, ID_VIEW_HASHTAGS,
 	ID_VIEW_HELP, ID_VIEW_IN_BROWSER, ID_VIEW_MENTIONS, ID_VIEW_POST, ID_VIEW_PROFILE, ID_VIEW_QUOTED_THREAD,
 	ID_VIEW_THREAD, ID_VIEW_USER_TIMELINE, KEY_DELETE, UiCommand,
-	config::{AutoloadMode, SortOrder},
+	config::{AutoloadMode, SortOrder, TimelineEnterAction},
 	ui::{dialogs, menu::build_menu_bar},
 	ui_wake::UiCommandSender,
 };
@@ -19,6 +19,8 @@ pub struct WindowParts {
 	pub frame: Frame,
 	pub timelines_selector: ListBox,
 	pub timeline_list: crate::ui::timeline_list::TimelineList,
+	pub add_account_button: Button,
+	pub detail_pane: TextCtrl,
 }
 
 pub fn build_main_window() -> WindowParts {
@@ -33,6 +35,10 @@ pub fn build_main_window() -> WindowParts {
 	let timelines_selector = ListBox::builder(&panel).with_choices(vec!["Home".to_string()]).build();
 	timelines_selector.set_selection(0_u32, true);
 	let timeline_list = crate::ui::timeline_list::TimelineList::new(&panel);
+	let add_account_button = Button::builder(&panel).with_label("&Add Account...").build();
+	add_account_button.show(false);
+	let detail_pane = TextCtrl::builder(&panel).with_style(TextCtrlStyle::MultiLine | TextCtrlStyle::ReadOnly).build();
+	detail_pane.show(false);
 	let timelines_sizer = BoxSizer::builder(Orientation::Vertical).build();
 	timelines_sizer.add(&timelines_label, 0, SizerFlag::All, 8);
 	timelines_sizer.add(
@@ -41,14 +47,32 @@ pub fn build_main_window() -> WindowParts {
 		SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom,
 		8,
 	);
+	timelines_sizer.add(
+		&add_account_button,
+		0,
+		SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom,
+		8,
+	);
 	sizer.add_sizer(&timelines_sizer, 1, SizerFlag::Expand, 0);
-	sizer.add(&timeline_list, 3, SizerFlag::Expand | SizerFlag::All, 8);
+	let entries_sizer = BoxSizer::builder(Orientation::Vertical).build();
+	entries_sizer.add(&timeline_list, 3, SizerFlag::Expand | SizerFlag::All, 8);
+	entries_sizer.add(&detail_pane, 1, SizerFlag::Expand | SizerFlag::Left | SizerFlag::Right | SizerFlag::Bottom, 8);
+	sizer.add_sizer(&entries_sizer, 3, SizerFlag::Expand, 0);
 	panel.set_sizer(sizer, true);
 	let frame_sizer = BoxSizer::builder(Orientation::Vertical).build();
 	frame_sizer.add(&panel, 1, SizerFlag::Expand | SizerFlag::All, 0);
 	frame.set_sizer(frame_sizer, true);
 
-	WindowParts { frame, timelines_selector, timeline_list }
+	WindowParts { frame, timelines_selector, timeline_list, add_account_button, detail_pane }
+}
+
+/// Shows or hides a control that is only part of the main window some of the time, such as the Add Account button or
+/// the detail pane, and lays the window out again around it.
+pub fn set_shown(window: &impl WxWidget, shown: bool) {
+	window.show(shown);
+	if let Some(parent) = window.get_parent() {
+		parent.layout();
+	}
 }
 
 pub fn bind_input_handlers(
@@ -60,6 +84,8 @@ pub fn bind_input_handlers(
 	autoload_mode: Rc<Cell<AutoloadMode>>,
 	sort_order_cell: Rc<Cell<SortOrder>>,
 	context_menu_state: Rc<Cell<ContextMenuState>>,
+	enter_action: Rc<Cell<TimelineEnterAction>>,
+	reply_all_default: Rc<Cell<bool>>,
 ) {
 	let ui_tx_selector = ui_tx.clone();
 	let shutdown_selector = is_shutting_down.clone();
@@ -100,6 +126,12 @@ pub fn bind_input_handlers(
 						event.skip(false);
 						return;
 					}
+					Some(48) => {
+						// Ctrl+0
+						let _ = ui_tx_delete.send(UiCommand::SwitchToLastTimeline);
+						event.skip(false);
+						return;
+					}
 					Some(k) if (49..=57).contains(&k) => {
 						// Ctrl+1-9
 						if let Ok(index) = usize::try_from(k - 49) {
@@ -147,6 +179,11 @@ pub fn bind_input_handlers(
 						event.skip(false);
 						return;
 					}
+					Some(48) if quick_action_keys_selector.get() => {
+						let _ = ui_tx_delete.send(UiCommand::SwitchToLastTimeline);
+						event.skip(false);
+						return;
+					}
 					Some(k) if (49..=57).contains(&k) && quick_action_keys_selector.get() => {
 						// 1-9 in quick keys mode
 						if let Ok(index) = usize::try_from(k - 49) {
@@ -197,7 +234,12 @@ pub fn bind_input_handlers(
 					return;
 				}
 				if !ctrl && !alt {
-					let _ = ui_tx_list_key.send(UiCommand::ViewThread);
+					let cmd = match enter_action.get() {
+						TimelineEnterAction::ViewThread => UiCommand::ViewThread,
+						TimelineEnterAction::Reply => UiCommand::Reply { reply_all: reply_all_default.get() },
+						TimelineEnterAction::ViewPost => UiCommand::ViewPost,
+					};
+					let _ = ui_tx_list_key.send(cmd);
 					event.skip(false);
 					return;
 				}
@@ -330,6 +372,11 @@ pub fn bind_input_handlers(
 						event.skip(false);
 						return;
 					}
+					74 => {
+						let _ = ui_tx_list_key.send(UiCommand::CopyRawJson);
+						event.skip(false);
+						return;
+					}
 					_ => {}
 				}
 			}
@@ -357,6 +404,11 @@ pub fn bind_input_handlers(
 							return;
 						}
 					}
+					48 => {
+						let _ = ui_tx_list_key.send(UiCommand::SwitchToLastTimeline);
+						event.skip(false);
+						return;
+					}
 					k if (49..=57).contains(&k) => {
 						if let Ok(index) = usize::try_from(k - 49) {
 							let _ = ui_tx_list_key.send(UiCommand::SwitchTimelineByIndex(index));
@@ -448,7 +500,7 @@ pub fn bind_input_handlers(
 						return;
 					}
 					82 => {
-						let _ = ui_tx_list_key.send(UiCommand::Reply { reply_all: true });
+						let _ = ui_tx_list_key.send(UiCommand::Reply { reply_all: reply_all_default.get() });
 						event.skip(false);
 						return;
 					}
@@ -497,6 +549,11 @@ pub fn bind_input_handlers(
 						event.skip(false);
 						return;
 					}
+					48 => {
+						let _ = ui_tx_list_key.send(UiCommand::SwitchToLastTimeline);
+						event.skip(false);
+						return;
+					}
 					k if (49..=57).contains(&k) => {
 						if let Ok(index) = usize::try_from(k - 49) {
 							let _ = ui_tx_list_key.send(UiCommand::SwitchTimelineByIndex(index));
@@ -524,6 +581,30 @@ pub fn bind_input_handlers(
 		}
 	});
 
+	let ui_tx_add_account = ui_tx.clone();
+	parts.add_account_button.on_click(move |_| {
+		let _ = ui_tx_add_account.send(UiCommand::AddAccount);
+	});
+
+	// ── Timeline list focus announcement ──────────────────────────────────
+	// Only focus arriving from the timelines selector is announced, so dialogs closing and the window being
+	// reactivated don't repeat the timeline name.
+	let left_selector = Rc::new(Cell::new(false));
+	let left_selector_kill = left_selector.clone();
+	parts.timelines_selector.bind_internal(EventType::KILL_FOCUS, move |event| {
+		left_selector_kill.set(true);
+		event.skip(true);
+	});
+	let ui_tx_focus = ui_tx.clone();
+	let shutdown_focus = is_shutting_down.clone();
+	parts.timeline_list.bind_internal(EventType::SET_FOCUS, move |event| {
+		event.skip(true);
+		if shutdown_focus.get() || !left_selector.replace(false) {
+			return;
+		}
+		let _ = ui_tx_focus.send(UiCommand::TimelineListFocused);
+	});
+
 	// ── Timeline list context menu ─────────────────────────────────────────
 	let shutdown_ctx = is_shutting_down.clone();
 	let context_menu_state_ctx = context_menu_state;
@@ -533,99 +614,232 @@ pub fn bind_input_handlers(
 			return;
 		}
 		let cms = context_menu_state_ctx.get();
+		if !cms.has_entry {
+			return;
+		}
 		let q = cms.quick_action_keys;
 		let mut menu = Menu::builder().build();
-		menu.append(
-			ID_REPLY,
-			if q { "&Reply...\tR" } else { "&Reply...\tCtrl+R" },
-			"Reply to all mentioned users",
-			ItemKind::Normal,
-		);
-		menu.append(
-			ID_REPLY_AUTHOR,
-			if q { "Reply to &Author...\tCtrl+R" } else { "Reply to &Author...\tCtrl+Shift+R" },
-			"Reply to author only",
-			ItemKind::Normal,
-		);
-		menu.append(
-			ID_QUOTE,
-			if q { "&Quote...\tQ" } else { "&Quote...\tCtrl+Q" },
-			"Quote this post",
-			ItemKind::Normal,
-		);
-		menu.append_separator();
-		let fav_label = match (cms.favourited, q) {
-			(true, true) => "Un&favorite\tF",
-			(true, false) => "Un&favorite\tCtrl+Shift+F",
-			(false, true) => "&Favorite\tF",
-			(false, false) => "&Favorite\tCtrl+Shift+F",
-		};
-		menu.append(ID_FAVORITE, fav_label, "Favorite or unfavorite selected post", ItemKind::Normal);
-		let bookmark_label = match (cms.bookmarked, q) {
-			(true, true) => "Un&bookmark\tK",
-			(true, false) => "Un&bookmark\tCtrl+Shift+K",
-			(false, true) => "&Bookmark\tK",
-			(false, false) => "&Bookmark\tCtrl+Shift+K",
-		};
-		menu.append(ID_BOOKMARK, bookmark_label, "Bookmark or unbookmark selected post", ItemKind::Normal);
-		if !cms.is_direct {
-			let boost_label = match (cms.reblogged, q) {
-				(true, true) => "Un&boost\tB",
-				(true, false) => "Un&boost\tCtrl+Shift+B",
-				(false, true) => "&Boost\tB",
-				(false, false) => "&Boost\tCtrl+Shift+B",
+		if cms.is_follow_notification {
+			menu.append(
+				crate::ui::ids::ID_FOLLOW_BACK,
+				"Follow &Back",
+				"Follow the account from the selected follow notification",
+				ItemKind::Normal,
+			);
+			menu.append_separator();
+		}
+		if cms.has_status {
+			let (reply_all_shortcut, reply_author_shortcut) = crate::keymap::reply_shortcuts(q, cms.reply_all_default);
+			menu.append(
+				ID_REPLY,
+				&format!("&Reply...\t{reply_all_shortcut}"),
+				"Reply to all mentioned users",
+				ItemKind::Normal,
+			);
+			menu.append(
+				ID_REPLY_AUTHOR,
+				&format!("Reply to &Author...\t{reply_author_shortcut}"),
+				"Reply to author only",
+				ItemKind::Normal,
+			);
+			menu.append(
+				ID_QUOTE,
+				if q { "&Quote...\tQ" } else { "&Quote...\tCtrl+Q" },
+				"Quote this post",
+				ItemKind::Normal,
+			);
+			menu.append_separator();
+			if cms.has_poll {
+				menu.append(
+					crate::ID_VOTE,
+					if q { "&Vote...\tV" } else { "&Vote...\tCtrl+V" },
+					"Vote on poll in selected post",
+					ItemKind::Normal,
+				);
+			}
+			let fav_label = match (cms.favourited, q) {
+				(true, true) => "Un&favorite\tF",
+				(true, false) => "Un&favorite\tCtrl+Shift+F",
+				(false, true) => "&Favorite\tF",
+				(false, false) => "&Favorite\tCtrl+Shift+F",
+			};
+			menu.append(ID_FAVORITE, fav_label, "Favorite or unfavorite selected post", ItemKind::Normal);
+			let bookmark_label = match (cms.bookmarked, q) {
+				(true, true) => "Un&bookmark\tK",
+				(true, false) => "Un&bookmark\tCtrl+Shift+K",
+				(false, true) => "&Bookmark\tK",
+				(false, false) => "&Bookmark\tCtrl+Shift+K",
 			};
-			menu.append(ID_BOOST, boost_label, "Boost or unboost selected post", ItemKind::Normal);
+			menu.append(ID_BOOKMARK, bookmark_label, "Bookmark or unbookmark selected post", ItemKind::Normal);
+			if !cms.is_direct {
+				let boost_label = match (cms.reblogged, q) {
+					(true, true) => "Un&boost\tB",
+					(true, false) => "Un&boost\tCtrl+Shift+B",
+					(false, true) => "&Boost\tB",
+					(false, false) => "&Boost\tCtrl+Shift+B",
+				};
+				menu.append(ID_BOOST, boost_label, "Boost or unboost selected post", ItemKind::Normal);
+			}
+			let mute_label = if cms.muted { "Unmute &Conversation" } else { "Mute &Conversation" };
+			menu.append(
+				crate::ui::ids::ID_MUTE_CONVERSATION,
+				mute_label,
+				"Mute or unmute notifications from this conversation",
+				ItemKind::Normal,
+			);
+			if cms.bookmarked {
+				menu.append(
+					crate::ui::ids::ID_TAG_BOOKMARK,
+					"&Tag Bookmark...",
+					"Set local tags on the selected bookmark",
+					ItemKind::Normal,
+				);
+			}
+			menu.append_separator();
+			let (post_keys, thread_keys) = crate::keymap::enter_shortcuts(cms.enter_action);
+			let post_label = crate::keymap::menu_label("View &Post Details", post_keys);
+			let thread_label = crate::keymap::menu_label("View &Thread", thread_keys);
+			menu.append(ID_VIEW_POST, &post_label, "View post content in a dialog", ItemKind::Normal);
+			if cms.has_related_post {
+				let thread_label = thread_label.replacen("View &Thread", "View &Thread of Related Post", 1);
+				menu.append(
+					ID_VIEW_THREAD,
+					&thread_label,
+					"View the thread of the post this is about",
+					ItemKind::Normal,
+				);
+			} else {
+				menu.append(ID_VIEW_THREAD, &thread_label, "View conversation thread", ItemKind::Normal);
+			}
+			if cms.has_more_replies {
+				menu.append(
+					crate::ui::ids::ID_LOAD_MORE_REPLIES,
+					"Load More Repl&ies",
+					"Load replies to this post that the thread left out",
+					ItemKind::Normal,
+				);
+			}
+			if cms.has_quote {
+				menu.append(
+					ID_VIEW_QUOTED_THREAD,
+					"View &Quoted Thread",
+					"View conversation thread for quoted post",
+					ItemKind::Normal,
+				);
+			}
+			if cms.has_boosts {
+				menu.append(ID_VIEW_BOOSTS, "View Boo&sts", "View users who boosted this post", ItemKind::Normal);
+			}
+			if cms.has_favorites {
+				menu.append(
+					ID_VIEW_FAVORITES,
+					"View Fa&vorites",
+					"View users who favorited this post",
+					ItemKind::Normal,
+				);
+			}
+			menu.append(ID_OPEN_LINKS, "Open &Links\tAlt+Enter", "Open links in selected post", ItemKind::Normal);
+			menu.append(
+				crate::ui::ids::ID_COPY_ALL_LINKS,
+				"Copy All Lin&ks",
+				"Copy every link in the selected post, one per line",
+				ItemKind::Normal,
+			);
+			if cms.has_media {
+				menu.append(
+					ID_PLAY_MEDIA,
+					if q { "Play &Media\tI" } else { "Play &Media\tCtrl+I" },
+					"Play media attached to selected post",
+					ItemKind::Normal,
+				);
+			}
+			if cms.has_url {
+				menu.append(
+					ID_VIEW_IN_BROWSER,
+					if q { "&Open in Browser\tO" } else { "&Open in Browser\tCtrl+Shift+O" },
+					"Open selected post in web browser",
+					ItemKind::Normal,
+				);
+			}
+			menu.append(ID_COPY_POST, "&Copy Post\tCtrl+Shift+C", "Copy selected post text", ItemKind::Normal);
+			if cms.has_url {
+				menu.append(ID_COPY_POST_LINK, "Copy Post &Link\tCtrl+C", "Copy selected post URL", ItemKind::Normal);
+			}
+			if cms.has_raw_json {
+				menu.append(
+					crate::ui::ids::ID_COPY_RAW_JSON,
+					"Copy Raw &JSON\tCtrl+Shift+J",
+					"Copy the JSON the selected post was parsed from",
+					ItemKind::Normal,
+				);
+			}
+			menu.append_separator();
+		}
+		if cms.is_notification {
+			menu.append(
+				ID_VIEW_PROFILE,
+				if q { "View &Profile of Actor\tP" } else { "View &Profile of Actor\tCtrl+P" },
+				"View profile of the account that triggered this notification",
+				ItemKind::Normal,
+			);
+		} else {
+			menu.append(
+				ID_VIEW_PROFILE,
+				if q { "View &Profile\tP" } else { "View &Profile\tCtrl+P" },
+				"View profile of selected post's author",
+				ItemKind::Normal,
+			);
 		}
-		menu.append_separator();
-		menu.append(ID_VIEW_POST, "View &Post Details\tShift+Enter", "View post content in a dialog", ItemKind::Normal);
-		menu.append(ID_VIEW_THREAD, "View &Thread\tAlt+Enter", "View conversation thread", ItemKind::Normal);
-		menu.append(
-			ID_VIEW_QUOTED_THREAD,
-			"View &Quoted Thread",
-			"View conversation thread for quoted post",
-			ItemKind::Normal,
-		);
-		menu.append(ID_OPEN_LINKS, "Open &Links\tEnter", "Open links in selected post", ItemKind::Normal);
-		menu.append(ID_PLAY_MEDIA, "Play &Media", "Play media attached to selected post", ItemKind::Normal);
-		menu.append(
-			ID_VIEW_IN_BROWSER,
-			if q { "&Open in Browser\tO" } else { "&Open in Browser\tCtrl+Shift+O" },
-			"Open selected post in web browser",
-			ItemKind::Normal,
-		);
-		menu.append(ID_COPY_POST, "&Copy Post\tCtrl+Shift+C", "Copy selected post text", ItemKind::Normal);
-		menu.append(ID_COPY_POST_LINK, "Copy Post &Link\tCtrl+C", "Copy selected post URL", ItemKind::Normal);
-		menu.append_separator();
-		menu.append(
-			ID_VIEW_PROFILE,
-			if q { "View &Profile\tP" } else { "View &Profile\tCtrl+P" },
-			"View profile of selected post's author",
-			ItemKind::Normal,
-		);
 		menu.append(
 			ID_VIEW_USER_TIMELINE,
 			if q { "&User Timeline\tT" } else { "&User Timeline\tCtrl+T" },
 			"Open timeline of selected post's author",
 			ItemKind::Normal,
 		);
+		menu.append(ID_TOGGLE_FOLLOW, "Toggle &Follow\tAlt+F", "Follow or unfollow the author", ItemKind::Normal);
 		menu.append(
-			ID_VIEW_MENTIONS,
-			if q { "View &Mentions\tM" } else { "View &Mentions\tCtrl+M" },
-			"View mentions in selected post",
+			crate::ui::ids::ID_ADD_AUTHOR_TO_LIST,
+			"Add Author to Li&st...",
+			"Add the selected post's author to one of your lists",
 			ItemKind::Normal,
 		);
-		menu.append(
-			ID_VIEW_HASHTAGS,
-			if q { "View &Hashtags\tH" } else { "View &Hashtags\tCtrl+H" },
-			"View hashtags in selected post",
-			ItemKind::Normal,
-		);
-		if cms.is_own {
+		if cms.is_boost {
+			menu.append(
+				crate::ui::ids::ID_HIDE_BOOSTS_FROM_BOOSTER,
+				"Hide Boosts from Boost&er",
+				"Stop showing boosts from the account that boosted this post",
+				ItemKind::Normal,
+			);
+		}
+		if cms.has_mentions {
+			menu.append(
+				ID_VIEW_MENTIONS,
+				if q { "View &Mentions\tM" } else { "View &Mentions\tCtrl+M" },
+				"View mentions in selected post",
+				ItemKind::Normal,
+			);
+		}
+		if cms.has_status {
+			menu.append(
+				crate::ui::ids::ID_FIND_HANDLES,
+				"Find Handles in Te&xt...",
+				"List @user@domain handles written in the selected post, including ones the server didn't link",
+				ItemKind::Normal,
+			);
+		}
+		if cms.has_tags {
+			menu.append(
+				ID_VIEW_HASHTAGS,
+				if q { "View &Hashtags\tH" } else { "View &Hashtags\tCtrl+H" },
+				"View hashtags in selected post",
+				ItemKind::Normal,
+			);
+		}
+		if cms.is_own && cms.has_status {
 			menu.append_separator();
 			let edit_label = if q { "&Edit Post...\tE" } else { "&Edit Post...\tCtrl+E" };
 			menu.append(ID_EDIT_POST, edit_label, "Edit selected post", ItemKind::Normal);
-			menu.append(ID_DELETE_POST, "&Delete Post\tDel", "Delete selected post", ItemKind::Normal);
+			menu.append(ID_DELETE_POST, "&Delete Post\tDelete", "Delete selected post", ItemKind::Normal);
 			let pin_label = if cms.pinned { "&Unpin Post" } else { "&Pin Post" };
 			menu.append(ID_PIN_POST, pin_label, "Pin or unpin this post on your profile", ItemKind::Normal);
 		}
@@ -693,6 +907,18 @@ pub fn bind_input_handlers(
 	let shutdown_menu = is_shutting_down;
 	let frame_menu = parts.frame;
 	frame_menu.on_menu_selected(move |event| match event.get_id() {
+		crate::ui::ids::ID_ADD_AUTHOR_TO_LIST => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::AddAuthorToList);
+		}
+		crate::ui::ids::ID_FOLLOW_BACK => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::FollowBack);
+		}
 		ID_TOGGLE_FOLLOW => {
 			if shutdown_menu.get() {
 				return;
@@ -711,12 +937,24 @@ pub fn bind_input_handlers(
 			}
 			let _ = ui_tx_menu.send(UiCommand::ShowOptions);
 		}
+		crate::ui::ids::ID_ADD_ACCOUNT => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::AddAccount);
+		}
 		ID_MANAGE_ACCOUNTS => {
 			if shutdown_menu.get() {
 				return;
 			}
 			let _ = ui_tx_menu.send(UiCommand::ManageAccounts);
 		}
+		crate::ui::ids::ID_SIGN_IN_AGAIN => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::SignInAgain);
+		}
 		crate::ui::ids::ID_MANAGE_FILTERS => {
 			if shutdown_menu.get() {
 				return;
@@ -795,12 +1033,30 @@ pub fn bind_input_handlers(
 			}
 			let _ = ui_tx_menu.send(UiCommand::Pin);
 		}
+		crate::ui::ids::ID_MUTE_CONVERSATION => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::MuteConversation);
+		}
+		crate::ui::ids::ID_TAG_BOOKMARK => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::TagBookmark);
+		}
 		ID_REFRESH => {
 			if shutdown_menu.get() {
 				return;
 			}
 			let _ = ui_tx_menu.send(UiCommand::Refresh);
 		}
+		crate::ui::ids::ID_RECONNECT_STREAMS => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::ReconnectStreams);
+		}
 		ID_VIEW_USER_TIMELINE => {
 			if shutdown_menu.get() {
 				return;
@@ -831,6 +1087,18 @@ pub fn bind_input_handlers(
 			}
 			let _ = ui_tx_menu.send(UiCommand::OpenTimeline(crate::timeline::TimelineType::Federated));
 		}
+		crate::ui::ids::ID_REMOTE_TIMELINE => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::OpenTimeline(crate::timeline::TimelineType::Remote));
+		}
+		crate::ui::ids::ID_MEDIA_TIMELINE => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::OpenMediaTimeline);
+		}
 		ID_DIRECT_TIMELINE => {
 			if shutdown_menu.get() {
 				return;
@@ -843,6 +1111,18 @@ pub fn bind_input_handlers(
 			}
 			let _ = ui_tx_menu.send(UiCommand::OpenList);
 		}
+		crate::ui::ids::ID_MANAGE_CURRENT_LIST => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::ManageCurrentList);
+		}
+		crate::ui::ids::ID_CREATE_LIST_FROM_AUTHORS => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::CreateListFromAuthors);
+		}
 		crate::ui::ids::ID_MENTIONS_TIMELINE => {
 			if shutdown_menu.get() {
 				return;
@@ -855,6 +1135,12 @@ pub fn bind_input_handlers(
 			}
 			let _ = ui_tx_menu.send(UiCommand::OpenTimeline(crate::timeline::TimelineType::Bookmarks));
 		}
+		crate::ui::ids::ID_FILTER_BOOKMARKS_BY_TAG => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::FilterBookmarksByTag);
+		}
 		crate::ID_FAVORITES_TIMELINE => {
 			if shutdown_menu.get() {
 				return;
@@ -867,6 +1153,48 @@ pub fn bind_input_handlers(
 			}
 			let _ = ui_tx_menu.send(UiCommand::CloseTimeline);
 		}
+		crate::ui::ids::ID_CLOSE_SECONDARY_TIMELINES => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::CloseSecondaryTimelines);
+		}
+		crate::ui::ids::ID_FIND_HANDLES => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::FindHandles);
+		}
+		crate::ui::ids::ID_COPY_ALL_LINKS => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::CopyAllLinks);
+		}
+		crate::ui::ids::ID_HIDE_BOOSTS_FROM_BOOSTER => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::HideBoostsFromBooster);
+		}
+		crate::ui::ids::ID_REFRESH_POST => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::RefreshPost);
+		}
+		crate::ui::ids::ID_FOLLOW_LIVE => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::ToggleFollowLive);
+		}
+		crate::ui::ids::ID_PIN_TIMELINE => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::TogglePinTimeline);
+		}
 		ID_VIEW_MENTIONS => {
 			if shutdown_menu.get() {
 				return;
@@ -921,6 +1249,18 @@ pub fn bind_input_handlers(
 			}
 			let _ = ui_tx_menu.send(UiCommand::CopyPostLink);
 		}
+		crate::ui::ids::ID_POST_STATS => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::AnnouncePostStats);
+		}
+		crate::ui::ids::ID_COPY_RAW_JSON => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::CopyRawJson);
+		}
 		ID_VIEW_POST => {
 			if shutdown_menu.get() {
 				return;
@@ -933,6 +1273,12 @@ pub fn bind_input_handlers(
 			}
 			let _ = ui_tx_menu.send(UiCommand::ViewThread);
 		}
+		crate::ui::ids::ID_LOAD_MORE_REPLIES => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::LoadMoreReplies);
+		}
 		ID_VIEW_QUOTED_THREAD => {
 			if shutdown_menu.get() {
 				return;
@@ -971,6 +1317,24 @@ pub fn bind_input_handlers(
 				let _ = ui_tx_menu.send(UiCommand::Find(query));
 			}
 		}
+		crate::ui::ids::ID_RESUME_DRAFT => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::ResumeDraft);
+		}
+		crate::ui::ids::ID_GO_TO_POST => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::GoToPost);
+		}
+		crate::ui::ids::ID_NEXT_MENTION => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::NextMentionOfMe);
+		}
 		crate::ui::ids::ID_FIND_NEXT => {
 			if shutdown_menu.get() {
 				return;
@@ -989,6 +1353,24 @@ pub fn bind_input_handlers(
 			}
 			let _ = ui_tx_menu.send(UiCommand::CheckForUpdates);
 		}
+		crate::ui::ids::ID_SHOW_LAST_ERROR => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::ShowLastError);
+		}
+		crate::ui::ids::ID_OPEN_LOG_FOLDER => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::OpenLogFolder);
+		}
+		crate::ui::ids::ID_ABOUT_INSTANCE => {
+			if shutdown_menu.get() {
+				return;
+			}
+			let _ = ui_tx_menu.send(UiCommand::ShowInstanceInfo);
+		}
 		_ => {}
 	});
 }
@@ -0,0 +1,347 @@
+use std::{
+	fmt::Write as _,
+	fs::{self, File},
+	io::{Read, Write},
+	path::{Path, PathBuf},
+	process::Command,
+};
+
+use anyhow::{Context, Result, anyhow};
+use minisign_verify::{PublicKey, Signature};
+use reqwest::blocking::Client;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use crate::config::UpdateChannel;
+
+const RELEASES_URL: &str = "https://api.github.com/repos/trypsynth/fedra/releases";
+/// The tag CI moves to the commit each development build was made from.
+const DEV_TAG_URL: &str = "https://api.github.com/repos/trypsynth/fedra/git/ref/tags/latest";
+const INSTALLER_ASSET: &str = "fedra_setup.exe";
+/// Public key release artifacts are signed with. A download is only installed if its signature checks out against it.
+const FEDRA_MINISIGN_KEY: &str = "RWTlkclKA9G3Jhv3wkicYywPfi5XqULERn6LrK7aIv9nYQUPbhQaxSqZ";
+/// Each release asset is published alongside a minisign signature with this extension.
+const SIGNATURE_SUFFIX: &str = ".minisig";
+/// Each release asset is published alongside a file of this extension holding its SHA-256 hash.
+const CHECKSUM_SUFFIX: &str = ".sha256";
+const UPDATE_DIR: &str = "fedra_update";
+
+#[derive(Debug, Clone, Deserialize)]
+struct Release {
+	tag_name: String,
+	#[serde(default)]
+	body: Option<String>,
+	#[serde(default)]
+	assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseAsset {
+	pub name: String,
+	pub browser_download_url: String,
+	#[serde(default)]
+	pub size: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitRef {
+	object: GitObject,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitObject {
+	sha: String,
+}
+
+/// A newer build than the running one, with the asset to install and the URLs of its signature and checksum.
+#[derive(Debug, Clone)]
+pub struct AvailableUpdate {
+	/// Version for stable releases, or the short commit hash for development builds.
+	pub version: String,
+	pub notes: String,
+	pub asset: ReleaseAsset,
+	signature_url: String,
+	checksum_url: String,
+}
+
+fn http_client() -> Result<Client> {
+	let builder = Client::builder().user_agent(crate::mastodon::user_agent());
+	crate::proxy::configure(builder)?.build().context("Failed to create HTTP client")
+}
+
+/// Asks GitHub for the newest release on `channel`. `None` when the running build is already up to date.
+pub fn check_for_update(channel: UpdateChannel, installer: bool) -> Result<Option<AvailableUpdate>> {
+	let url = match channel {
+		UpdateChannel::Stable => format!("{RELEASES_URL}/latest"),
+		UpdateChannel::Dev => format!("{RELEASES_URL}/tags/latest"),
+	};
+	let client = http_client()?;
+	let release: Release = client
+		.get(url)
+		.header("Accept", "application/vnd.github+json")
+		.send()
+		.context("Failed to check for updates")?
+		.error_for_status()
+		.context("GitHub rejected the update check")?
+		.json()
+		.context("Invalid release information")?;
+	let notes = release.body.clone().unwrap_or_default();
+	let version = match channel {
+		UpdateChannel::Stable => {
+			if !is_newer_version(&release.tag_name, env!("CARGO_PKG_VERSION")) {
+				return Ok(None);
+			}
+			release.tag_name.trim_start_matches('v').to_string()
+		}
+		UpdateChannel::Dev => {
+			let commit = dev_build_commit(&client)?;
+			if commit.eq_ignore_ascii_case(env!("FEDRA_COMMIT_HASH")) {
+				return Ok(None);
+			}
+			commit.chars().take(7).collect()
+		}
+	};
+	let asset = pick_asset(&release.assets, installer)
+		.ok_or_else(|| anyhow!("The new release has no download for this copy of Fedra"))?;
+	let signature_url = companion_url(&release.assets, asset, SIGNATURE_SUFFIX)
+		.ok_or_else(|| anyhow!("The new release isn't signed, so it can't be verified"))?;
+	let checksum_url = companion_url(&release.assets, asset, CHECKSUM_SUFFIX)
+		.ok_or_else(|| anyhow!("The new release has no published checksum, so it can't be verified"))?;
+	Ok(Some(AvailableUpdate { version, notes, asset: asset.clone(), signature_url, checksum_url }))
+}
+
+/// The full hash of the commit the development build was made from, which the `latest` tag points at.
+fn dev_build_commit(client: &Client) -> Result<String> {
+	let tag: GitRef = client
+		.get(DEV_TAG_URL)
+		.header("Accept", "application/vnd.github+json")
+		.send()
+		.context("Failed to check for updates")?
+		.error_for_status()
+		.context("GitHub rejected the update check")?
+		.json()
+		.context("Invalid release information")?;
+	Ok(tag.object.sha)
+}
+
+/// The download URL of the file published next to `asset` with `suffix` added to its name.
+fn companion_url(assets: &[ReleaseAsset], asset: &ReleaseAsset, suffix: &str) -> Option<String> {
+	let name = format!("{}{suffix}", asset.name);
+	assets.iter().find(|candidate| candidate.name == name).map(|candidate| candidate.browser_download_url.clone())
+}
+
+/// Whether release tag `tag` (such as "v0.5.0") is a later version than `current`.
+pub fn is_newer_version(tag: &str, current: &str) -> bool {
+	fn parts(version: &str) -> Vec<u64> {
+		let version = version.trim().trim_start_matches('v');
+		let version = version.split(['-', '+']).next().unwrap_or_default();
+		version.split('.').map(|part| part.parse().unwrap_or(0)).collect()
+	}
+	let (mut tag, mut current) = (parts(tag), parts(current));
+	let len = tag.len().max(current.len());
+	tag.resize(len, 0);
+	current.resize(len, 0);
+	tag > current
+}
+
+/// The installer for installed copies, or the Windows portable zip otherwise.
+fn pick_asset(assets: &[ReleaseAsset], installer: bool) -> Option<&ReleaseAsset> {
+	assets.iter().find(|asset| {
+		if installer {
+			asset.name == INSTALLER_ASSET
+		} else {
+			asset.name.starts_with("fedra-") && asset.name.contains("windows") && asset.name.ends_with(".zip")
+		}
+	})
+}
+
+/// Reads the hash for `file_name` out of a checksum file, which holds either a bare hash or `sha256sum` lines such
+/// as "<hash>  fedra_setup.exe".
+pub fn parse_checksum(contents: &str, file_name: &str) -> Option<String> {
+	let is_hash = |value: &str| value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit());
+	contents.lines().find_map(|line| {
+		let mut fields = line.split_whitespace();
+		let hash = fields.next().filter(|hash| is_hash(hash))?;
+		match fields.next() {
+			Some(name) if name.trim_start_matches('*') != file_name => None,
+			_ => Some(hash.to_ascii_lowercase()),
+		}
+	})
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+	bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+		let _ = write!(hex, "{byte:02x}");
+		hex
+	})
+}
+
+fn release_key() -> Result<PublicKey> {
+	PublicKey::from_base64(FEDRA_MINISIGN_KEY).context("Fedra's release key couldn't be read")
+}
+
+fn download_text(client: &Client, url: &str, what: &str) -> Result<String> {
+	client
+		.get(url)
+		.send()
+		.and_then(reqwest::blocking::Response::error_for_status)
+		.with_context(|| format!("Failed to download the update's {what}"))?
+		.text()
+		.with_context(|| format!("Failed to read the update's {what}"))
+}
+
+/// Downloads `update` into the temporary folder and checks it against both its minisign signature, made with Fedra's
+/// release key, and its published SHA-256 hash. `progress` is called with the bytes received so far and the total,
+/// and cancels the download by returning false, in which case `None` is returned. A download that fails either check
+/// is deleted and reported as an error.
+pub fn download_verified(
+	update: &AvailableUpdate,
+	mut progress: impl FnMut(u64, u64) -> bool,
+) -> Result<Option<PathBuf>> {
+	let client = http_client()?;
+	let signature = Signature::decode(&download_text(&client, &update.signature_url, "signature")?)
+		.context("The update's signature couldn't be read")?;
+	let key = release_key()?;
+	let mut verifier =
+		key.verify_stream(&signature).context("The update's signature can't be checked against Fedra's release key")?;
+	let checksum_file = download_text(&client, &update.checksum_url, "checksum")?;
+	let expected = parse_checksum(&checksum_file, &update.asset.name)
+		.ok_or_else(|| anyhow!("The update's published checksum couldn't be read"))?;
+	let dir = std::env::temp_dir().join(UPDATE_DIR);
+	fs::create_dir_all(&dir).context("Failed to create a folder for the update")?;
+	let path = dir.join(&update.asset.name);
+	let mut response = client
+		.get(&update.asset.browser_download_url)
+		.send()
+		.and_then(reqwest::blocking::Response::error_for_status)
+		.context("Failed to download the update")?;
+	let total = response.content_length().unwrap_or(update.asset.size);
+	let mut file = File::create(&path).context("Failed to save the update")?;
+	let mut hasher = Sha256::new();
+	let mut buf = [0u8; 16 * 1024];
+	let mut received = 0;
+	loop {
+		if !progress(received, total) {
+			drop(file);
+			let _ = fs::remove_file(&path);
+			return Ok(None);
+		}
+		let read = response.read(&mut buf).context("The update download was interrupted")?;
+		if read == 0 {
+			break;
+		}
+		hasher.update(&buf[..read]);
+		verifier.update(&buf[..read]);
+		file.write_all(&buf[..read]).context("Failed to save the update")?;
+		received += read as u64;
+	}
+	drop(file);
+	if verifier.finalize().is_err() {
+		let _ = fs::remove_file(&path);
+		return Err(anyhow!(
+			"The downloaded update's signature doesn't check out, so it was deleted instead of being installed."
+		));
+	}
+	let actual = to_hex(&hasher.finalize());
+	if actual != expected {
+		let _ = fs::remove_file(&path);
+		return Err(anyhow!(
+			"The downloaded update doesn't match its published checksum, so it was deleted instead of being installed."
+		));
+	}
+	Ok(Some(path))
+}
+
+/// Starts installing a verified download: runs the installer, or for portable copies unpacks the zip and leaves a
+/// script that copies it over this copy once Fedra exits, then starts it again. The caller should exit afterwards.
+pub fn launch_update(path: &Path) -> Result<()> {
+	if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("exe")) {
+		Command::new(path).spawn().context("Failed to start the installer")?;
+		return Ok(());
+	}
+	let exe = std::env::current_exe().context("Failed to find Fedra's folder")?;
+	let install_dir = exe.parent().ok_or_else(|| anyhow!("Failed to find Fedra's folder"))?;
+	let dir = path.parent().ok_or_else(|| anyhow!("Failed to find the downloaded update"))?;
+	let unpacked = dir.join("files");
+	let _ = fs::remove_dir_all(&unpacked);
+	let archive = File::open(path).context("Failed to open the downloaded update")?;
+	zip::ZipArchive::new(archive)
+		.and_then(|mut archive| archive.extract(&unpacked))
+		.context("Failed to unpack the downloaded update")?;
+	let script = dir.join("apply_update.cmd");
+	let exe_name = exe.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+	let contents = format!(
+		"@echo off\r\n:wait\r\ntasklist /FI \"PID eq {pid}\" 2>NUL | find \"{pid}\" >NUL\r\nif not errorlevel 1 (\r\n\ttimeout /t 1 /nobreak >NUL\r\n\tgoto wait\r\n)\r\nxcopy /E /Y /Q \"{from}\\*\" \"{to}\\\" >NUL\r\nstart \"\" \"{to}\\{exe_name}\"\r\n",
+		pid = std::process::id(),
+		from = unpacked.display(),
+		to = install_dir.display(),
+	);
+	fs::write(&script, contents).context("Failed to prepare the update")?;
+	let mut command = Command::new("cmd");
+	command.arg("/C").arg(&script);
+	#[cfg(windows)]
+	{
+		use std::os::windows::process::CommandExt;
+		const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+		command.creation_flags(CREATE_NO_WINDOW);
+	}
+	command.spawn().context("Failed to start the update")?;
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ReleaseAsset, Signature, companion_url, is_newer_version, parse_checksum, pick_asset, release_key};
+
+	fn asset(name: &str) -> ReleaseAsset {
+		ReleaseAsset { name: name.to_string(), browser_download_url: format!("https://example.com/{name}"), size: 0 }
+	}
+
+	#[test]
+	fn newer_versions_compare_numerically() {
+		assert!(is_newer_version("v0.10.0", "0.4.0"));
+		assert!(is_newer_version("v0.4.1", "0.4.0"));
+		assert!(is_newer_version("1.0", "0.9.9"));
+		assert!(!is_newer_version("v0.4.0", "0.4.0"));
+		assert!(!is_newer_version("v0.3.9", "0.4.0"));
+		assert!(!is_newer_version("v0.4.0-beta", "0.4.0"));
+	}
+
+	#[test]
+	fn signatures_from_other_keys_are_refused() {
+		let foreign = "untrusted comment: signature from minisign secret key\n\
+			RWQf6LRCGA9i59SLOFxz6NxvASXDJeRtuZykwQepbDEGt87ig1BNpWaVWuNrm73YiIiJbq71Wi+dP9eKL8OC351vwIasSSbXxwA=\n\
+			trusted comment: timestamp:1555779966\tfile:test\n\
+			QtKMXWyYcwdpZAlPF7tE2ENJkRd1ujvKjlj1m9RtHTBnZPa5WKU5uWRs5GoP5M/VqE81QFuMKI5k/SfNQUaOAA==";
+		let key = release_key().unwrap();
+		assert!(key.verify_stream(&Signature::decode(foreign).unwrap()).is_err());
+		assert!(Signature::decode("not a signature").is_err());
+	}
+
+	#[test]
+	fn checksum_files_are_matched_by_name() {
+		let hash = "AB".repeat(32);
+		assert_eq!(parse_checksum(&hash, "fedra_setup.exe"), Some("ab".repeat(32)));
+		let listing = format!("{}  fedra-0.5.0-x86_64-pc-windows-msvc.zip\n{hash} *fedra_setup.exe\n", "cd".repeat(32));
+		assert_eq!(parse_checksum(&listing, "fedra_setup.exe"), Some("ab".repeat(32)));
+		assert_eq!(parse_checksum(&listing, "other.zip"), None);
+		assert_eq!(parse_checksum("not a hash  fedra_setup.exe", "fedra_setup.exe"), None);
+	}
+
+	#[test]
+	fn asset_matches_distribution() {
+		let assets = [
+			asset("fedra-0.5.0-x86_64-pc-windows-msvc.zip"),
+			asset("fedra-0.5.0-x86_64-pc-windows-msvc.zip.sha256"),
+			asset("fedra_setup.exe"),
+			asset("fedra_setup.exe.sha256"),
+		];
+		assert_eq!(pick_asset(&assets, true).map(|a| a.name.as_str()), Some("fedra_setup.exe"));
+		assert_eq!(pick_asset(&assets, false).map(|a| a.name.as_str()), Some("fedra-0.5.0-x86_64-pc-windows-msvc.zip"));
+		assert_eq!(
+			companion_url(&assets, &assets[2], ".sha256").as_deref(),
+			Some("https://example.com/fedra_setup.exe.sha256")
+		);
+		assert_eq!(companion_url(&assets, &assets[2], ".minisig"), None);
+	}
+}
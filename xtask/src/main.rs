@@ -3,8 +3,8 @@
 use std::{
 	env,
 	error::Error,
-	fs::File,
-	io,
+	fs::{self, File},
+	io::{self, Write},
 	path::{Path, PathBuf},
 	process::Command,
 };
@@ -13,9 +13,10 @@ use walkdir::WalkDir;
 use zip::{CompressionMethod, ZipWriter, write::SimpleFileOptions};
 
 fn main() -> Result<(), Box<dyn Error>> {
-	let task = env::args().nth(1);
+	let mut args = env::args().skip(1);
+	let task = args.next();
 	match task.as_deref() {
-		Some("release") => release()?,
+		Some("release") => release(parse_target(args)?)?,
 		_ => print_help(),
 	}
 	Ok(())
@@ -24,25 +25,71 @@ fn main() -> Result<(), Box<dyn Error>> {
 fn print_help() {
 	println!("Tasks:");
 	println!("	release	Build release binaries and package them");
+	println!("		--target <triple>	Build and package for the given target instead of the host");
 }
 
-fn release() -> Result<(), Box<dyn Error>> {
+fn parse_target(mut args: impl Iterator<Item = String>) -> Result<Option<String>, Box<dyn Error>> {
+	let mut target = None;
+	while let Some(arg) = args.next() {
+		if arg == "--target" {
+			target = Some(args.next().ok_or("--target requires a target triple")?);
+		} else if let Some(value) = arg.strip_prefix("--target=") {
+			target = Some(value.to_string());
+		} else {
+			return Err(format!("Unknown argument: {arg}").into());
+		}
+	}
+	Ok(target)
+}
+
+fn ensure_target_installed(triple: &str) -> Result<(), Box<dyn Error>> {
+	let output = Command::new("rustup")
+		.args(["target", "list", "--installed"])
+		.output()
+		.map_err(|e| format!("Failed to run rustup to check installed targets: {e}"))?;
+	let installed = String::from_utf8_lossy(&output.stdout);
+	if installed.lines().any(|line| line.trim() == triple) {
+		Ok(())
+	} else {
+		Err(format!("Target {triple} is not installed. Install it with: rustup target add {triple}").into())
+	}
+}
+
+fn release(target: Option<String>) -> Result<(), Box<dyn Error>> {
+	let host = host_triple()?;
+	let triple = target.unwrap_or_else(|| host.clone());
+	let cross = triple != host;
+	if cross {
+		ensure_target_installed(&triple)?;
+	}
 	let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
-	let status = Command::new(cargo).current_dir(project_root()).args(&["build", "--release"]).status()?;
-	if !status.success() {
+	let mut build = Command::new(cargo);
+	build.current_dir(project_root()).args(["build", "--release"]);
+	if cross {
+		build.args(["--target", &triple]);
+	}
+	if !build.status()?.success() {
 		return Err("Cargo build failed".into());
 	}
-	let target_dir = project_root().join("target/release");
-	let exe_name = if cfg!(windows) { "fedra.exe" } else { "fedra" };
+	let target_dir = if cross {
+		project_root().join("target").join(&triple).join("release")
+	} else {
+		project_root().join("target/release")
+	};
+	let is_windows_target = triple.contains("windows");
+	let exe_name = if is_windows_target { "fedra.exe" } else { "fedra" };
 	let exe_path = target_dir.join(exe_name);
 	let readme_path = target_dir.join("readme.html");
 	let sounds_dir = project_root().join("sounds");
 	if !exe_path.exists() {
-		return Err("Executable not found".into());
+		return Err(format!("Executable not found at {}", exe_path.display()).into());
 	}
+	// xtask shares the workspace version, so this is Fedra's version.
+	let version = env!("CARGO_PKG_VERSION");
 	println!("Packaging binaries and docs...");
-	build_zip_package(&target_dir, &exe_path, &readme_path, &sounds_dir)?;
-	if cfg!(windows) {
+	build_zip_package(&target_dir, &exe_path, &readme_path, &sounds_dir, version, &triple)?;
+	if is_windows_target && cfg!(windows) {
+		write_installer_script(&target_dir, version, exe_name, &sounds_dir)?;
 		build_windows_installer(&target_dir)?;
 	}
 	Ok(())
@@ -52,13 +99,25 @@ fn project_root() -> PathBuf {
 	Path::new(&env!("CARGO_MANIFEST_DIR")).ancestors().nth(1).unwrap().to_path_buf()
 }
 
+fn host_triple() -> Result<String, Box<dyn Error>> {
+	let output = Command::new("rustc").arg("-vV").output()?;
+	let info = String::from_utf8_lossy(&output.stdout);
+	info.lines()
+		.find_map(|line| line.strip_prefix("host: "))
+		.map(|host| host.trim().to_string())
+		.ok_or_else(|| "Could not determine the host target triple".into())
+}
+
 fn build_zip_package(
 	target_dir: &Path,
 	exe_path: &Path,
 	readme_path: &Path,
 	sounds_dir: &Path,
+	version: &str,
+	triple: &str,
 ) -> Result<(), Box<dyn Error>> {
-	let package_name = if cfg!(target_os = "macos") { "fedra_mac.zip" } else { "fedra.zip" };
+	let base_name = if triple.contains("apple-darwin") { "fedra_mac" } else { "fedra" };
+	let package_name = format!("{base_name}-{version}-{triple}.zip");
 	let package_path = target_dir.join(package_name);
 	let file = File::create(&package_path)?;
 	let mut zip = ZipWriter::new(file);
@@ -67,6 +126,8 @@ fn build_zip_package(
 	zip.start_file(exe_filename.to_string_lossy(), options)?;
 	let mut f = File::open(exe_path)?;
 	io::copy(&mut f, &mut zip)?;
+	zip.start_file("version.txt", options)?;
+	writeln!(zip, "Fedra {version} ({triple})")?;
 	if readme_path.exists() {
 		zip.start_file("readme.html", options)?;
 		let mut f = File::open(readme_path)?;
@@ -97,12 +158,34 @@ fn build_zip_package(
 	Ok(())
 }
 
+fn write_installer_script(
+	target_dir: &Path,
+	version: &str,
+	exe_name: &str,
+	sounds_dir: &Path,
+) -> Result<(), Box<dyn Error>> {
+	let template = fs::read_to_string(project_root().join("fedra.iss.in"))?;
+	if !template.contains("@PROJECT_VERSION@") {
+		return Err(
+			"fedra.iss.in has no @PROJECT_VERSION@ placeholder; refusing to build an unversioned installer".into()
+		);
+	}
+	let script = template
+		.replace("@PROJECT_VERSION@", version)
+		.replace("@EXE_NAME@", exe_name)
+		.replace("@OUTPUT_DIR@", &target_dir.to_string_lossy())
+		.replace("@SOUNDS_DIR@", &sounds_dir.to_string_lossy());
+	if !script.contains(&format!("AppVersion={version}")) {
+		return Err("Failed to substitute the version into the installer script".into());
+	}
+	let iss_path = target_dir.join("fedra.iss");
+	fs::write(&iss_path, script)?;
+	println!("Generated installer script: {}", iss_path.display());
+	Ok(())
+}
+
 fn build_windows_installer(target_dir: &Path) -> io::Result<()> {
 	let iss_path = target_dir.join("fedra.iss");
-	if !iss_path.exists() {
-		println!("Skipping installer: fedra.iss not found.");
-		return Ok(());
-	}
 	let status = Command::new("ISCC.exe").arg(&iss_path).status();
 	match status {
 		Ok(s) if s.success() => println!("Installer created successfully."),